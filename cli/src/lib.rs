@@ -282,7 +282,7 @@ impl Iroha<ToriiNotStarted> {
             }
         }.unwrap_or_else(|| {
             State::from_config(
-                config.chain_wide,
+                config.chain_wide.clone(),
                 world,
                 Arc::clone(&kura),
                 live_query_store_handle.clone(),
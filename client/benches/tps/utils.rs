@@ -205,7 +205,9 @@ impl MeasurerUnit {
                                 .with_instructions([instruction]);
                         transaction.set_nonce(nonce); // Use nonce to avoid transaction duplication within the same thread
 
-                        let transaction = submitter.sign_transaction(transaction);
+                        let transaction = submitter
+                            .sign_transaction(transaction)
+                            .expect("Signing with a key pair never fails");
                         if let Err(error) = submitter.submit_transaction(&transaction) {
                             iroha_logger::error!(?error, "Failed to submit transaction");
                         }
@@ -6,6 +6,7 @@ use std::{
     fmt::Debug,
     marker::PhantomData,
     num::{NonZeroU32, NonZeroU64},
+    sync::Arc,
     thread,
     time::Duration,
 };
@@ -33,12 +34,15 @@ use url::Url;
 use self::{blocks_api::AsyncBlockStream, events_api::AsyncEventStream};
 use crate::{
     config::Config,
-    crypto::{HashOf, KeyPair},
+    crypto::{HashOf, Signer},
     data_model::{
         block::SignedBlock,
         isi::Instruction,
         prelude::*,
-        query::{predicate::PredicateBox, Pagination, Query, Sorting},
+        query::{
+            cursor::ForwardCursor, predicate::PredicateBox, IterableQuery, Pagination, Query,
+            Sorting,
+        },
         BatchedResponse, ChainId, ValidationFail,
     },
     http::{Method as HttpMethod, RequestBuilder, Response, StatusCode},
@@ -70,19 +74,22 @@ pub type QueryResult<T> = core::result::Result<T, ClientQueryError>;
 
 /// Trait for signing transactions
 pub trait Sign {
-    /// Sign transaction with provided key pair.
-    fn sign(self, key_pair: &crate::crypto::KeyPair) -> SignedTransaction;
+    /// Sign transaction with provided signer, e.g. a key pair.
+    ///
+    /// # Errors
+    /// Fails if `signer` fails to produce a signature
+    fn sign(self, signer: &dyn Signer) -> Result<SignedTransaction, crate::crypto::Error>;
 }
 
 impl Sign for TransactionBuilder {
-    fn sign(self, key_pair: &crate::crypto::KeyPair) -> SignedTransaction {
-        self.sign(key_pair)
+    fn sign(self, signer: &dyn Signer) -> Result<SignedTransaction, crate::crypto::Error> {
+        self.sign_with(signer)
     }
 }
 
 impl Sign for SignedTransaction {
-    fn sign(self, key_pair: &crate::crypto::KeyPair) -> SignedTransaction {
-        self.sign(key_pair)
+    fn sign(self, signer: &dyn Signer) -> Result<SignedTransaction, crate::crypto::Error> {
+        self.sign_with(signer)
     }
 }
 
@@ -152,6 +159,17 @@ pub enum ClientQueryError {
     Other(#[from] eyre::Error),
 }
 
+/// Errors of queries expected to return exactly one result
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+pub enum SingleQueryError {
+    /// Query returned no results
+    NoResults,
+    /// Query returned {0} results instead of one
+    TooManyResults(usize),
+    /// Query failed
+    Query(#[from] ClientQueryError),
+}
+
 impl From<ResponseReport> for ClientQueryError {
     #[inline]
     fn from(ResponseReport(err): ResponseReport) -> Self {
@@ -177,6 +195,15 @@ impl TransactionResponseHandler {
     }
 }
 
+/// The client stopped waiting for the transaction `{hash}` to be committed after {timeout:?}
+#[derive(Debug, Clone, thiserror::Error, displaydoc::Display)]
+pub struct TransactionTimeoutError {
+    /// Hash of the transaction that was submitted
+    pub hash: HashOf<SignedTransaction>,
+    /// How long the client waited for the transaction
+    pub timeout: Duration,
+}
+
 /// Phantom struct that handles status check HTTP response
 #[derive(Clone, Copy)]
 pub struct StatusResponseHandler;
@@ -251,6 +278,44 @@ impl<T> ResultSet<T> {
     pub fn batch_len(&self) -> usize {
         self.iter.len()
     }
+
+    /// Server-side cursor pointing to the next batch, `None` if all batches were fetched
+    pub fn cursor(&self) -> Option<&ForwardCursor> {
+        match &self.query_handler.query_request.request {
+            crate::data_model::query::QueryRequest::Cursor(cursor) if cursor.cursor().is_some() => {
+                Some(cursor)
+            }
+            _ => None,
+        }
+    }
+
+    /// Release the live query held by the peer if iteration stopped before the last batch.
+    ///
+    /// A result set which is merely dropped keeps its live query on the peer
+    /// until the query expires for being idle.
+    ///
+    /// # Errors
+    /// Fails if sending request fails or the peer doesn't accept it
+    pub fn release(self) -> Result<()> {
+        let Some(cursor) = self.cursor() else {
+            return Ok(());
+        };
+
+        let response = self
+            .query_handler
+            .query_request
+            .assemble_release(cursor.clone())
+            .build()?
+            .send()?;
+        if response.status() != StatusCode::OK {
+            return Err(
+                ResponseReport::with_msg("Unexpected query release response", &response)
+                    .unwrap_or_else(core::convert::identity)
+                    .into(),
+            );
+        }
+        Ok(())
+    }
 }
 
 impl<T: Clone> Iterator for ResultSet<T>
@@ -327,9 +392,11 @@ impl_query_output! {
     crate::data_model::account::Account,
     crate::data_model::domain::Domain,
     crate::data_model::block::BlockHeader,
+    crate::data_model::block::SignedBlock,
     crate::data_model::metadata::MetadataValueBox,
     crate::data_model::query::TransactionQueryOutput,
     crate::data_model::executor::ExecutorDataModel,
+    crate::data_model::peer::Topology,
     crate::data_model::trigger::Trigger,
     crate::data_model::prelude::Numeric,
 }
@@ -338,16 +405,17 @@ impl_query_output! {
 #[derive(Clone, DebugCustom, Display)]
 #[debug(
     fmt = "Client {{ torii: {torii_url}, public_key: {} }}",
-    "key_pair.public_key()"
+    "signer.public_key()"
 )]
-#[display(fmt = "{}@{torii_url}", "key_pair.public_key()")]
+#[display(fmt = "{}@{torii_url}", "signer.public_key()")]
 pub struct Client {
     /// Unique id of the blockchain. Used for simple replay attack protection.
     pub chain_id: ChainId,
     /// Url for accessing Iroha node
     pub torii_url: Url,
-    /// Accounts keypair
-    pub key_pair: KeyPair,
+    /// Signer of transactions and queries: the account's key pair
+    /// or e.g. a handle to a key stored in an HSM
+    pub signer: Arc<dyn Signer + Send + Sync>,
     /// Transaction time to live in milliseconds
     pub transaction_ttl: Option<Duration>,
     /// Transaction status timeout
@@ -400,6 +468,15 @@ impl QueryRequest {
             }
         }
     }
+
+    fn assemble_release(&self, cursor: ForwardCursor) -> DefaultRequestBuilder {
+        DefaultRequestBuilder::new(
+            HttpMethod::DELETE,
+            self.torii_url.join(torii_uri::QUERY).expect("Valid URI"),
+        )
+        .headers(self.headers.clone())
+        .params(Vec::from(cursor))
+    }
 }
 
 /// Representation of `Iroha` client.
@@ -437,7 +514,7 @@ impl Client {
         Self {
             chain_id,
             torii_url: torii_api_url,
-            key_pair,
+            signer: Arc::new(key_pair),
             transaction_ttl: Some(transaction_ttl),
             transaction_status_timeout,
             account_id,
@@ -446,6 +523,25 @@ impl Client {
         }
     }
 
+    /// Sign transactions and queries with `signer` instead of the key pair from the configuration.
+    ///
+    /// Allows keeping the account's private key outside of the client, e.g. in an HSM.
+    ///
+    /// # Errors
+    /// Fails if the public key of `signer` is not the signatory of [`Self::account_id`]
+    pub fn with_signer(mut self, signer: Arc<dyn Signer + Send + Sync>) -> Result<Self> {
+        if signer.public_key() != self.account_id.signatory() {
+            return Err(eyre!(
+                "Public key of the signer `{}` is not the signatory of `{}`",
+                signer.public_key(),
+                self.account_id
+            ));
+        }
+
+        self.signer = signer;
+        Ok(self)
+    }
+
     /// Builds transaction out of supplied instructions or wasm.
     ///
     /// # Errors
@@ -454,7 +550,7 @@ impl Client {
         &self,
         instructions: impl Into<Executable>,
         metadata: UnlimitedMetadata,
-    ) -> SignedTransaction {
+    ) -> Result<SignedTransaction> {
         let tx_builder = TransactionBuilder::new(self.chain_id.clone(), self.account_id.clone());
 
         let mut tx_builder = match instructions.into() {
@@ -470,23 +566,30 @@ impl Client {
             tx_builder.set_nonce(nonce);
         };
 
-        tx_builder.with_metadata(metadata).sign(&self.key_pair)
+        tx_builder
+            .with_metadata(metadata)
+            .sign_with(self.signer.as_ref())
+            .wrap_err("Failed to sign transaction")
     }
 
     /// Signs transaction
     ///
     /// # Errors
     /// Fails if signature generation fails
-    pub fn sign_transaction<Tx: Sign>(&self, transaction: Tx) -> SignedTransaction {
-        transaction.sign(&self.key_pair)
+    pub fn sign_transaction<Tx: Sign>(&self, transaction: Tx) -> Result<SignedTransaction> {
+        transaction
+            .sign(self.signer.as_ref())
+            .wrap_err("Failed to sign transaction")
     }
 
     /// Signs query
     ///
     /// # Errors
     /// Fails if signature generation fails
-    pub fn sign_query(&self, query: ClientQueryBuilder) -> SignedQuery {
-        query.sign(&self.key_pair)
+    pub fn sign_query(&self, query: ClientQueryBuilder) -> Result<SignedQuery> {
+        query
+            .sign_with(self.signer.as_ref())
+            .wrap_err("Failed to sign query")
     }
 
     /// Instructions API entry point. Submits one Iroha Special Instruction to `Iroha` peers.
@@ -536,7 +639,7 @@ impl Client {
         instructions: impl IntoIterator<Item = impl Instruction>,
         metadata: UnlimitedMetadata,
     ) -> Result<HashOf<SignedTransaction>> {
-        self.submit_transaction(&self.build_transaction(instructions, metadata))
+        self.submit_transaction(&self.build_transaction(instructions, metadata)?)
     }
 
     /// Submit a prebuilt transaction.
@@ -567,6 +670,46 @@ impl Client {
         &self,
         transaction: &SignedTransaction,
     ) -> Result<HashOf<SignedTransaction>> {
+        self.submit_transaction_blocking_with_height(transaction)
+            .map(|_height| transaction.hash())
+    }
+
+    /// Submit the prebuilt transaction and wait until it is either rejected or committed.
+    /// Returns height of the block which included the transaction.
+    /// If rejected, return the rejection reason.
+    ///
+    /// # Errors
+    /// Fails if sending a transaction to a peer fails or there is an error in the response
+    pub fn submit_transaction_blocking_with_height(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<u64> {
+        self.submit_transaction_and_wait(transaction, None)
+    }
+
+    /// Submit the prebuilt transaction and wait at most `timeout` until it is either rejected or committed.
+    /// If rejected, return the rejection reason.
+    ///
+    /// Running out of time only stops the client from waiting: the transaction
+    /// stays submitted and can still be committed by the peers.
+    ///
+    /// # Errors
+    /// Fails with [`TransactionTimeoutError`] if the transaction wasn't committed in time,
+    /// or if sending a transaction to a peer fails or there is an error in the response
+    pub fn submit_transaction_blocking_with_timeout(
+        &self,
+        transaction: &SignedTransaction,
+        timeout: Duration,
+    ) -> Result<HashOf<SignedTransaction>> {
+        self.submit_transaction_and_wait(transaction, Some(timeout))
+            .map(|_height| transaction.hash())
+    }
+
+    fn submit_transaction_and_wait(
+        &self,
+        transaction: &SignedTransaction,
+        timeout: Option<Duration>,
+    ) -> Result<u64> {
         let (init_sender, init_receiver) = tokio::sync::oneshot::channel();
         let hash = transaction.hash();
 
@@ -582,7 +725,7 @@ impl Client {
                 Ok(())
             });
 
-            let confirmation_res = self.listen_for_tx_confirmation(init_sender, hash);
+            let confirmation_res = self.listen_for_tx_confirmation(init_sender, hash, timeout);
 
             match submitter_handle.join() {
                 Ok(Ok(())) => confirmation_res,
@@ -596,8 +739,10 @@ impl Client {
         &self,
         init_sender: tokio::sync::oneshot::Sender<bool>,
         hash: HashOf<SignedTransaction>,
-    ) -> Result<HashOf<SignedTransaction>> {
+        timeout: Option<Duration>,
+    ) -> Result<u64> {
         let deadline = tokio::time::Instant::now() + self.transaction_status_timeout;
+        let timeout_error = |timeout| TransactionTimeoutError { hash, timeout };
         let rt = tokio::runtime::Builder::new_current_thread()
             .enable_all()
             .build()?;
@@ -614,29 +759,33 @@ impl Client {
                 let event_iterator_result =
                     tokio::time::timeout_at(deadline, self.listen_for_events_async(filters))
                         .await
-                        .map_err(Into::into)
+                        .map_err(|_elapsed| {
+                            eyre::Report::new(timeout_error(self.transaction_status_timeout))
+                        })
                         .and_then(std::convert::identity)
                         .wrap_err("Failed to establish event listener connection");
                 let _send_result = init_sender.send(event_iterator_result.is_ok());
                 event_iterator_result?
             };
 
+            // A custom timeout only bounds waiting for the events, not connecting to the peer
+            let (deadline, timeout) = timeout
+                .map_or((deadline, self.transaction_status_timeout), |timeout| {
+                    (tokio::time::Instant::now() + timeout, timeout)
+                });
             let result = tokio::time::timeout_at(
                 deadline,
-                Self::listen_for_tx_confirmation_loop(&mut event_iterator, hash),
+                Self::listen_for_tx_confirmation_loop(&mut event_iterator),
             )
             .await
-            .map_err(Into::into)
+            .map_err(|_elapsed| eyre::Report::new(timeout_error(timeout)))
             .and_then(std::convert::identity);
             event_iterator.close().await;
             result
         })
     }
 
-    async fn listen_for_tx_confirmation_loop(
-        event_iterator: &mut AsyncEventStream,
-        hash: HashOf<SignedTransaction>,
-    ) -> Result<HashOf<SignedTransaction>> {
+    async fn listen_for_tx_confirmation_loop(event_iterator: &mut AsyncEventStream) -> Result<u64> {
         let mut block_height = None;
 
         while let Some(event) = event_iterator.next().await {
@@ -655,12 +804,14 @@ impl Client {
                         }
                     }
                     PipelineEventBox::Block(block_event) => {
-                        if Some(block_event.header().height()) == block_height {
+                        let height = block_event.header().height();
+                        if Some(height) == block_height {
                             if let BlockStatus::Applied = block_event.status() {
-                                return Ok(hash);
+                                return Ok(height);
                             }
                         }
                     }
+                    PipelineEventBox::BlockCommitted(_) => {}
                 }
             }
         }
@@ -696,6 +847,52 @@ impl Client {
         )
     }
 
+    /// Checks several Iroha Special Instructions against the current state of the peer
+    /// as if they were submitted in a single transaction, without submitting them.
+    /// Returns the outcome of each instruction in the same order.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn validate_all(
+        &self,
+        instructions: impl IntoIterator<Item = impl Instruction>,
+    ) -> Result<Vec<Result<(), ValidationFail>>> {
+        self.validate_transaction(&self.build_transaction(instructions, UnlimitedMetadata::new())?)
+    }
+
+    /// Checks instructions of a prebuilt transaction against the current state of the peer
+    /// without submitting it.
+    /// Returns the outcome of each instruction in the same order.
+    ///
+    /// Nothing is changed on the peer: instructions are executed one after another
+    /// on a copy of the state, skipping the rejected ones.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn validate_transaction(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<Vec<Result<(), ValidationFail>>> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            self.torii_url.join(torii_uri::VALIDATE).expect("Valid URI"),
+        )
+        .headers(&self.headers)
+        .body(transaction.encode_versioned())
+        .build()?
+        .send()
+        .wrap_err("Failed to send transaction for validation")?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to validate transaction with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        DecodeAll::decode_all(&mut resp.body().as_slice()).map_err(|err| eyre!("{err}"))
+    }
+
     /// Submits and waits until the transaction is either rejected or committed.
     /// Returns rejection reason if transaction was rejected.
     ///
@@ -708,6 +905,35 @@ impl Client {
         self.submit_all_blocking(vec![instruction.into()])
     }
 
+    /// Submits and waits at most `timeout` until the transaction is either rejected or committed.
+    /// Returns rejection reason if transaction was rejected.
+    ///
+    /// Running out of time doesn't cancel the transaction, only the wait for it.
+    ///
+    /// # Errors
+    /// Fails with [`TransactionTimeoutError`] if the transaction wasn't committed in time,
+    /// or if sending transaction to peer fails or if it response with error
+    pub fn submit_blocking_with_timeout(
+        &self,
+        instruction: impl Instruction,
+        timeout: Duration,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let transaction = self.build_transaction([instruction], UnlimitedMetadata::new())?;
+        self.submit_transaction_blocking_with_timeout(&transaction, timeout)
+    }
+
+    /// Submits and waits until the transaction is either rejected or committed.
+    /// Returns height of the block which included the transaction,
+    /// so that the state can be queried right after it.
+    /// Returns rejection reason if transaction was rejected.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub fn submit_blocking_with_height(&self, instruction: impl Instruction) -> Result<u64> {
+        let transaction = self.build_transaction([instruction], UnlimitedMetadata::new())?;
+        self.submit_transaction_blocking_with_height(&transaction)
+    }
+
     /// Submits and waits until the transaction is either rejected or committed.
     /// Returns rejection reason if transaction was rejected.
     ///
@@ -745,10 +971,45 @@ impl Client {
         instructions: impl IntoIterator<Item = impl Instruction>,
         metadata: UnlimitedMetadata,
     ) -> Result<HashOf<SignedTransaction>> {
-        let transaction = self.build_transaction(instructions, metadata);
+        let transaction = self.build_transaction(instructions, metadata)?;
         self.submit_transaction_blocking(&transaction)
     }
 
+    /// Signs and submits several independent transactions,
+    /// then waits until each of them is either rejected or committed.
+    /// Returns the outcome of every transaction in the order they were given,
+    /// so a rejected transaction doesn't hide the results of the others.
+    pub fn submit_transactions_blocking(
+        &self,
+        transactions: impl IntoIterator<Item = impl IntoIterator<Item = impl Instruction>>,
+    ) -> Vec<Result<HashOf<SignedTransaction>>> {
+        let transactions = transactions
+            .into_iter()
+            .map(|instructions| self.build_transaction(instructions, UnlimitedMetadata::new()))
+            .collect::<Vec<_>>();
+
+        thread::scope(|spawner| {
+            let handles = transactions
+                .into_iter()
+                .map(|transaction| {
+                    spawner.spawn(move || {
+                        transaction
+                            .and_then(|transaction| self.submit_transaction_blocking(&transaction))
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|_| Err(eyre!("Transaction submitter thread panicked")))
+                })
+                .collect()
+        })
+    }
+
     /// Lower-level Query API entry point. Prepares an http-request and returns it with an http-response handler.
     ///
     /// # Examples
@@ -813,7 +1074,7 @@ impl Client {
         pagination: Pagination,
         sorting: Sorting,
         fetch_size: FetchSize,
-    ) -> (DefaultRequestBuilder, QueryResponseHandler<R::Output>)
+    ) -> Result<(DefaultRequestBuilder, QueryResponseHandler<R::Output>)>
     where
         <R::Output as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
     {
@@ -822,7 +1083,7 @@ impl Client {
             .with_pagination(pagination)
             .with_sorting(sorting)
             .with_fetch_size(fetch_size);
-        let request = self.sign_query(query_builder);
+        let request = self.sign_query(query_builder)?;
 
         let query_request = QueryRequest {
             torii_url: self.torii_url.clone(),
@@ -830,10 +1091,10 @@ impl Client {
             request: crate::data_model::query::QueryRequest::Query(request),
         };
 
-        (
+        Ok((
             query_request.clone().assemble(),
             QueryResponseHandler::new(query_request),
-        )
+        ))
     }
 
     /// Create a request with pagination, sorting and add the filter.
@@ -854,7 +1115,7 @@ impl Client {
     {
         iroha_logger::trace!(?request, %pagination, ?sorting, ?filter);
         let (req, mut resp_handler) =
-            self.prepare_query_request::<R>(request, filter, pagination, sorting, fetch_size);
+            self.prepare_query_request::<R>(request, filter, pagination, sorting, fetch_size)?;
 
         let response = req.build()?.send()?;
         let output = resp_handler.handle(&response)?;
@@ -876,6 +1137,21 @@ impl Client {
         self.build_query(request).execute()
     }
 
+    /// Query API entry point for iterable queries expected to return exactly one result.
+    /// Shorthand for `self.build_query(r).execute_single()`.
+    ///
+    /// # Errors
+    /// Fails if sending request fails or if the query didn't return exactly one result
+    pub fn query_single<R, T>(&self, request: R) -> core::result::Result<T, SingleQueryError>
+    where
+        R: IterableQuery<Output = Vec<T>> + Debug,
+        T: Debug + Clone,
+        Vec<T>: QueryOutput<Target = ResultSet<T>>,
+        <Vec<T> as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
+    {
+        self.build_query(request).execute_single()
+    }
+
     /// Query API entry point using cursor.
     ///
     /// You should probably not use this function directly.
@@ -1445,6 +1721,14 @@ pub mod asset {
     pub fn by_id(asset_id: AssetId) -> FindAssetById {
         FindAssetById::new(asset_id)
     }
+
+    /// Construct a query to get the total quantity of an asset held by all accounts of a domain
+    pub fn total_by_definition_in_domain(
+        asset_definition_id: AssetDefinitionId,
+        domain_id: DomainId,
+    ) -> FindTotalAssetQuantityByDomainIdAndAssetDefinitionId {
+        FindTotalAssetQuantityByDomainIdAndAssetDefinitionId::new(domain_id, asset_definition_id)
+    }
 }
 
 pub mod block {
@@ -1466,6 +1750,43 @@ pub mod block {
     pub fn header_by_hash(hash: HashOf<SignedBlock>) -> FindBlockHeaderByHash {
         FindBlockHeaderByHash::new(hash)
     }
+
+    /// Construct a query to find committed block by height
+    pub fn by_height(height: u64) -> FindBlockByHeight {
+        FindBlockByHeight::new(height)
+    }
+
+    /// Construct a query to find committed block by hash
+    pub fn by_hash(hash: HashOf<SignedBlock>) -> FindBlockByHash {
+        FindBlockByHash::new(hash)
+    }
+
+    /// Stream all committed blocks starting from `from_height` in ascending order, e.g. to back up the chain.
+    ///
+    /// The export stops at the height of the latest block at the moment of the call,
+    /// blocks committed while exporting are not included.
+    ///
+    /// # Errors
+    /// Fails if querying the latest block header or connecting to the blocks stream fails
+    pub fn export(
+        client: &Client,
+        from_height: NonZeroU64,
+    ) -> Result<impl Iterator<Item = Result<SignedBlock>>> {
+        let latest_height = client
+            .build_query(all_headers())
+            .with_pagination(Pagination {
+                limit: NonZeroU32::new(1),
+                start: None,
+            })
+            .execute()?
+            .next()
+            .transpose()?
+            .map_or(0, |header| header.height());
+        let block_count = latest_height.saturating_sub(from_height.get() - 1);
+
+        let blocks = client.listen_for_blocks(from_height)?;
+        Ok(blocks.take(usize::try_from(block_count).expect("Block count should fit into usize")))
+    }
 }
 
 pub mod domain {
@@ -1483,6 +1804,22 @@ pub mod domain {
     }
 }
 
+pub mod peer {
+    //! Module with queries for peers
+    use super::*;
+
+    /// Construct a query to get all trusted peers
+    pub const fn all() -> FindAllPeers {
+        FindAllPeers
+    }
+
+    /// Construct a query to get the ordering of peers in the current round of consensus,
+    /// the first of them being the leader
+    pub const fn topology() -> FindTopology {
+        FindTopology
+    }
+}
+
 pub mod transaction {
     //! Module with queries for transactions
 
@@ -1558,6 +1895,36 @@ pub mod parameter {
     pub const fn all() -> FindAllParameters {
         FindAllParameters
     }
+
+    /// Construct a query to retrieve config parameters of the given subsystem
+    pub const fn by_subsystem(subsystem: ParameterSubsystem) -> FindParametersBySubsystem {
+        FindParametersBySubsystem { subsystem }
+    }
+
+    /// Construct a query to retrieve consensus parameters
+    pub const fn sumeragi() -> FindParametersBySubsystem {
+        by_subsystem(ParameterSubsystem::Sumeragi)
+    }
+
+    /// Construct a query to retrieve transaction limits parameters
+    pub const fn transaction() -> FindParametersBySubsystem {
+        by_subsystem(ParameterSubsystem::Transaction)
+    }
+
+    /// Construct a query to retrieve world state limits parameters
+    pub const fn world() -> FindParametersBySubsystem {
+        by_subsystem(ParameterSubsystem::World)
+    }
+
+    /// Construct a query to retrieve executor runtime parameters
+    pub const fn executor() -> FindParametersBySubsystem {
+        by_subsystem(ParameterSubsystem::Executor)
+    }
+
+    /// Construct a query to retrieve smart contract runtime parameters
+    pub const fn smart_contract() -> FindParametersBySubsystem {
+        by_subsystem(ParameterSubsystem::SmartContract)
+    }
 }
 
 pub mod executor {
@@ -1570,6 +1937,186 @@ pub mod executor {
     }
 }
 
+pub mod events {
+    //! Module with helpers for subscribing to events
+    use super::*;
+
+    /// Subscribe to the events matching `filter`, e.g. the events of a single domain.
+    ///
+    /// The filter is applied by the peer, events not matching it are never sent to the client
+    ///
+    /// # Errors
+    /// Fails if connecting to the events stream fails
+    pub fn subscribe_filtered(
+        client: &Client,
+        filter: impl Into<EventFilterBox>,
+    ) -> Result<impl Iterator<Item = Result<EventBox>>> {
+        client.listen_for_events([filter.into()])
+    }
+}
+
+pub mod status {
+    //! Module with helpers inspecting the actual behaviour of the network
+    use super::*;
+
+    /// Get time deltas between consecutive blocks among the last `last_n` committed ones
+    /// (from the oldest to the newest), e.g. to compare them against the configured block time
+    ///
+    /// # Errors
+    /// Fails if sending the query or decoding the response fails
+    pub fn block_timing(client: &Client, last_n: NonZeroU32) -> QueryResult<Vec<Duration>> {
+        let mut headers = client
+            .build_query(block::all_headers())
+            .with_pagination(Pagination {
+                limit: Some(last_n),
+                start: None,
+            })
+            .execute()?
+            .collect::<QueryResult<Vec<_>>>()?;
+        headers.reverse();
+
+        Ok(headers
+            .windows(2)
+            .map(|pair| pair[1].timestamp().saturating_sub(pair[0].timestamp()))
+            .collect())
+    }
+}
+
+pub mod chain {
+    //! Module with helpers auditing the whole chain, e.g. for light clients
+    use super::*;
+    use crate::crypto::PublicKey;
+
+    /// Defect found while verifying the chain
+    #[derive(Debug, thiserror::Error, displaydoc::Display)]
+    pub enum ChainError {
+        /// Failed to fetch the block at height {height}: {reason}
+        Fetch {
+            /// Height of the block
+            height: u64,
+            /// Why fetching failed, e.g. the block was tampered with so that its signatures don't match
+            reason: eyre::Report,
+        },
+        /// Expected the block at height {height}, got the block at height {actual}
+        Height {
+            /// Height of the expected block
+            height: u64,
+            /// Height of the received block
+            actual: u64,
+        },
+        /// Block at height {height} doesn't reference the hash of the previous block
+        PreviousBlockHash {
+            /// Height of the block
+            height: u64,
+        },
+        /// Block at height {height} isn't signed by enough peers of its commit topology
+        Signatures {
+            /// Height of the block
+            height: u64,
+        },
+    }
+
+    impl ChainError {
+        /// Height of the block which failed verification
+        pub fn height(&self) -> u64 {
+            match self {
+                Self::Fetch { height, .. }
+                | Self::Height { height, .. }
+                | Self::PreviousBlockHash { height }
+                | Self::Signatures { height } => *height,
+            }
+        }
+    }
+
+    /// Verify the whole chain from the genesis block to the latest one.
+    ///
+    /// See [`verify_blocks`] for what is checked
+    ///
+    /// # Errors
+    /// Fails with the height of the first block which can't be fetched or doesn't pass verification
+    pub fn verify(client: &Client) -> Result<(), ChainError> {
+        let genesis_height = NonZeroU64::new(1).expect("Valid");
+        let blocks = block::export(client, genesis_height).map_err(|reason| ChainError::Fetch {
+            height: genesis_height.get(),
+            reason,
+        })?;
+
+        verify_blocks(blocks)
+    }
+
+    /// Verify that `blocks` make up a chain starting from the genesis block:
+    /// the blocks have consecutive heights, each of them references the hash of the previous one,
+    /// and every block after the genesis one is signed by enough peers of the topology it was committed by.
+    ///
+    /// Validity of the signatures themselves is checked when a block is decoded
+    ///
+    /// # Errors
+    /// Fails with the height of the first block which isn't a valid continuation of the chain
+    pub fn verify_blocks(
+        blocks: impl IntoIterator<Item = Result<SignedBlock>>,
+    ) -> Result<(), ChainError> {
+        let mut previous_block_hash = None;
+
+        for (height, block) in (1..).zip(blocks) {
+            let block = block.map_err(|reason| ChainError::Fetch { height, reason })?;
+            let header = block.header();
+
+            if header.height() != height {
+                return Err(ChainError::Height {
+                    height,
+                    actual: header.height(),
+                });
+            }
+            if header.previous_block_hash != previous_block_hash {
+                return Err(ChainError::PreviousBlockHash { height });
+            }
+            // Genesis block is signed by the genesis key rather than by peers
+            if height > 1 && !is_signed_by_commit_topology(&block) {
+                return Err(ChainError::Signatures { height });
+            }
+
+            previous_block_hash = Some(block.hash());
+        }
+
+        Ok(())
+    }
+
+    fn is_signed_by_commit_topology(block: &SignedBlock) -> bool {
+        let topology = block.commit_topology();
+        // Same quorum as the one required by consensus
+        let min_votes_for_commit = if topology.len() > 3 {
+            (topology.len() - 1) / 3 * 2 + 1
+        } else {
+            topology.len()
+        };
+        if min_votes_for_commit == 0 {
+            return false;
+        }
+
+        let signatures = block.signatures();
+        let voting_peers = &topology[..min_votes_for_commit];
+        // Signatures of the voting peers might be aggregated into a single one
+        if let (1, Some(signature)) = (signatures.len(), signatures.iter().next()) {
+            if voting_peers.len() > 1
+                && PublicKey::aggregate(voting_peers.iter().map(|peer| &peer.public_key))
+                    .is_ok_and(|voting_peers_key| &voting_peers_key == signature.public_key())
+            {
+                return true;
+            }
+        }
+
+        let votes_count = signatures
+            .iter()
+            .filter(|signature| {
+                topology
+                    .iter()
+                    .any(|peer| &peer.public_key == signature.public_key())
+            })
+            .count();
+        votes_count >= min_votes_for_commit
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -1578,7 +2125,10 @@ mod tests {
     use test_samples::gen_account_in;
 
     use super::*;
-    use crate::config::{BasicAuth, Config, WebLogin};
+    use crate::{
+        config::{BasicAuth, Config, WebLogin},
+        crypto::KeyPair,
+    };
 
     const LOGIN: &str = "mad_hatter";
     const PASSWORD: &str = "ilovetea";
@@ -1606,8 +2156,11 @@ mod tests {
             ..config_factory()
         });
 
-        let build_transaction =
-            || client.build_transaction(Vec::<InstructionBox>::new(), UnlimitedMetadata::new());
+        let build_transaction = || {
+            client
+                .build_transaction(Vec::<InstructionBox>::new(), UnlimitedMetadata::new())
+                .expect("Signing with a key pair never fails")
+        };
         let tx1 = build_transaction();
         let tx2 = build_transaction();
         assert_ne!(tx1.hash(), tx2.hash());
@@ -1626,11 +2179,29 @@ mod tests {
                 tx.set_ttl(transaction_ttl);
             }
 
-            client.sign_transaction(tx)
+            client
+                .sign_transaction(tx)
+                .expect("Signing with a key pair never fails")
         };
         assert_eq!(tx1.hash(), tx2.hash());
     }
 
+    #[test]
+    fn signer_must_be_account_signatory() {
+        let config = config_factory();
+        let key_pair = config.key_pair.clone();
+        let client = Client::new(config);
+
+        assert!(client
+            .clone()
+            .with_signer(Arc::new(KeyPair::random()))
+            .is_err());
+        let client = client
+            .with_signer(Arc::new(key_pair.clone()))
+            .expect("Signer holds the signatory key");
+        assert_eq!(client.signer.public_key(), key_pair.public_key());
+    }
+
     #[test]
     fn authorization_header() {
         let client = Client::new(Config {
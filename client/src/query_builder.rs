@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use iroha_data_model::query::{IterableQuery, QueryOutputBox};
 
 use crate::{
-    client::{Client, QueryOutput, QueryResult},
+    client::{Client, QueryOutput, QueryResult, ResultSet, SingleQueryError},
     data_model::query::{predicate::PredicateBox, sorting::Sorting, FetchSize, Pagination, Query},
 };
 
@@ -70,3 +70,27 @@ where
         self
     }
 }
+
+impl<R, T> QueryRequestBuilder<'_, R>
+where
+    R: IterableQuery<Output = Vec<T>> + Debug,
+    T: Debug + Clone,
+    Vec<T>: QueryOutput<Target = ResultSet<T>>,
+    <Vec<T> as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
+{
+    /// Execute the query expecting it to return exactly one result
+    ///
+    /// # Errors
+    /// Fails if the query fails, returns no results or more than one result
+    pub fn execute_single(self) -> Result<T, SingleQueryError> {
+        let mut results = self.execute()?;
+
+        let single = results.next().ok_or(SingleQueryError::NoResults)??;
+        let count = results.try_fold(1, |count, result| result.map(|_| count + 1))?;
+        if count > 1 {
+            return Err(SingleQueryError::TooManyResults(count));
+        }
+
+        Ok(single)
+    }
+}
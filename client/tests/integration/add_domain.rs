@@ -1,4 +1,4 @@
-use std::thread;
+use std::{thread, time::Duration};
 
 use eyre::Result;
 use iroha::{client, data_model::prelude::*};
@@ -35,3 +35,24 @@ fn client_add_domain_with_name_length_more_than_limit_should_not_commit_transact
 
     Ok(())
 }
+
+#[test]
+fn submit_blocking_with_timeout_stops_waiting_but_keeps_transaction() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_307).start_with_runtime();
+    wait_for_genesis_committed(&vec![test_client.clone()], 0);
+
+    let domain_id: DomainId = "impatient".parse()?;
+    let create_domain = Register::domain(Domain::new(domain_id.clone()));
+    let error = test_client
+        .submit_blocking_with_timeout(create_domain, Duration::from_millis(1))
+        .expect_err("Transaction can't be committed within a millisecond");
+    let timeout = error
+        .downcast_ref::<client::TransactionTimeoutError>()
+        .expect("Should be a timeout error");
+    assert_eq!(timeout.timeout, Duration::from_millis(1));
+
+    // Only the wait was cut short, the transaction is still committed
+    test_client.poll_request(client::domain::by_id(domain_id), |_| true)?;
+
+    Ok(())
+}
@@ -114,7 +114,7 @@ fn client_add_asset_quantity_to_existing_asset_should_increase_asset_amount() ->
         AssetId::new(asset_definition_id.clone(), account_id.clone()),
     );
     let instructions: [InstructionBox; 2] = [create_asset.into(), mint.into()];
-    let tx = test_client.build_transaction(instructions, metadata);
+    let tx = test_client.build_transaction(instructions, metadata)?;
     test_client.submit_transaction(&tx)?;
     test_client.poll_request(client::asset::by_account_id(account_id), |result| {
         let assets = result.collect::<QueryResult<Vec<_>>>().expect("Valid");
@@ -145,7 +145,7 @@ fn client_add_big_asset_quantity_to_existing_asset_should_increase_asset_amount(
         AssetId::new(asset_definition_id.clone(), account_id.clone()),
     );
     let instructions: [InstructionBox; 2] = [create_asset.into(), mint.into()];
-    let tx = test_client.build_transaction(instructions, metadata);
+    let tx = test_client.build_transaction(instructions, metadata)?;
     test_client.submit_transaction(&tx)?;
     test_client.poll_request(client::asset::by_account_id(account_id), |result| {
         let assets = result.collect::<QueryResult<Vec<_>>>().expect("Valid");
@@ -177,7 +177,7 @@ fn client_add_asset_with_decimal_should_increase_asset_amount() -> Result<()> {
         AssetId::new(asset_definition_id.clone(), account_id.clone()),
     );
     let instructions: [InstructionBox; 2] = [create_asset.into(), mint.into()];
-    let tx = test_client.build_transaction(instructions, metadata);
+    let tx = test_client.build_transaction(instructions, metadata)?;
     test_client.submit_transaction(&tx)?;
     test_client.poll_request(client::asset::by_account_id(account_id.clone()), |result| {
         let assets = result.collect::<QueryResult<Vec<_>>>().expect("Valid");
@@ -430,14 +430,15 @@ fn fail_if_dont_satisfy_spec() {
 
         assert_eq!(
             rejection_reason,
-            &TransactionRejectionReason::Validation(ValidationFail::InstructionFailed(
-                InstructionExecutionError::Evaluate(InstructionEvaluationError::Type(
+            &TransactionRejectionReason::InstructionFailed(FailedInstruction {
+                index: 0,
+                error: InstructionExecutionError::Evaluate(InstructionEvaluationError::Type(
                     TypeError::from(Mismatch {
                         expected: AssetValueType::Numeric(NumericSpec::integer()),
                         actual: AssetValueType::Numeric(NumericSpec::fractional(2))
                     })
                 ))
-            ))
+            })
         );
     }
 
@@ -0,0 +1,127 @@
+use std::num::NonZeroU64;
+
+use eyre::Result;
+use iroha::{
+    client::{self, chain::ChainError, ClientQueryError, QueryResult},
+    crypto::{KeyPair, SignatureOf},
+    data_model::{
+        prelude::*,
+        query::error::{FindError, QueryExecutionFail},
+    },
+};
+use test_network::*;
+
+#[test]
+fn export_streams_committed_blocks() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_290).start_with_runtime();
+    wait_for_genesis_committed(&vec![client.clone()], 0);
+
+    for domain in ["looking_glass", "garden"] {
+        client.submit_blocking(Register::domain(Domain::new(domain.parse()?)))?;
+    }
+
+    let mut expected_hashes = client
+        .request(client::block::all())?
+        .map(|block| block.map(|block| block.hash()))
+        .collect::<QueryResult<Vec<_>>>()?;
+    expected_hashes.reverse();
+
+    let exported =
+        client::block::export(&client, NonZeroU64::new(1).unwrap())?.collect::<Result<Vec<_>>>()?;
+    assert_eq!(exported.len(), 3);
+    assert_eq!(
+        exported.iter().map(SignedBlock::hash).collect::<Vec<_>>(),
+        expected_hashes
+    );
+
+    let exported_tail =
+        client::block::export(&client, NonZeroU64::new(2).unwrap())?.collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        exported_tail
+            .iter()
+            .map(SignedBlock::hash)
+            .collect::<Vec<_>>(),
+        expected_hashes[1..]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn verify_chain_detects_tampered_blocks() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_304).start_with_runtime();
+    wait_for_genesis_committed(&vec![client.clone()], 0);
+
+    for domain in ["looking_glass", "garden"] {
+        client.submit_blocking(Register::domain(Domain::new(domain.parse()?)))?;
+    }
+
+    client::chain::verify(&client)?;
+
+    let blocks =
+        client::block::export(&client, NonZeroU64::new(1).unwrap())?.collect::<Result<Vec<_>>>()?;
+    client::chain::verify_blocks(blocks.clone().into_iter().map(Ok))?;
+
+    let mut forged = blocks.clone();
+    let SignedBlock::V1(block) = &forged[1];
+    let forged_signature = SignatureOf::new(&KeyPair::random(), block.payload());
+    assert!(forged[1].replace_signatures(forged_signature.into()));
+    let error = client::chain::verify_blocks(forged.into_iter().map(Ok))
+        .expect_err("Block signed by a peer outside of the topology should be rejected");
+    assert!(matches!(error, ChainError::Signatures { height: 2 }));
+
+    let mut truncated = blocks;
+    truncated.remove(1);
+    let error = client::chain::verify_blocks(truncated.into_iter().map(Ok))
+        .expect_err("Chain with a missing block should be rejected");
+    assert!(matches!(
+        error,
+        ChainError::Height {
+            height: 2,
+            actual: 3
+        }
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn committed_block_can_be_found_by_height_and_hash() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_312).start_with_runtime();
+    wait_for_genesis_committed(&vec![client.clone()], 0);
+
+    let transaction = client.build_transaction(
+        [Register::domain(Domain::new("looking_glass".parse()?))],
+        UnlimitedMetadata::new(),
+    )?;
+    let mut events = client.listen_for_events([TransactionEventFilter::default()
+        .for_hash(transaction.hash())
+        .for_status(TransactionStatus::Approved)])?;
+    client.submit_transaction(&transaction)?;
+    let event: TransactionEvent = events
+        .next()
+        .expect("Transaction must be approved")?
+        .try_into()?;
+    let height = event
+        .block_height()
+        .expect("Approved transaction must be in a block");
+
+    let block = client.request(client::block::by_height(height))?;
+    assert_eq!(block.header().height(), height);
+    assert!(block
+        .transactions()
+        .any(|tx| tx.as_ref().hash() == transaction.hash()));
+    assert_eq!(client.request(client::block::by_hash(block.hash()))?, block);
+
+    let error = client
+        .request(client::block::by_height(height + 100))
+        .expect_err("Block isn't committed yet");
+    assert!(matches!(
+        error,
+        ClientQueryError::Validation(ValidationFail::QueryFailed(QueryExecutionFail::Find(
+            FindError::BlockHeight(missing)
+        ))) if missing == height + 100
+    ));
+
+    Ok(())
+}
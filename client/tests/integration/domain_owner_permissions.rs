@@ -1,6 +1,8 @@
 use eyre::Result;
 use iroha::data_model::prelude::*;
-use iroha_data_model::transaction::error::TransactionRejectionReason;
+use iroha_data_model::{
+    isi::error::IndexedInstructionError, transaction::error::TransactionRejectionReason,
+};
 use serde_json::json;
 use test_network::*;
 use test_samples::{gen_account_in, ALICE_ID, BOB_ID};
@@ -38,7 +40,9 @@ fn domain_owner_domain_permissions() -> Result<()> {
 
     assert!(matches!(
         rejection_reason,
-        &TransactionRejectionReason::Validation(ValidationFail::NotPermitted(_))
+        TransactionRejectionReason::Validation(ValidationFail::TransactionInstructionFailed(
+            IndexedInstructionError { index: 0, error }
+        )) if matches!(**error, ValidationFail::NotPermitted(_))
     ));
 
     // "alice@wonderland" owns the domain and can register AssetDefinitions by default as domain owner
@@ -1,11 +1,14 @@
 use std::{fmt::Write as _, str::FromStr, sync::mpsc, thread};
 
 use eyre::Result;
-use iroha::data_model::{prelude::*, transaction::WasmSmartContract};
+use iroha::{
+    client::{self, QueryResult},
+    data_model::{prelude::*, transaction::WasmSmartContract},
+};
 use parity_scale_codec::Encode as _;
 use serde_json::json;
 use test_network::*;
-use test_samples::{ALICE_ID, BOB_ID};
+use test_samples::{gen_account_in, ALICE_ID, BOB_ID};
 
 /// Return string containing exported memory, dummy allocator, and
 /// host function imports which you can embed into your wasm module.
@@ -151,7 +154,7 @@ fn transaction_execution_should_produce_events(
 
     // submit transaction to produce events
     init_receiver.recv()?;
-    let transaction = client.build_transaction(executable, UnlimitedMetadata::new());
+    let transaction = client.build_transaction(executable, UnlimitedMetadata::new())?;
     client.submit_transaction_blocking(&transaction)?;
 
     // assertion
@@ -286,3 +289,137 @@ fn produce_multiple_events() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn subscribe_filtered_receives_only_matching_events() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_300).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let wonderland_id = DomainId::from_str("wonderland")?;
+    let neverland_id = DomainId::from_str("neverland")?;
+    client.submit_blocking(Register::domain(Domain::new(neverland_id.clone())))?;
+
+    // Spawn event reporter
+    let listener = client.clone();
+    let (init_sender, init_receiver) = mpsc::channel();
+    let (event_sender, event_receiver) = mpsc::channel();
+    let event_filter = DomainEventFilter::new().for_domain(wonderland_id);
+    thread::spawn(move || -> Result<()> {
+        let event_iterator = client::events::subscribe_filtered(&listener, event_filter)?;
+        init_sender.send(())?;
+        for event in event_iterator {
+            event_sender.send(event)?
+        }
+        Ok(())
+    });
+
+    // Wait for event listener
+    init_receiver.recv()?;
+
+    let (neverland_account_id, _) = gen_account_in("neverland");
+    let (wonderland_account_id, _) = gen_account_in("wonderland");
+    client.submit_blocking(Register::account(Account::new(neverland_account_id)))?;
+    client.submit_blocking(Register::account(Account::new(
+        wonderland_account_id.clone(),
+    )))?;
+
+    // The event of the account registered in `neverland` first must have been filtered out
+    let event: DataEvent = event_receiver.recv()??.try_into()?;
+    let DataEvent::Domain(DomainEvent::Account(AccountEvent::Created(account))) = event else {
+        panic!("Expected account created event, got {event:?}");
+    };
+    assert_eq!(account.id(), &wonderland_account_id);
+    assert!(event_receiver.try_recv().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn account_filter_composes_with_event_set() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_316).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let (watched_account_id, _) = gen_account_in("wonderland");
+    let (other_account_id, _) = gen_account_in("wonderland");
+
+    // Spawn event reporter
+    let listener = client.clone();
+    let (init_sender, init_receiver) = mpsc::channel();
+    let (event_sender, event_receiver) = mpsc::channel();
+    let event_filter = AccountEventFilter::new()
+        .for_account(watched_account_id.clone())
+        .for_events(AccountEventSet::Created);
+    thread::spawn(move || -> Result<()> {
+        let event_iterator = client::events::subscribe_filtered(&listener, event_filter)?;
+        init_sender.send(())?;
+        for event in event_iterator {
+            event_sender.send(event)?
+        }
+        Ok(())
+    });
+
+    // Wait for event listener
+    init_receiver.recv()?;
+
+    client.submit_blocking(Register::account(Account::new(other_account_id)))?;
+    client.submit_blocking(Register::account(Account::new(watched_account_id.clone())))?;
+    client.submit_blocking(SetKeyValue::account(
+        watched_account_id.clone(),
+        Name::from_str("key")?,
+        "value".to_owned(),
+    ))?;
+
+    // Neither the other account's events nor the metadata event are expected
+    let event: DataEvent = event_receiver.recv()??.try_into()?;
+    let DataEvent::Domain(DomainEvent::Account(AccountEvent::Created(account))) = event else {
+        panic!("Expected account created event, got {event:?}");
+    };
+    assert_eq!(account.id(), &watched_account_id);
+    assert!(event_receiver.try_recv().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn parameter_change_emits_event_with_old_and_new_values() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_303).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let parameter = Parameter::from_str("?BlockTime=4000")?;
+    let old_value = client
+        .request(client::parameter::sumeragi())?
+        .collect::<QueryResult<Vec<_>>>()?
+        .into_iter()
+        .find(|param| param.id() == parameter.id())
+        .expect("Parameter should exist")
+        .val()
+        .clone();
+
+    // Spawn event reporter
+    let listener = client.clone();
+    let (init_sender, init_receiver) = mpsc::channel();
+    let (event_sender, event_receiver) = mpsc::channel();
+    thread::spawn(move || -> Result<()> {
+        let event_iterator = listener.listen_for_events([ConfigurationEventFilter::new()])?;
+        init_sender.send(())?;
+        for event in event_iterator {
+            event_sender.send(event)?
+        }
+        Ok(())
+    });
+
+    // Wait for event listener
+    init_receiver.recv()?;
+
+    client.submit_blocking(SetParameter::new(parameter.clone()))?;
+
+    let event: DataEvent = event_receiver.recv()??.try_into()?;
+    let DataEvent::Configuration(ConfigurationEvent::Changed(changed)) = event else {
+        panic!("Expected parameter changed event, got {event:?}");
+    };
+    assert_eq!(changed.parameter_id(), parameter.id());
+    assert_eq!(changed.old_value(), &old_value);
+    assert_eq!(changed.new_value(), parameter.val());
+
+    Ok(())
+}
@@ -6,16 +6,17 @@ use iroha::{
     data_model::{
         parameter::{default::MAX_TRANSACTIONS_IN_BLOCK, ParametersBuilder},
         prelude::*,
+        Level,
     },
 };
 use iroha_config::parameters::actual::Root as Config;
 use iroha_data_model::{
     events::pipeline::{
-        BlockEvent, BlockEventFilter, BlockStatus, TransactionEventFilter, TransactionStatus,
+        BlockCommittedEvent, BlockCommittedEventFilter, BlockEvent, BlockEventFilter, BlockStatus,
+        TransactionEventFilter, TransactionStatus,
     },
     isi::error::InstructionExecutionError,
     transaction::error::TransactionRejectionReason,
-    ValidationFail,
 };
 use test_network::*;
 
@@ -37,8 +38,12 @@ fn transaction_with_fail_instruction_should_be_rejected() -> Result<()> {
     let fail = Fail::new(msg.clone());
     test_with_instruction_and_status_and_port(
         Some(fail.into()),
-        &TransactionStatus::Rejected(Box::new(TransactionRejectionReason::Validation(
-            ValidationFail::InstructionFailed(InstructionExecutionError::Fail(msg)),
+        &TransactionStatus::Rejected(Box::new(TransactionRejectionReason::InstructionFailed(
+            FailedInstruction {
+                index: 0,
+                error: InstructionExecutionError::Fail(msg),
+                rolled_back: Vec::new(),
+            },
         ))),
         10_350,
     )
@@ -63,7 +68,7 @@ fn test_with_instruction_and_status_and_port(
 
     // Given
     let submitter = client;
-    let transaction = submitter.build_transaction(instruction, UnlimitedMetadata::new());
+    let transaction = submitter.build_transaction(instruction, UnlimitedMetadata::new())?;
     let hash = transaction.hash();
     let mut handles = Vec::new();
     for listener in clients {
@@ -132,3 +137,68 @@ fn applied_block_must_be_available_in_kura() {
         .get_block_by_height(event.header().height())
         .expect("Block applied event was received earlier");
 }
+
+#[test]
+fn block_committed_event_must_summarize_block() {
+    let (_rt, peer, client) = <PeerBuilder>::new().with_port(11_318).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let mut event_iter = client
+        .listen_for_events([BlockCommittedEventFilter::new()])
+        .expect("Failed to subscribe for events");
+
+    let rejected = client
+        .build_transaction(
+            [Fail::new("Dummy instruction".to_owned())],
+            UnlimitedMetadata::new(),
+        )
+        .expect("Failed to sign transaction");
+    let approved = client
+        .build_transaction(
+            [Log::new(Level::INFO, "Dummy instruction".to_owned())],
+            UnlimitedMetadata::new(),
+        )
+        .expect("Failed to sign transaction");
+    client
+        .submit_transaction(&rejected)
+        .expect("Failed to submit transaction");
+    client
+        .submit_transaction(&approved)
+        .expect("Failed to submit transaction");
+
+    let mut pending = vec![(rejected.hash(), true), (approved.hash(), false)];
+    while !pending.is_empty() {
+        let event: BlockCommittedEvent = event_iter
+            .next()
+            .expect("Block must be committed")
+            .expect("Block must be committed")
+            .try_into()
+            .expect("Received unexpected event");
+
+        let block = peer
+            .irohad
+            .as_ref()
+            .expect("Must be some")
+            .kura()
+            .get_block_by_height(event.height())
+            .expect("Block committed event was received earlier");
+
+        assert_eq!(*event.hash(), block.hash());
+        assert_eq!(event.transaction_count(), block.transactions().len());
+        for (summary, tx) in event.transactions().iter().zip(block.transactions()) {
+            assert_eq!(*summary.hash(), tx.as_ref().hash());
+            assert_eq!(summary.is_rejected(), tx.error().is_some());
+        }
+
+        pending.retain(|(hash, is_rejected)| {
+            event
+                .transactions()
+                .iter()
+                .find(|summary| summary.hash() == hash)
+                .map_or(true, |summary| {
+                    assert_eq!(summary.is_rejected(), *is_rejected);
+                    false
+                })
+        });
+    }
+}
@@ -1,6 +1,7 @@
 mod add_domain;
 mod asset;
 mod asset_propagation;
+mod blocks;
 mod domain_owner_permissions;
 mod events;
 mod extra_functional;
@@ -1,10 +1,17 @@
+use std::collections::BTreeSet;
+
 use eyre::Result;
 use iroha::{
-    client::{asset, Client, QueryResult},
-    data_model::{asset::AssetDefinition, prelude::*, query::Pagination},
+    client::{account, asset, Client, ClientQueryError, QueryResult},
+    data_model::{
+        asset::AssetDefinition,
+        prelude::*,
+        query::{error::QueryExecutionFail, Pagination},
+    },
 };
 use nonzero_ext::nonzero;
 use test_network::*;
+use test_samples::gen_account_in;
 
 #[test]
 fn limits_should_work() -> Result<()> {
@@ -44,6 +51,74 @@ fn fetch_size_should_work() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn batched_iteration_yields_snapshot_once() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_305).start_with_runtime();
+    wait_for_genesis_committed(&vec![client.clone()], 0);
+
+    client.submit_blocking(Register::domain(Domain::new("garden".parse()?)))?;
+    for _ in 0..4 {
+        let register: Vec<InstructionBox> = core::iter::repeat_with(|| gen_account_in("garden").0)
+            .take(25)
+            .map(|account_id| Register::account(Account::new(account_id)).into())
+            .collect();
+        client.submit_all_blocking(register)?;
+    }
+    let expected = client
+        .request(account::all())?
+        .map(|account| account.map(|account| account.id().clone()))
+        .collect::<QueryResult<BTreeSet<_>>>()?;
+
+    let mut accounts = client
+        .build_query(account::all())
+        .with_fetch_size(FetchSize::new(Some(nonzero!(7_u32))))
+        .execute()?;
+    let mut yielded = accounts
+        .by_ref()
+        .take(expected.len() / 2)
+        .map(|account| account.map(|account| account.id().clone()))
+        .collect::<QueryResult<Vec<_>>>()?;
+    // Accounts registered while iterating aren't observed
+    client.submit_blocking(Register::account(Account::new(gen_account_in("garden").0)))?;
+    for account in accounts {
+        yielded.push(account?.id().clone());
+    }
+
+    assert_eq!(yielded.len(), expected.len());
+    assert_eq!(yielded.into_iter().collect::<BTreeSet<_>>(), expected);
+    Ok(())
+}
+
+#[test]
+fn releasing_batched_iteration_drops_live_query() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_306).start_with_runtime();
+    wait_for_genesis_committed(&vec![client.clone()], 0);
+
+    register_assets(&client)?;
+
+    let mut definitions = client
+        .build_query(asset::all_definitions())
+        .with_fetch_size(FetchSize::new(Some(nonzero!(3_u32))))
+        .execute()?;
+    definitions.next().transpose()?;
+    let cursor = definitions
+        .cursor()
+        .cloned()
+        .expect("Not all batches should be fetched");
+    definitions.release()?;
+
+    let err = client
+        .request_with_cursor::<Vec<AssetDefinition>>(cursor)
+        .expect_err("Live query should be dropped once released");
+    assert!(matches!(
+        err,
+        ClientQueryError::Validation(ValidationFail::QueryFailed(
+            QueryExecutionFail::UnknownCursor
+        ))
+    ));
+    Ok(())
+}
+
 fn register_assets(client: &Client) -> Result<()> {
     // FIXME transaction is rejected for more than a certain number of instructions
     let register: Vec<InstructionBox> = ('a'..='j')
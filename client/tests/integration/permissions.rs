@@ -7,8 +7,8 @@ use iroha::{
     data_model::prelude::*,
 };
 use iroha_data_model::{
-    permission::Permission, role::RoleId, transaction::error::TransactionRejectionReason,
-    JsonString,
+    isi::error::IndexedInstructionError, permission::Permission, role::RoleId,
+    transaction::error::TransactionRejectionReason, JsonString,
 };
 use iroha_genesis::GenesisNetwork;
 use serde_json::json;
@@ -113,7 +113,9 @@ fn permissions_disallow_asset_transfer() {
     //Then
     assert!(matches!(
         rejection_reason,
-        &TransactionRejectionReason::Validation(ValidationFail::NotPermitted(_))
+        TransactionRejectionReason::Validation(ValidationFail::TransactionInstructionFailed(
+            IndexedInstructionError { index: 0, error }
+        )) if matches!(**error, ValidationFail::NotPermitted(_))
     ));
     let alice_assets = get_assets(&iroha, &alice_id);
     assert_eq!(alice_assets, alice_start_assets);
@@ -163,7 +165,9 @@ fn permissions_disallow_asset_burn() {
 
     assert!(matches!(
         rejection_reason,
-        &TransactionRejectionReason::Validation(ValidationFail::NotPermitted(_))
+        TransactionRejectionReason::Validation(ValidationFail::TransactionInstructionFailed(
+            IndexedInstructionError { index: 0, error }
+        )) if matches!(**error, ValidationFail::NotPermitted(_))
     ));
 
     let alice_assets = get_assets(&iroha, &alice_id);
@@ -468,3 +472,80 @@ fn associated_permissions_removed_from_role_on_unregister() {
         .into_iter()
         .all(|token| { token != set_kv_in_domain_token }));
 }
+
+#[test]
+fn account_permissions_reflect_grants_and_revocations() {
+    let (_rt, _peer, iroha) = <PeerBuilder>::new().with_port(11_311).start_with_runtime();
+    wait_for_genesis_committed(&[iroha.clone()], 0);
+
+    let bob_id = BOB_ID.clone();
+    let domain_id: DomainId = "wonderland".parse().expect("Valid");
+    let can_unregister_domain = Permission::new(
+        "CanUnregisterDomain".parse().unwrap(),
+        json!({ "domain_id": domain_id }),
+    );
+    let can_set_kv_in_domain = Permission::new(
+        "CanSetKeyValueInDomain".parse().unwrap(),
+        json!({ "domain_id": domain_id }),
+    );
+    let bob_permissions = || {
+        iroha
+            .request(client::permission::by_account_id(bob_id.clone()))
+            .and_then(std::iter::Iterator::collect::<QueryResult<Vec<Permission>>>)
+            .expect("failed to get permissions for bob")
+    };
+
+    iroha
+        .submit_all_blocking([
+            Grant::permission(can_unregister_domain.clone(), bob_id.clone()),
+            Grant::permission(can_set_kv_in_domain.clone(), bob_id.clone()),
+        ])
+        .expect("failed to grant permissions");
+    let permissions = bob_permissions();
+    assert!(permissions.contains(&can_unregister_domain));
+    assert!(permissions.contains(&can_set_kv_in_domain));
+
+    iroha
+        .submit_blocking(Revoke::permission(
+            can_unregister_domain.clone(),
+            bob_id.clone(),
+        ))
+        .expect("failed to revoke permission");
+    let permissions = bob_permissions();
+    assert!(!permissions.contains(&can_unregister_domain));
+    assert!(permissions.contains(&can_set_kv_in_domain));
+}
+
+#[test]
+fn validate_flags_only_unauthorized_instruction() -> Result<()> {
+    let (_rt, _peer, iroha) = <PeerBuilder>::new().with_port(11_317).start_with_runtime();
+    wait_for_genesis_committed(&[iroha.clone()], 0);
+
+    let domain_id: DomainId = "validation".parse()?;
+    let key = Name::from_str("nickname")?;
+    let instructions: [InstructionBox; 4] = [
+        Register::domain(Domain::new(domain_id.clone())).into(),
+        // Depends on the domain registered by the previous instruction
+        Register::asset_definition(AssetDefinition::numeric(
+            format!("coin#{domain_id}").parse()?,
+        ))
+        .into(),
+        // Alice can't set metadata of Bob's account
+        SetKeyValue::account(BOB_ID.clone(), key.clone(), "bob".to_owned()).into(),
+        SetKeyValue::account(ALICE_ID.clone(), key, "alice".to_owned()).into(),
+    ];
+
+    let outcomes = iroha.validate_all(instructions)?;
+    assert_eq!(outcomes.len(), 4);
+    for (index, outcome) in outcomes.iter().enumerate() {
+        if index == 2 {
+            assert!(matches!(outcome, Err(ValidationFail::NotPermitted(_))));
+        } else {
+            assert!(outcome.is_ok(), "instruction #{index} failed: {outcome:?}");
+        }
+    }
+
+    // Nothing was committed
+    assert!(iroha.request(client::domain::by_id(domain_id)).is_err());
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use eyre::Result;
 use iroha::{
-    client::{Client, ClientQueryError},
+    client::{self, Client, ClientQueryError},
     data_model::{
         asset::AssetValue,
         isi::Instruction,
@@ -218,3 +218,66 @@ where
 
     Ok(())
 }
+
+#[test]
+fn find_asset_total_quantity_in_domain() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_315).start_with_runtime();
+    wait_for_genesis_committed(&[test_client.clone()], 0);
+
+    let domain_id: DomainId = "looking_glass".parse()?;
+    test_client.submit_blocking(Register::domain(Domain::new(domain_id.clone())))?;
+
+    let wonderland_accounts: [AccountId; 3] = [
+        ALICE_ID.clone(),
+        gen_account_in("wonderland").0,
+        gen_account_in("wonderland").0,
+    ];
+    let looking_glass_account = gen_account_in("looking_glass").0;
+    let register_accounts = wonderland_accounts
+        .iter()
+        .skip(1) // Alice has already been registered in genesis
+        .chain(core::iter::once(&looking_glass_account))
+        .cloned()
+        .map(|account_id| Register::account(Account::new(account_id)))
+        .collect::<Vec<_>>();
+    test_client.submit_all_blocking(register_accounts)?;
+
+    let definition_id: AssetDefinitionId = "quantity#wonderland".parse()?;
+    test_client.submit_blocking(Register::asset_definition(AssetDefinition::numeric(
+        definition_id.clone(),
+    )))?;
+
+    // Assert that total quantity of a definition without holders is zero
+    let total = test_client.request(client::asset::total_by_definition_in_domain(
+        definition_id.clone(),
+        "wonderland".parse()?,
+    ))?;
+    assert!(total.is_zero());
+
+    let mint_assets = wonderland_accounts
+        .iter()
+        .zip([10_u32, 20, 30])
+        .chain(core::iter::once((&looking_glass_account, 100)))
+        .map(|(account_id, quantity)| {
+            Mint::asset_numeric(
+                quantity,
+                AssetId::new(definition_id.clone(), account_id.clone()),
+            )
+        })
+        .collect::<Vec<_>>();
+    test_client.submit_all_blocking(mint_assets)?;
+
+    // Assert that only accounts of the requested domain are summed over
+    let total = test_client.request(client::asset::total_by_definition_in_domain(
+        definition_id.clone(),
+        "wonderland".parse()?,
+    ))?;
+    assert_eq!(numeric!(60), total);
+    let total = test_client.request(client::asset::total_by_definition_in_domain(
+        definition_id,
+        domain_id,
+    ))?;
+    assert_eq!(numeric!(100), total);
+
+    Ok(())
+}
@@ -9,6 +9,7 @@ use test_network::*;
 
 mod account;
 mod asset;
+mod peer;
 mod query_errors;
 mod role;
 mod smart_contract;
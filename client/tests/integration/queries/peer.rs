@@ -0,0 +1,16 @@
+use eyre::Result;
+use iroha::client;
+use test_network::*;
+
+#[test]
+fn find_topology_of_single_peer() -> Result<()> {
+    let (_rt, peer, client) = <PeerBuilder>::new().with_port(11_313).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let topology = client.request(client::peer::topology())?;
+
+    assert_eq!(topology.peers(), [peer.id.clone()]);
+    assert_eq!(topology.leader(), Some(&peer.id));
+
+    Ok(())
+}
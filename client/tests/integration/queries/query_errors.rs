@@ -1,10 +1,14 @@
 use iroha::{
-    client::{self, ClientQueryError},
+    client::{self, ClientQueryError, SingleQueryError},
     data_model::{
         prelude::*,
-        query::error::{FindError, QueryExecutionFail},
+        query::{
+            error::{FindError, QueryExecutionFail},
+            predicate::{string, value, PredicateBox},
+        },
     },
 };
+use test_network::*;
 use test_samples::gen_account_in;
 
 #[test]
@@ -28,3 +32,52 @@ fn non_existent_account_is_specific_error() {
         x => panic!("Unexpected error: {x:?}"),
     };
 }
+
+#[test]
+fn single_query_without_results_is_specific_error() {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_308).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let (account_id, _account_keypair) = gen_account_in("wonderland");
+    let err = client
+        .build_query(client::account::all())
+        .with_filter(PredicateBox::new(
+            value::QueryOutputPredicate::Identifiable(string::StringPredicate::is(
+                &account_id.to_string(),
+            )),
+        ))
+        .execute_single()
+        .expect_err("Account isn't registered");
+
+    assert!(matches!(err, SingleQueryError::NoResults), "{err:?}");
+}
+
+#[test]
+fn single_query_with_several_results_reports_count() {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_309).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let domain_id: DomainId = "crowd".parse().expect("Valid");
+    let register_accounts = (0..3)
+        .map(|_| Register::account(Account::new(gen_account_in("crowd").0)))
+        .map(InstructionBox::from);
+    client
+        .submit_all_blocking(
+            std::iter::once(Register::domain(Domain::new(domain_id)).into())
+                .chain(register_accounts),
+        )
+        .expect("Valid");
+
+    let err = client
+        .build_query(client::account::all())
+        .with_filter(PredicateBox::new(
+            value::QueryOutputPredicate::Identifiable(string::StringPredicate::ends_with("@crowd")),
+        ))
+        .execute_single()
+        .expect_err("Several accounts match");
+
+    assert!(
+        matches!(err, SingleQueryError::TooManyResults(3)),
+        "{err:?}"
+    );
+}
@@ -26,7 +26,7 @@ fn live_query_is_dropped_after_smart_contract_end() -> Result<()> {
     let transaction = client.build_transaction(
         WasmSmartContract::from_compiled(wasm),
         UnlimitedMetadata::default(),
-    );
+    )?;
     client.submit_transaction_blocking(&transaction)?;
 
     let metadata_value = client.request(FindAccountKeyValueByIdAndKey::new(
@@ -66,7 +66,7 @@ fn smart_contract_can_filter_queries() -> Result<()> {
     let transaction = client.build_transaction(
         WasmSmartContract::from_compiled(wasm),
         UnlimitedMetadata::default(),
-    );
+    )?;
     client.submit_transaction_blocking(&transaction)?;
 
     Ok(())
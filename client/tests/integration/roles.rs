@@ -5,7 +5,9 @@ use iroha::{
     client::{self, QueryResult},
     data_model::prelude::*,
 };
-use iroha_data_model::transaction::error::TransactionRejectionReason;
+use iroha_data_model::{
+    isi::error::IndexedInstructionError, transaction::error::TransactionRejectionReason,
+};
 use serde_json::json;
 use test_network::*;
 use test_samples::{gen_account_in, ALICE_ID};
@@ -164,7 +166,9 @@ fn role_with_invalid_permissions_is_not_accepted() -> Result<()> {
 
     assert!(matches!(
         rejection_reason,
-        &TransactionRejectionReason::Validation(ValidationFail::NotPermitted(_))
+        TransactionRejectionReason::Validation(ValidationFail::TransactionInstructionFailed(
+            IndexedInstructionError { index: 0, error }
+        )) if matches!(**error, ValidationFail::NotPermitted(_))
     ));
 
     Ok(())
@@ -3,7 +3,7 @@ use std::str::FromStr;
 use eyre::Result;
 use iroha::{
     client::{self, QueryResult},
-    data_model::prelude::*,
+    data_model::{parameter::ParameterValueBox, prelude::*},
 };
 use test_network::*;
 
@@ -40,6 +40,91 @@ fn can_change_parameter_value() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn can_change_parameter_value_and_query_it_by_subsystem() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_301).start_with_runtime();
+    wait_for_genesis_committed(&vec![test_client.clone()], 0);
+
+    let parameter = Parameter::from_str("?BlockTime=4000")?;
+    let parameter_id = ParameterId::from_str("BlockTime")?;
+    let param_box = SetParameter::new(parameter.clone());
+
+    test_client.submit_blocking(param_box)?;
+
+    let sumeragi_params = test_client
+        .request(client::parameter::sumeragi())?
+        .collect::<QueryResult<Vec<_>>>()?;
+    assert!(sumeragi_params
+        .iter()
+        .all(|param| ParameterSubsystem::of(param.id()) == Some(ParameterSubsystem::Sumeragi)));
+    let param_new = sumeragi_params
+        .iter()
+        .find(|param| param.id() == &parameter_id)
+        .expect("Parameter should exist");
+    assert_eq!(param_new.val(), parameter.val());
+
+    let transaction_params = test_client
+        .request(client::parameter::transaction())?
+        .collect::<QueryResult<Vec<_>>>()?;
+    assert!(transaction_params
+        .iter()
+        .all(|param| param.id() != &parameter_id));
+    Ok(())
+}
+
+#[test]
+fn can_change_several_parameters_at_once() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_302).start_with_runtime();
+    wait_for_genesis_committed(&vec![test_client.clone()], 0);
+
+    let parameters = [
+        Parameter::from_str("?BlockTime=4000")?,
+        Parameter::from_str("?CommitTimeLimit=8000")?,
+    ];
+
+    test_client.submit_blocking(SetParameters::new(parameters.clone()))?;
+
+    let new_params = test_client
+        .request(client::parameter::sumeragi())?
+        .collect::<QueryResult<Vec<_>>>()?;
+    for parameter in parameters {
+        let param_new = new_params
+            .iter()
+            .find(|param| param.id() == parameter.id())
+            .expect("Parameter should exist");
+        assert_eq!(param_new.val(), parameter.val());
+    }
+    Ok(())
+}
+
+#[test]
+fn submit_blocking_returns_height_of_the_block_with_transaction() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_265).start_with_runtime();
+    wait_for_genesis_committed(&vec![test_client.clone()], 0);
+
+    let parameter = Parameter::from_str("?BlockTime=4000")?;
+    let param_box = SetParameter::new(parameter);
+    let expected_instruction = InstructionBox::from(param_box.clone());
+
+    let height = test_client.submit_blocking_with_height(param_box)?;
+
+    let block = test_client
+        .request(client::block::all())?
+        .collect::<QueryResult<Vec<_>>>()?
+        .into_iter()
+        .find(|block| block.header().height() == height)
+        .expect("Block with the returned height should exist");
+    let changed_parameter = block.transactions().any(|tx| {
+        tx.error.is_none()
+            && matches!(
+                tx.as_ref().instructions(),
+                Executable::Instructions(instructions) if instructions.contains(&expected_instruction)
+            )
+    });
+    assert!(changed_parameter);
+    Ok(())
+}
+
 #[test]
 fn parameter_propagated() -> Result<()> {
     let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(10_985).start_with_runtime();
@@ -60,3 +145,76 @@ fn parameter_propagated() -> Result<()> {
         .expect("Should work after ident length limits update");
     Ok(())
 }
+
+#[test]
+fn assert_query_eq_reports_parameter_difference() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_275).start_with_runtime();
+    wait_for_genesis_committed(&vec![test_client.clone()], 0);
+
+    let old_params = test_client
+        .request(client::parameter::all())?
+        .collect::<QueryResult<Vec<_>>>()?;
+    let parameter = Parameter::from_str("?BlockTime=4000")?;
+    test_client.submit_blocking(SetParameter::new(parameter.clone()))?;
+
+    let new_params = old_params
+        .iter()
+        .map(|param| {
+            if param.id() == parameter.id() {
+                parameter.clone()
+            } else {
+                param.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+    assert_query_eq(&test_client, client::parameter::all(), &new_params)?;
+
+    let changed_index = old_params
+        .iter()
+        .position(|param| param.id() == parameter.id())
+        .expect("Parameter should exist");
+    let error = assert_query_eq(&test_client, client::parameter::all(), &old_params)
+        .expect_err("Parameter value was changed");
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "Query result doesn't match the expected value:\n\
+             result[{changed_index}]: expected `\"{}\"`, got `\"{parameter}\"`",
+            old_params[changed_index]
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn set_parameter_if_applies_only_on_expected_value() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_285).start_with_runtime();
+    wait_for_genesis_committed(&vec![test_client.clone()], 0);
+
+    let parameter_id = ParameterId::from_str("BlockTime")?;
+    let current_value = |test_client: &client::Client| -> Result<ParameterValueBox> {
+        Ok(test_client
+            .request(client::parameter::all())?
+            .collect::<QueryResult<Vec<_>>>()?
+            .into_iter()
+            .find(|param| param.id() == &parameter_id)
+            .expect("Parameter should exist")
+            .val()
+            .clone())
+    };
+    let old_value = current_value(&test_client)?;
+
+    let stale_guard = SetParameterIf::new(
+        Parameter::from_str("?BlockTime=5000")?,
+        ParameterValueBox::Numeric(numeric!(1)),
+    );
+    let _ = test_client
+        .submit_blocking(stale_guard)
+        .expect_err("Should fail when the current value doesn't match");
+    assert_eq!(current_value(&test_client)?, old_value);
+
+    let parameter = Parameter::from_str("?BlockTime=4000")?;
+    test_client.submit_blocking(SetParameterIf::new(parameter.clone(), old_value))?;
+    assert_eq!(&current_value(&test_client)?, parameter.val());
+    Ok(())
+}
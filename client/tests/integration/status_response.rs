@@ -1,7 +1,7 @@
-use std::str::FromStr as _;
+use std::{num::NonZeroU32, str::FromStr as _, time::Duration};
 
 use eyre::Result;
-use iroha::{data_model::prelude::*, samples::get_status_json};
+use iroha::{client::status::block_timing, data_model::prelude::*, samples::get_status_json};
 use iroha_telemetry::metrics::Status;
 use test_network::*;
 use test_samples::gen_account_in;
@@ -55,3 +55,25 @@ fn json_and_scale_statuses_equality() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn block_timing_returns_deltas_between_last_blocks() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_280).start_with_runtime();
+    wait_for_genesis_committed(&vec![client.clone()], 0);
+
+    for domain in ["looking_glass", "garden", "tulgey_wood"] {
+        client.submit_blocking(Register::domain(Domain::new(domain.parse()?)))?;
+    }
+
+    let last_n = NonZeroU32::new(4).unwrap();
+    let deltas = block_timing(&client, last_n)?;
+    assert_eq!(deltas.len(), 3);
+    for delta in deltas {
+        assert!(
+            delta < Duration::from_secs(60),
+            "Implausible block time: {delta:?}"
+        );
+    }
+
+    Ok(())
+}
@@ -1,4 +1,9 @@
-use std::{str::FromStr as _, sync::mpsc, thread, time::Duration};
+use std::{
+    str::FromStr as _,
+    sync::{mpsc, Arc},
+    thread,
+    time::Duration,
+};
 
 use eyre::{eyre, Result, WrapErr};
 use iroha::{
@@ -7,8 +12,10 @@ use iroha::{
         prelude::*,
         query::error::{FindError, QueryExecutionFail},
         transaction::{Executable, WasmSmartContract},
+        Level,
     },
 };
+use iroha_config::parameters::defaults::chain_wide::WASM_FUEL_LIMIT;
 use iroha_crypto::KeyPair;
 use iroha_genesis::GenesisNetwork;
 use iroha_logger::info;
@@ -282,7 +289,7 @@ fn only_account_with_permission_can_register_trigger() -> Result<()> {
 
     let mut rabbit_client = test_client.clone();
     rabbit_client.account_id = rabbit_account_id.clone();
-    rabbit_client.key_pair = rabbit_keys;
+    let rabbit_client = rabbit_client.with_signer(Arc::new(rabbit_keys))?;
 
     // Permission token for the trigger registration
     // on behalf of alice
@@ -617,6 +624,60 @@ fn unregistering_one_of_two_triggers_with_identical_wasm_should_not_cause_origin
     Ok(())
 }
 
+#[test]
+fn triggers_exceeding_block_fuel_are_deferred_to_next_blocks() -> Result<()> {
+    let (_rt, _peer, mut test_client) = <PeerBuilder>::new().with_port(11_295).start_with_runtime();
+    wait_for_genesis_committed(&vec![test_client.clone()], 0);
+
+    let account_id = ALICE_ID.clone();
+    let asset_id = AssetId::new("rose#wonderland".parse()?, account_id.clone());
+    let trigger_id = TriggerId::from_str("mint_rose_within_block_fuel")?;
+
+    let wasm =
+        iroha_wasm_builder::Builder::new("tests/integration/smartcontracts/mint_rose_trigger")
+            .show_output()
+            .build()?
+            .optimize()?
+            .into_bytes()?;
+    let trigger = Trigger::new(
+        trigger_id.clone(),
+        Action::new(
+            WasmSmartContract::from_compiled(wasm),
+            Repeats::Indefinitely,
+            account_id.clone(),
+            ExecuteTriggerEventFilter::new()
+                .for_trigger(trigger_id.clone())
+                .under_authority(account_id),
+        ),
+    );
+    // Block fuel covers a single execution, the next one isn't guaranteed to fit
+    let max_block_fuel = Parameter::from_str(&format!("?MaxBlockFuel={WASM_FUEL_LIMIT}"))?;
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::trigger(trigger).into(),
+        SetKeyValue::trigger(trigger_id.clone(), "VAL".parse()?, numeric!(1)).into(),
+        NewParameter::new(max_block_fuel).into(),
+    ])?;
+    let prev_value = get_asset_value(&mut test_client, asset_id.clone());
+
+    let call_trigger = ExecuteTrigger::new(trigger_id);
+    test_client.submit_all_blocking([call_trigger.clone(), call_trigger.clone(), call_trigger])?;
+    assert_eq!(
+        get_asset_value(&mut test_client, asset_id.clone()),
+        prev_value.checked_add(numeric!(1)).unwrap()
+    );
+
+    // Deferred executions spill over into the following blocks, one per block
+    for minted in [numeric!(2), numeric!(3)] {
+        test_client.submit_blocking(Log::new(Level::DEBUG, "Just to create block".to_string()))?;
+        assert_eq!(
+            get_asset_value(&mut test_client, asset_id.clone()),
+            prev_value.checked_add(minted).unwrap()
+        );
+    }
+
+    Ok(())
+}
+
 fn get_asset_value(client: &mut Client, asset_id: AssetId) -> Numeric {
     let asset = client.request(client::asset::by_id(asset_id)).unwrap();
 
@@ -3,7 +3,7 @@ use std::{str::FromStr as _, thread};
 use eyre::Result;
 use iroha::{
     client::{transaction, QueryResult},
-    data_model::{prelude::*, query::Pagination},
+    data_model::{prelude::*, query::Pagination, Level},
 };
 use iroha_config::parameters::actual::Root as Config;
 use nonzero_ext::nonzero;
@@ -46,7 +46,7 @@ fn client_has_rejected_and_acepted_txs_should_return_tx_history() -> Result<()>
             &mint_not_existed_asset
         };
         let instructions: Vec<InstructionBox> = vec![mint_asset.clone().into()];
-        let transaction = client.build_transaction(instructions, UnlimitedMetadata::new());
+        let transaction = client.build_transaction(instructions, UnlimitedMetadata::new())?;
         client.submit_transaction(&transaction)?;
     }
     thread::sleep(pipeline_time * 5);
@@ -74,3 +74,26 @@ fn client_has_rejected_and_acepted_txs_should_return_tx_history() -> Result<()>
         });
     Ok(())
 }
+
+#[test]
+fn transaction_metadata_is_preserved_in_committed_block() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_270).start_with_runtime();
+    wait_for_genesis_committed(&vec![client.clone()], 0);
+
+    let reference_id = Name::from_str("reference_id")?;
+    let mut metadata = UnlimitedMetadata::new();
+    metadata.insert(reference_id.clone(), "ref-42".to_owned().into());
+
+    let log = Log::new(Level::INFO, "annotated transaction".to_owned());
+    let transaction = client.build_transaction([log], metadata.clone())?;
+    let hash = client.submit_transaction_blocking(&transaction)?;
+
+    let committed = client.request(transaction::by_hash(hash))?;
+    let committed_transaction: &SignedTransaction = committed.as_ref().as_ref();
+    assert_eq!(committed_transaction.metadata(), &metadata);
+    assert_eq!(
+        committed_transaction.metadata().get(&reference_id),
+        Some(&"ref-42".to_owned().into())
+    );
+    Ok(())
+}
@@ -3,7 +3,7 @@ use std::str::FromStr as _;
 use eyre::Result;
 use iroha::{
     client::{self, QueryResult},
-    data_model::prelude::*,
+    data_model::{prelude::*, transaction::error::TransactionRejectionReason},
 };
 use test_network::*;
 use test_samples::ALICE_ID;
@@ -39,3 +39,61 @@ fn client_sends_transaction_with_invalid_instruction_should_not_see_any_changes(
         .all(|asset| *asset.id() != wrong_asset_definition_id));
     Ok(())
 }
+
+#[test]
+fn rejected_transaction_in_batch_does_not_affect_others() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_310).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let first_domain_id = DomainId::from_str("first")?;
+    let second_domain_id = DomainId::from_str("second")?;
+    let missing_domain_asset_id = AssetDefinitionId::from_str("xor#missing")?;
+    let transactions: Vec<Vec<InstructionBox>> = vec![
+        vec![Register::domain(Domain::new(first_domain_id.clone())).into()],
+        vec![Register::asset_definition(AssetDefinition::numeric(missing_domain_asset_id)).into()],
+        vec![Register::domain(Domain::new(second_domain_id.clone())).into()],
+    ];
+
+    let results = client.submit_transactions_blocking(transactions);
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok(), "{:?}", results[0]);
+    assert!(results[1]
+        .as_ref()
+        .expect_err("Domain doesn't exist")
+        .downcast_ref::<TransactionRejectionReason>()
+        .is_some());
+    assert!(results[2].is_ok(), "{:?}", results[2]);
+    client.request(client::domain::by_id(first_domain_id))?;
+    client.request(client::domain::by_id(second_domain_id))?;
+    Ok(())
+}
+
+#[test]
+fn rejected_transaction_reports_failed_instruction_index() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_314).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let missing_domain_asset_id = AssetDefinitionId::from_str("xor#missing")?;
+    let instructions: [InstructionBox; 3] = [
+        Register::domain(Domain::new(DomainId::from_str("first")?)).into(),
+        Register::domain(Domain::new(DomainId::from_str("second")?)).into(),
+        Register::asset_definition(AssetDefinition::numeric(missing_domain_asset_id)).into(),
+    ];
+
+    let err = client
+        .submit_all_blocking(instructions)
+        .expect_err("Asset definition domain doesn't exist");
+    let rejection_reason = err
+        .downcast_ref::<TransactionRejectionReason>()
+        .unwrap_or_else(|| panic!("Error {err} is not TransactionRejectionReason"));
+
+    assert!(matches!(
+        rejection_reason,
+        TransactionRejectionReason::InstructionFailed(FailedInstruction { index: 2, .. })
+    ));
+    assert!(client
+        .request(client::domain::by_id(DomainId::from_str("first")?))
+        .is_err());
+    Ok(())
+}
@@ -4,7 +4,7 @@ use eyre::Result;
 use futures_util::TryStreamExt as _;
 use iroha::{
     client::{self, Client, QueryResult},
-    crypto::KeyPair,
+    crypto::{HashOf, KeyPair},
     data_model::prelude::*,
 };
 use iroha_logger::info;
@@ -242,21 +242,22 @@ fn migration_should_cause_upgrade_event() {
             .unwrap();
         while let Some(event) = stream.try_next().await.unwrap() {
             if let EventBox::Data(DataEvent::Executor(ExecutorEvent::Upgraded(ExecutorUpgrade {
+                new_executor_hash,
                 new_data_model,
             }))) = event
             {
-                let _ = sender.send(new_data_model).await;
+                let _ = sender.send((new_executor_hash, new_data_model)).await;
             }
         }
     });
 
-    upgrade_executor(
+    let executor_hash = upgrade_executor(
         &client,
         "tests/integration/smartcontracts/executor_with_custom_permission",
     )
     .unwrap();
 
-    let data_model = rt
+    let (new_executor_hash, data_model) = rt
         .block_on(async {
             tokio::time::timeout(std::time::Duration::from_secs(60), receiver.recv()).await
         })
@@ -264,10 +265,11 @@ fn migration_should_cause_upgrade_event() {
         .flatten()
         .expect("should receive upgraded event immediately after upgrade");
 
+    assert_eq!(new_executor_hash, executor_hash);
     assert!(!data_model.permissions.is_empty());
 }
 
-fn upgrade_executor(client: &Client, executor: impl AsRef<Path>) -> Result<()> {
+fn upgrade_executor(client: &Client, executor: impl AsRef<Path>) -> Result<HashOf<Executor>> {
     info!("Building executor");
 
     let wasm = iroha_wasm_builder::Builder::new(executor.as_ref())
@@ -278,8 +280,9 @@ fn upgrade_executor(client: &Client, executor: impl AsRef<Path>) -> Result<()> {
 
     info!("WASM size is {} bytes", wasm.len());
 
-    let upgrade_executor = Upgrade::new(Executor::new(WasmSmartContract::from_compiled(wasm)));
-    client.submit_blocking(upgrade_executor)?;
+    let executor = Executor::new(WasmSmartContract::from_compiled(wasm));
+    let executor_hash = HashOf::new(&executor);
+    client.submit_blocking(Upgrade::new(executor))?;
 
-    Ok(())
+    Ok(executor_hash)
 }
@@ -241,7 +241,7 @@ fn submit(
 ) -> Result<()> {
     let iroha = context.client_from_config();
     let instructions = instructions.into();
-    let tx = iroha.build_transaction(instructions, metadata);
+    let tx = iroha.build_transaction(instructions, metadata)?;
 
     #[cfg(not(debug_assertions))]
     let err_msg = "Failed to submit transaction.";
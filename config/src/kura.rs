@@ -26,9 +26,31 @@ pub enum InitMode {
     Fast,
 }
 
+/// Codec used by Kura to compress stored blocks.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    strum::EnumString,
+    strum::Display,
+    DeserializeFromStr,
+    SerializeDisplay,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum BlockCodec {
+    /// Store blocks uncompressed.
+    #[default]
+    None,
+    /// Compress blocks with zstd.
+    Zstd,
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::kura::InitMode;
+    use crate::kura::{BlockCodec, InitMode};
 
     #[test]
     fn init_mode_display_reprs() {
@@ -37,4 +59,12 @@ mod tests {
         assert_eq!("strict".parse::<InitMode>().unwrap(), InitMode::Strict);
         assert_eq!("fast".parse::<InitMode>().unwrap(), InitMode::Fast);
     }
+
+    #[test]
+    fn block_codec_display_reprs() {
+        assert_eq!(format!("{}", BlockCodec::None), "none");
+        assert_eq!(format!("{}", BlockCodec::Zstd), "zstd");
+        assert_eq!("none".parse::<BlockCodec>().unwrap(), BlockCodec::None);
+        assert_eq!("zstd".parse::<BlockCodec>().unwrap(), BlockCodec::Zstd);
+    }
 }
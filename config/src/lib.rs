@@ -7,6 +7,7 @@ use tracing::log::SetLoggerError;
 pub mod client_api;
 pub mod kura;
 pub mod logger;
+pub mod naming;
 pub mod parameters;
 pub mod snapshot;
 
@@ -0,0 +1,75 @@
+//! Configuration tools related to the naming of registered entities.
+
+use std::{fmt, str::FromStr};
+
+use regex::Regex;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+/// Policy which names of registered entities have to conform to.
+///
+/// It is a regular expression which has to match the whole name, i.e. it is implicitly anchored.
+#[derive(Clone, DeserializeFromStr, SerializeDisplay)]
+pub struct NamingPolicy {
+    pattern: String,
+    regex: Regex,
+}
+
+impl NamingPolicy {
+    /// Check if `name` conforms to the policy
+    pub fn is_match(&self, name: &str) -> bool {
+        self.regex.is_match(name)
+    }
+}
+
+impl FromStr for NamingPolicy {
+    type Err = regex::Error;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        let regex = Regex::new(&format!("^(?:{pattern})$"))?;
+
+        Ok(Self {
+            pattern: pattern.to_owned(),
+            regex,
+        })
+    }
+}
+
+impl fmt::Display for NamingPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+impl fmt::Debug for NamingPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NamingPolicy").field(&self.pattern).finish()
+    }
+}
+
+impl PartialEq for NamingPolicy {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Eq for NamingPolicy {}
+
+#[cfg(test)]
+mod tests {
+    use super::NamingPolicy;
+
+    #[test]
+    fn naming_policy_matches_whole_name() {
+        let policy: NamingPolicy = "[a-z0-9]+".parse().unwrap();
+
+        assert!(policy.is_match("wonderland42"));
+        assert!(!policy.is_match("Wonderland"));
+        assert!(!policy.is_match("wonder land"));
+        assert_eq!(policy.to_string(), "[a-z0-9]+");
+    }
+
+    #[test]
+    fn invalid_naming_policy_is_rejected() {
+        assert!("[a-z".parse::<NamingPolicy>().is_err());
+    }
+}
@@ -20,7 +20,8 @@ use url::Url;
 pub use user::{DevTelemetry, Logger, Snapshot};
 
 use crate::{
-    kura::InitMode,
+    kura::{BlockCodec, InitMode},
+    naming::NamingPolicy,
     parameters::{defaults, user},
 };
 
@@ -138,6 +139,7 @@ pub struct Queue {
 pub struct Kura {
     pub init_mode: InitMode,
     pub store_dir: WithOrigin<PathBuf>,
+    pub block_codec: BlockCodec,
     pub debug_output_new_blocks: bool,
 }
 
@@ -209,12 +211,13 @@ pub struct TransactionGossiper {
     pub gossip_max_size: NonZeroU32,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct ChainWide {
     pub max_transactions_in_block: NonZeroU32,
     pub block_time: Duration,
     pub commit_time: Duration,
+    pub max_clock_skew: Duration,
     pub transaction_limits: TransactionLimits,
     pub domain_metadata_limits: MetadataLimits,
     pub asset_definition_metadata_limits: MetadataLimits,
@@ -222,8 +225,11 @@ pub struct ChainWide {
     pub asset_metadata_limits: MetadataLimits,
     pub trigger_metadata_limits: MetadataLimits,
     pub ident_length_limits: LengthLimits,
+    pub ident_naming_policy: Option<NamingPolicy>,
     pub executor_runtime: WasmRuntime,
     pub wasm_runtime: WasmRuntime,
+    pub max_block_fuel: u64,
+    pub deterministic_tx_order: bool,
 }
 
 impl ChainWide {
@@ -239,6 +245,7 @@ impl Default for ChainWide {
             max_transactions_in_block: defaults::chain_wide::MAX_TXS,
             block_time: defaults::chain_wide::BLOCK_TIME,
             commit_time: defaults::chain_wide::COMMIT_TIME,
+            max_clock_skew: defaults::chain_wide::MAX_CLOCK_SKEW,
             transaction_limits: defaults::chain_wide::TRANSACTION_LIMITS,
             domain_metadata_limits: defaults::chain_wide::METADATA_LIMITS,
             account_metadata_limits: defaults::chain_wide::METADATA_LIMITS,
@@ -246,8 +253,11 @@ impl Default for ChainWide {
             asset_metadata_limits: defaults::chain_wide::METADATA_LIMITS,
             trigger_metadata_limits: defaults::chain_wide::METADATA_LIMITS,
             ident_length_limits: defaults::chain_wide::IDENT_LENGTH_LIMITS,
+            ident_naming_policy: None,
             executor_runtime: WasmRuntime::default(),
             wasm_runtime: WasmRuntime::default(),
+            max_block_fuel: defaults::chain_wide::MAX_BLOCK_FUEL,
+            deterministic_tx_order: false,
         }
     }
 }
@@ -34,8 +34,9 @@ use serde::Deserialize;
 use url::Url;
 
 use crate::{
-    kura::InitMode as KuraInitMode,
+    kura::{BlockCodec as KuraBlockCodec, InitMode as KuraInitMode},
     logger::Format as LoggerFormat,
+    naming::NamingPolicy,
     parameters::{actual, defaults},
     snapshot::Mode as SnapshotMode,
 };
@@ -221,6 +222,8 @@ pub struct Kura {
         default = "PathBuf::from(defaults::kura::STORE_DIR)"
     )]
     pub store_dir: WithOrigin<PathBuf>,
+    #[config(env = "KURA_BLOCK_CODEC", default)]
+    pub block_codec: KuraBlockCodec,
     #[config(nested)]
     pub debug: KuraDebug,
 }
@@ -230,6 +233,7 @@ impl Kura {
         let Self {
             init_mode,
             store_dir,
+            block_codec,
             debug:
                 KuraDebug {
                     output_new_blocks: debug_output_new_blocks,
@@ -239,6 +243,7 @@ impl Kura {
         actual::Kura {
             init_mode,
             store_dir,
+            block_codec,
             debug_output_new_blocks,
         }
     }
@@ -468,7 +473,7 @@ pub struct Snapshot {
 }
 
 // TODO: make serde
-#[derive(Debug, Copy, Clone, ReadConfig)]
+#[derive(Debug, Clone, ReadConfig)]
 pub struct ChainWide {
     #[config(default = "defaults::chain_wide::MAX_TXS")]
     pub max_transactions_in_block: NonZeroU32,
@@ -476,6 +481,9 @@ pub struct ChainWide {
     pub block_time: HumanDuration,
     #[config(default = "defaults::chain_wide::COMMIT_TIME.into()")]
     pub commit_time: HumanDuration,
+    /// Transactions created further than this from the block time (in either direction) are rejected.
+    #[config(default = "defaults::chain_wide::MAX_CLOCK_SKEW.into()")]
+    pub max_clock_skew: HumanDuration,
     #[config(default = "defaults::chain_wide::TRANSACTION_LIMITS")]
     pub transaction_limits: TransactionLimits,
     #[config(default = "defaults::chain_wide::METADATA_LIMITS")]
@@ -490,6 +498,8 @@ pub struct ChainWide {
     pub trigger_metadata_limits: MetadataLimits,
     #[config(default = "defaults::chain_wide::IDENT_LENGTH_LIMITS")]
     pub ident_length_limits: LengthLimits,
+    /// Regular expression which names of registered domains and asset definitions must match.
+    pub ident_naming_policy: Option<NamingPolicy>,
     #[config(default = "defaults::chain_wide::WASM_FUEL_LIMIT")]
     pub executor_fuel_limit: u64,
     #[config(default = "defaults::chain_wide::WASM_MAX_MEMORY_BYTES")]
@@ -498,6 +508,12 @@ pub struct ChainWide {
     pub wasm_fuel_limit: u64,
     #[config(default = "defaults::chain_wide::WASM_MAX_MEMORY_BYTES")]
     pub wasm_max_memory: u32,
+    /// Fuel all the triggers of a block can consume, triggers which may not fit into the remaining fuel are deferred to the next block.
+    #[config(default = "defaults::chain_wide::MAX_BLOCK_FUEL")]
+    pub max_block_fuel: u64,
+    /// Order transactions of blocks built by this peer by their hash instead of their arrival, so that the same set of transactions always yields the same block.
+    #[config(default)]
+    pub deterministic_tx_order: bool,
 }
 
 impl ChainWide {
@@ -506,6 +522,7 @@ impl ChainWide {
             max_transactions_in_block,
             block_time,
             commit_time,
+            max_clock_skew,
             transaction_limits,
             asset_metadata_limits,
             trigger_metadata_limits,
@@ -513,16 +530,20 @@ impl ChainWide {
             account_metadata_limits,
             domain_metadata_limits,
             ident_length_limits,
+            ident_naming_policy,
             executor_fuel_limit,
             executor_max_memory,
             wasm_fuel_limit,
             wasm_max_memory,
+            max_block_fuel,
+            deterministic_tx_order,
         } = self;
 
         actual::ChainWide {
             max_transactions_in_block,
             block_time: block_time.get(),
             commit_time: commit_time.get(),
+            max_clock_skew: max_clock_skew.get(),
             transaction_limits,
             asset_metadata_limits,
             trigger_metadata_limits,
@@ -530,6 +551,7 @@ impl ChainWide {
             account_metadata_limits,
             domain_metadata_limits,
             ident_length_limits,
+            ident_naming_policy,
             executor_runtime: actual::WasmRuntime {
                 fuel_limit: executor_fuel_limit,
                 max_memory_bytes: executor_max_memory,
@@ -538,6 +560,8 @@ impl ChainWide {
                 fuel_limit: wasm_fuel_limit,
                 max_memory_bytes: wasm_max_memory,
             },
+            max_block_fuel,
+            deterministic_tx_order,
         }
     }
 }
@@ -124,6 +124,7 @@ fn minimal_config_snapshot() {
                         id: ParameterId(kura.store_dir),
                     },
                 },
+                block_codec: None,
                 debug_output_new_blocks: false,
             },
             sumeragi: Sumeragi {
@@ -198,6 +199,7 @@ fn minimal_config_snapshot() {
                 max_transactions_in_block: 512,
                 block_time: 2s,
                 commit_time: 4s,
+                max_clock_skew: 86400s,
                 transaction_limits: TransactionLimits {
                     max_instruction_number: 4096,
                     max_wasm_size_bytes: 4194304,
@@ -226,6 +228,7 @@ fn minimal_config_snapshot() {
                     min: 1,
                     max: 128,
                 },
+                ident_naming_policy: None,
                 executor_runtime: WasmRuntime {
                     fuel_limit: 55000000,
                     max_memory_bytes: 524288000,
@@ -234,6 +237,8 @@ fn minimal_config_snapshot() {
                     fuel_limit: 55000000,
                     max_memory_bytes: 524288000,
                 },
+                max_block_fuel: 18446744073709551615,
+                deterministic_tx_order: false,
             },
         }"#]].assert_eq(&format!("{config:#?}"));
 }
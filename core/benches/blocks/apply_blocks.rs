@@ -1,6 +1,5 @@
 use eyre::Result;
 use iroha_core::{block::CommittedBlock, prelude::*, state::State};
-use test_samples::gen_account_in;
 
 #[path = "./common.rs"]
 mod common;
@@ -23,19 +22,22 @@ impl StateApplyBlocks {
         let domains = 100;
         let accounts_per_domain = 1000;
         let assets_per_domain = 1000;
-        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (alice_id, alice_keypair) = deterministic_account_in("wonderland", b"alice");
         let state = build_state(rt, &alice_id);
 
         let nth = 100;
+        let transfers = 10_000;
         let instructions = [
             populate_state(domains, accounts_per_domain, assets_per_domain, &alice_id),
             delete_every_nth(domains, accounts_per_domain, assets_per_domain, nth),
             restore_every_nth(domains, accounts_per_domain, assets_per_domain, nth),
+            transfer_round_robin(domains, accounts_per_domain, assets_per_domain, transfers),
         ];
 
         let blocks = {
-            // Create empty state because it will be changed during creation of block
-            let state = build_state(rt, &alice_id);
+            // Create empty state because it will be changed during creation of block.
+            // Blocks are applied without execution, so permission checks can be skipped here
+            let state = build_state_bare(rt, &alice_id);
             instructions
                 .into_iter()
                 .map(|instructions| {
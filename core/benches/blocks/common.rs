@@ -1,7 +1,10 @@
-use std::str::FromStr as _;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr as _,
+};
 
 use iroha_core::{
-    block::{BlockBuilder, CommittedBlock},
+    block::{BlockBuilder, Chained, CommittedBlock},
     prelude::*,
     query::store::LiveQueryStore,
     smartcontracts::{Execute, Registrable as _},
@@ -10,15 +13,18 @@ use iroha_core::{
 };
 use iroha_data_model::{
     account::Account,
-    asset::{AssetDefinition, AssetDefinitionId},
+    asset::{AssetDefinition, AssetDefinitionId, AssetValueType},
     domain::Domain,
     isi::InstructionBox,
+    parameter::{
+        default::{EXECUTOR_FUEL_LIMIT, EXECUTOR_MAX_MEMORY, TRANSACTION_LIMITS},
+        ParameterValueBox,
+    },
     prelude::*,
     transaction::TransactionLimits,
-    ChainId, JsonString,
+    ChainId,
 };
 use iroha_primitives::unique_vec::UniqueVec;
-use serde_json::json;
 
 /// Create block
 pub fn create_block(
@@ -27,6 +33,30 @@ pub fn create_block(
     account_id: AccountId,
     key_pair: &KeyPair,
 ) -> CommittedBlock {
+    let topology = Topology::new(UniqueVec::new());
+    let block = build_unsigned_block(state, instructions, account_id, key_pair)
+        .sign(key_pair)
+        .unpack(|_| {})
+        .commit(&topology)
+        .unpack(|_| {})
+        .unwrap();
+
+    // Verify that transactions are valid
+    for tx in block.as_ref().transactions() {
+        assert_eq!(tx.error, None);
+    }
+
+    block
+}
+
+/// Create block chained with the state, but neither signed nor committed,
+/// so that validation can be measured separately from signing
+pub fn build_unsigned_block(
+    state: &mut StateBlock<'_>,
+    instructions: Vec<InstructionBox>,
+    account_id: AccountId,
+    key_pair: &KeyPair,
+) -> BlockBuilder<Chained> {
     let chain_id = ChainId::from("0");
 
     let transaction = TransactionBuilder::new(chain_id.clone(), account_id)
@@ -37,18 +67,13 @@ pub fn create_block(
     let topology = Topology::new(UniqueVec::new());
     let block = BlockBuilder::new(
         vec![AcceptedTransaction::accept(transaction, &chain_id, &limits).unwrap()],
-        topology.clone(),
+        topology,
         Vec::new(),
     )
-    .chain(0, state)
-    .sign(key_pair)
-    .unpack(|_| {})
-    .commit(&topology)
-    .unpack(|_| {})
-    .unwrap();
+    .chain(0, state);
 
-    // Verify that transactions are valid
-    for tx in block.as_ref().transactions() {
+    // Verify that transactions are valid before the block is signed
+    for tx in block.transactions() {
         assert_eq!(tx.error, None);
     }
 
@@ -60,6 +85,23 @@ pub fn populate_state(
     accounts_per_domain: usize,
     assets_per_domain: usize,
     owner_id: &AccountId,
+) -> Vec<InstructionBox> {
+    populate_state_with_value_type(
+        domains,
+        accounts_per_domain,
+        assets_per_domain,
+        owner_id,
+        AssetValueType::Numeric(NumericSpec::default()),
+    )
+}
+
+/// Same as [`populate_state`], but registers asset definitions of the given `value_type`
+pub fn populate_state_with_value_type(
+    domains: usize,
+    accounts_per_domain: usize,
+    assets_per_domain: usize,
+    owner_id: &AccountId,
+    value_type: AssetValueType,
 ) -> Vec<InstructionBox> {
     let mut instructions: Vec<InstructionBox> = Vec::new();
     for i in 0..domains {
@@ -67,10 +109,7 @@ pub fn populate_state(
         let domain = Domain::new(domain_id.clone());
         instructions.push(Register::domain(domain).into());
         let can_unregister_domain = Grant::permission(
-            Permission::new(
-                "CanUnregisterDomain".parse().unwrap(),
-                JsonString::from(&json!({ "domain_id": domain_id.clone() })),
-            ),
+            Permission::can_unregister_domain(domain_id.clone()),
             owner_id.clone(),
         );
         instructions.push(can_unregister_domain.into());
@@ -79,23 +118,17 @@ pub fn populate_state(
             let account = Account::new(account_id.clone());
             instructions.push(Register::account(account).into());
             let can_unregister_account = Grant::permission(
-                Permission::new(
-                    "CanUnregisterAccount".parse().unwrap(),
-                    JsonString::from(&json!({ "account_id": account_id.clone() })),
-                ),
+                Permission::can_unregister_account(account_id.clone()),
                 owner_id.clone(),
             );
             instructions.push(can_unregister_account.into());
         }
         for k in 0..assets_per_domain {
             let asset_definition_id = construct_asset_definition_id(k, domain_id.clone());
-            let asset_definition = AssetDefinition::numeric(asset_definition_id.clone());
+            let asset_definition = AssetDefinition::new(asset_definition_id.clone(), value_type);
             instructions.push(Register::asset_definition(asset_definition).into());
             let can_unregister_asset_definition = Grant::permission(
-                Permission::new(
-                    "CanUnregisterAssetDefinition".parse().unwrap(),
-                    JsonString::from(&json!({ "asset_definition_id": asset_definition_id })),
-                ),
+                Permission::can_unregister_asset_definition(asset_definition_id),
                 owner_id.clone(),
             );
             instructions.push(can_unregister_asset_definition.into());
@@ -164,7 +197,81 @@ pub fn restore_every_nth(
     instructions
 }
 
+/// Register accounts, mint them asset balances and then move those balances around
+/// with `transfers` transfers of a single unit.
+///
+/// Transfer `t` moves asset `t % assets_per_domain` of domain `t % domains` from account
+/// `t % accounts_per_domain` to the next account of the same domain. Every account is minted
+/// exactly as much as it sends, asset definitions are expected to be registered already.
+pub fn transfer_round_robin(
+    domains: usize,
+    accounts_per_domain: usize,
+    assets_per_domain: usize,
+    transfers: usize,
+) -> Vec<InstructionBox> {
+    let route = |t: usize| {
+        let from = t % accounts_per_domain;
+        (
+            t % domains,
+            t % assets_per_domain,
+            from,
+            (from + 1) % accounts_per_domain,
+        )
+    };
+
+    let mut accounts = BTreeSet::new();
+    let mut minted = BTreeMap::new();
+    for t in 0..transfers {
+        let (i, k, from, to) = route(t);
+        accounts.extend([(i, from), (i, to)]);
+        *minted.entry((i, k, from)).or_insert(0_u32) += 1;
+    }
+
+    let mut instructions: Vec<InstructionBox> = Vec::new();
+    for &(i, j) in &accounts {
+        let account_id = construct_account_id(j, construct_domain_id(i));
+        instructions.push(Register::account(Account::new(account_id)).into());
+    }
+    for (&(i, k, j), &amount) in &minted {
+        let domain_id = construct_domain_id(i);
+        let asset_id = AssetId::new(
+            construct_asset_definition_id(k, domain_id.clone()),
+            construct_account_id(j, domain_id),
+        );
+        instructions.push(Mint::asset_numeric(amount, asset_id).into());
+    }
+
+    // Verify that transfers balance out: nobody sends more than it holds
+    // and the total across accounts stays equal to the total minted
+    let mut balances = minted.clone();
+    for t in 0..transfers {
+        let (i, k, from, to) = route(t);
+        let balance = balances.get_mut(&(i, k, from)).expect("Sender was minted");
+        *balance = balance.checked_sub(1).expect("Sender has enough balance");
+        *balances.entry((i, k, to)).or_insert(0) += 1;
+
+        let domain_id = construct_domain_id(i);
+        let asset_id = AssetId::new(
+            construct_asset_definition_id(k, domain_id.clone()),
+            construct_account_id(from, domain_id.clone()),
+        );
+        instructions.push(
+            Transfer::asset_numeric(asset_id, 1_u32, construct_account_id(to, domain_id)).into(),
+        );
+    }
+    assert_eq!(balances.values().sum::<u32>(), minted.values().sum::<u32>());
+
+    instructions
+}
+
+/// Build [`State`] with the default executor loaded from `configs/swarm/executor.wasm`
 pub fn build_state(rt: &tokio::runtime::Handle, account_id: &AccountId) -> State {
+    with_executor(build_state_bare(rt, account_id), account_id)
+}
+
+/// Build [`State`] without upgrading the executor, so that the initial executor
+/// which performs no permission checks is used and the executor wasm isn't required
+pub fn build_state_bare(rt: &tokio::runtime::Handle, account_id: &AccountId) -> State {
     let kura = iroha_core::kura::Kura::blank_kura_for_testing();
     let query_handle = {
         let _guard = rt.enter();
@@ -175,15 +282,29 @@ pub fn build_state(rt: &tokio::runtime::Handle, account_id: &AccountId) -> State
         account_id.clone(),
         Account::new(account_id.clone()).build(account_id),
     );
-    let state = State::new(World::with([domain], UniqueVec::new()), kura, query_handle);
+    let parameters: [(&str, ParameterValueBox); 3] = [
+        (TRANSACTION_LIMITS, TransactionLimits::unlimited().into()),
+        (EXECUTOR_FUEL_LIMIT, u64::MAX.into()),
+        (EXECUTOR_MAX_MEMORY, u32::MAX.into()),
+    ];
+    let parameters = parameters
+        .into_iter()
+        .map(|(id, val)| Parameter::new(id.parse().expect("Valid"), val))
+        .collect();
 
+    State::with_parameters(
+        parameters,
+        World::with([domain], UniqueVec::new()),
+        kura,
+        query_handle,
+    )
+    .expect("Parameters should be valid")
+}
+
+/// Upgrade executor of the `state` to the default one loaded from `configs/swarm/executor.wasm`
+pub fn with_executor(state: State, account_id: &AccountId) -> State {
     {
         let mut state_block = state.block();
-
-        state_block.config.transaction_limits = TransactionLimits::new(u64::MAX, u64::MAX);
-        state_block.config.executor_runtime.fuel_limit = u64::MAX;
-        state_block.config.executor_runtime.max_memory_bytes = u32::MAX;
-
         let mut state_transaction = state_block.transaction();
         let path_to_executor = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
             .join("../configs/swarm/executor.wasm");
@@ -201,6 +322,14 @@ pub fn build_state(rt: &tokio::runtime::Handle, account_id: &AccountId) -> State
     state
 }
 
+/// Construct an account in `domain` with a key pair derived from `seed`,
+/// so that blocks signed by it are reproducible across runs
+pub fn deterministic_account_in(domain: &str, seed: &[u8]) -> (AccountId, KeyPair) {
+    let key_pair = KeyPair::deterministic(seed);
+    let account_id = AccountId::new(domain.parse().unwrap(), key_pair.public_key().clone());
+    (account_id, key_pair)
+}
+
 fn construct_domain_id(i: usize) -> DomainId {
     DomainId::from_str(&format!("non_inlinable_domain_name_{i}")).unwrap()
 }
@@ -209,6 +338,16 @@ fn generate_account_id(domain_id: DomainId) -> AccountId {
     AccountId::new(domain_id, KeyPair::random().into_parts().0)
 }
 
+fn construct_account_id(i: usize, domain_id: DomainId) -> AccountId {
+    let seed = format!("non_inlinable_account_seed_{i}").into_bytes();
+    AccountId::new(
+        domain_id,
+        KeyPair::from_seed(seed, Algorithm::default())
+            .into_parts()
+            .0,
+    )
+}
+
 fn construct_asset_definition_id(i: usize, domain_id: DomainId) -> AssetDefinitionId {
     AssetDefinitionId::new(
         domain_id,
@@ -1,6 +1,5 @@
 use iroha_core::{prelude::*, state::State};
 use iroha_data_model::{isi::InstructionBox, prelude::*};
-use test_samples::gen_account_in;
 
 #[path = "./common.rs"]
 mod common;
@@ -26,14 +25,16 @@ impl StateValidateBlocks {
         let domains = 100;
         let accounts_per_domain = 1000;
         let assets_per_domain = 1000;
-        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (alice_id, alice_keypair) = deterministic_account_in("wonderland", b"alice");
         let state = build_state(rt, &alice_id);
 
         let nth = 100;
+        let transfers = 10_000;
         let instructions = [
             populate_state(domains, accounts_per_domain, assets_per_domain, &alice_id),
             delete_every_nth(domains, accounts_per_domain, assets_per_domain, nth),
             restore_every_nth(domains, accounts_per_domain, assets_per_domain, nth),
+            transfer_round_robin(domains, accounts_per_domain, assets_per_domain, transfers),
         ]
         .into_iter()
         .collect::<Vec<_>>();
@@ -39,6 +39,7 @@ async fn measure_block_size_for_n_executors(n_executors: u32) {
     let dir = tempfile::tempdir().expect("Could not create tempfile.");
     let cfg = Config {
         init_mode: iroha_config::kura::InitMode::Strict,
+        block_codec: iroha_config::kura::BlockCodec::None,
         debug_output_new_blocks: false,
         store_dir: WithOrigin::inline(dir.path().to_path_buf()),
     };
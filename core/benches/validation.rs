@@ -171,6 +171,18 @@ fn sign_blocks(criterion: &mut Criterion) {
     println!("Count: {count}");
 }
 
+fn restore_state(criterion: &mut Criterion) {
+    let _ = criterion.bench_function("build_state", |b| {
+        b.iter(build_test_and_transient_state);
+    });
+
+    let state = build_test_and_transient_state();
+    let snapshot = state.snapshot();
+    let _ = criterion.bench_function("restore_state", |b| {
+        b.iter(|| state.restore(&snapshot));
+    });
+}
+
 criterion_group!(
     transactions,
     accept_transaction,
@@ -178,4 +190,5 @@ criterion_group!(
     validate_transaction
 );
 criterion_group!(blocks, sign_blocks);
-criterion_main!(transactions, blocks);
+criterion_group!(state, restore_state);
+criterion_main!(transactions, blocks, state);
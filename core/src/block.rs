@@ -4,15 +4,18 @@
 //! 2. If a block is received, i.e. deserialized:
 //!     `SignedBlock` -> `ValidBlock` -> `CommittedBlock`
 //! [`Block`]s are organised into a linear sequence over time (also known as the block chain).
-use std::error::Error as _;
+use std::{error::Error as _, time::Duration};
 
 use iroha_config::parameters::defaults::chain_wide::CONSENSUS_ESTIMATION as DEFAULT_CONSENSUS_ESTIMATION;
-use iroha_crypto::{HashOf, KeyPair, MerkleTree, SignatureOf, SignaturesOf};
+use iroha_crypto::{HashOf, KeyPair, MerkleTree, PublicKey, SignatureOf, SignaturesOf};
 use iroha_data_model::{
     block::*,
     events::prelude::*,
     peer::PeerId,
-    transaction::{error::TransactionRejectionReason, prelude::*},
+    transaction::{
+        error::{ClockSkewError, TransactionRejectionReason},
+        prelude::*,
+    },
 };
 use iroha_genesis::GenesisTransaction;
 use iroha_primitives::unique_vec::UniqueVec;
@@ -100,6 +103,39 @@ pub enum SignatureVerificationError {
 #[derive(Debug, Clone)]
 pub struct BlockBuilder<B>(B);
 
+/// Check that the transaction was created within `max_clock_skew` of the `block_time`.
+///
+/// # Errors
+///
+/// Fails if the transaction creation time is too far in the past or in the future
+fn check_clock_skew(
+    tx: &SignedTransaction,
+    block_time: Duration,
+    max_clock_skew: Duration,
+) -> Result<(), TransactionRejectionReason> {
+    let creation_time = tx.creation_time();
+    let skew = if creation_time > block_time {
+        creation_time - block_time
+    } else {
+        block_time - creation_time
+    };
+
+    if skew > max_clock_skew {
+        return Err(TransactionRejectionReason::ClockSkew(ClockSkewError {
+            skew_ms: skew
+                .as_millis()
+                .try_into()
+                .expect("Time should fit into u64"),
+            max_clock_skew_ms: max_clock_skew
+                .as_millis()
+                .try_into()
+                .expect("Time should fit into u64"),
+        }));
+    }
+
+    Ok(())
+}
+
 mod pending {
     use std::time::SystemTime;
 
@@ -144,10 +180,24 @@ mod pending {
             })
         }
 
+        /// Order transactions canonically by their hash instead of keeping the order
+        /// in which they were given.
+        ///
+        /// The sort is stable, so blocks built from the same transactions end up with
+        /// the same transaction order regardless of the input order.
+        #[must_use]
+        pub fn with_sorted_transactions(mut self) -> Self {
+            self.0
+                .transactions
+                .sort_by_cached_key(|tx| tx.as_ref().hash());
+            self
+        }
+
         fn make_header(
             previous_height: u64,
             prev_block_hash: Option<HashOf<SignedBlock>>,
             view_change_index: u64,
+            timestamp: Duration,
             transactions: &[CommittedTransaction],
         ) -> BlockHeader {
             BlockHeader {
@@ -158,9 +208,7 @@ mod pending {
                     .map(|value| value.as_ref().hash())
                     .collect::<MerkleTree<_>>()
                     .hash(),
-                timestamp_ms: SystemTime::now()
-                    .duration_since(SystemTime::UNIX_EPOCH)
-                    .expect("Failed to get the current system time")
+                timestamp_ms: timestamp
                     .as_millis()
                     .try_into()
                     .expect("Time should fit into u64"),
@@ -174,12 +222,27 @@ mod pending {
 
         fn categorize_transactions(
             transactions: Vec<AcceptedTransaction>,
+            block_time: Duration,
             state_block: &mut StateBlock<'_>,
         ) -> Vec<CommittedTransaction> {
+            let is_genesis = state_block.height() == 0;
+            let max_clock_skew = state_block.config.max_clock_skew;
+
             transactions
                 .into_iter()
-                .map(
-                    |tx| match state_block.transaction_executor().validate(tx, state_block) {
+                .map(|tx| {
+                    let result = if is_genesis {
+                        Ok(())
+                    } else {
+                        check_clock_skew(tx.as_ref(), block_time, max_clock_skew)
+                    };
+
+                    let result = match result {
+                        Ok(()) => state_block.transaction_executor().validate(tx, state_block),
+                        Err(error) => Err((tx.into(), error)),
+                    };
+
+                    match result {
                         Ok(tx) => CommittedTransaction {
                             value: tx,
                             error: None,
@@ -195,31 +258,47 @@ mod pending {
                                 error: Some(error),
                             }
                         }
-                    },
-                )
+                    }
+                })
                 .collect()
         }
 
         /// Chain the block with existing blockchain.
         ///
         /// Upon executing this method current timestamp is stored in the block header.
+        /// If deterministic transaction ordering is enabled in the config of this peer,
+        /// transactions of a non-genesis block are sorted by their hash before being executed.
+        /// The order isn't enforced on blocks received from other peers.
         pub fn chain(
             self,
             view_change_index: u64,
             state: &mut StateBlock<'_>,
         ) -> BlockBuilder<Chained> {
-            let transactions = Self::categorize_transactions(self.0.transactions, state);
+            let timestamp = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .expect("Failed to get the current system time");
+            let Self(Pending {
+                commit_topology,
+                transactions,
+                event_recommendations,
+            }) = if state.config.deterministic_tx_order && state.height() > 0 {
+                self.with_sorted_transactions()
+            } else {
+                self
+            };
+            let transactions = Self::categorize_transactions(transactions, timestamp, state);
 
             BlockBuilder(Chained(BlockPayload {
                 header: Self::make_header(
                     state.height(),
                     state.latest_block_hash(),
                     view_change_index,
+                    timestamp,
                     &transactions,
                 ),
                 transactions,
-                commit_topology: self.0.commit_topology.ordered_peers,
-                event_recommendations: self.0.event_recommendations,
+                commit_topology: commit_topology.ordered_peers,
+                event_recommendations,
             }))
         }
     }
@@ -233,6 +312,19 @@ mod chained {
     pub struct Chained(pub(super) BlockPayload);
 
     impl BlockBuilder<Chained> {
+        /// Transactions of the chained block, together with the result of their execution.
+        pub fn transactions(&self) -> impl ExactSizeIterator<Item = &CommittedTransaction> {
+            self.0 .0.transactions.iter()
+        }
+
+        /// Hash of the block payload, e.g. to announce the block before it's signed.
+        ///
+        /// Signing doesn't change the payload, so the hash is the same
+        /// as the payload hash of the signed and committed block.
+        pub fn payload_hash(&self) -> HashOf<BlockPayload> {
+            HashOf::new(&self.0 .0)
+        }
+
         /// Sign this block and get [`SignedBlock`].
         pub fn sign(self, key_pair: &KeyPair) -> WithEvents<ValidBlock> {
             let signed_block = SignedBlockV1::new(self.0 .0, key_pair);
@@ -355,6 +447,8 @@ mod valid {
             state_block: &mut StateBlock<'_>,
         ) -> Result<(), TransactionValidationError> {
             let is_genesis = block.header().is_genesis();
+            let block_time = block.header().timestamp();
+            let max_clock_skew = state_block.config.max_clock_skew;
 
             block
                 .transactions()
@@ -374,12 +468,21 @@ mod valid {
                         AcceptedTransaction::accept(value, expected_chain_id, limits)
                     }?;
 
+                    let clock_skew = if is_genesis {
+                        Ok(())
+                    } else {
+                        check_clock_skew(tx.as_ref(), block_time, max_clock_skew)
+                    };
+
                     if error.is_some() {
-                        match transaction_executor.validate(tx, state_block) {
-                            Err(rejected_transaction) => Ok(rejected_transaction),
-                            Ok(_) => Err(TransactionValidationError::RejectedIsValid),
-                        }?;
+                        if clock_skew.is_ok() {
+                            match transaction_executor.validate(tx, state_block) {
+                                Err(rejected_transaction) => Ok(rejected_transaction),
+                                Ok(_) => Err(TransactionValidationError::RejectedIsValid),
+                            }?;
+                        }
                     } else {
+                        clock_skew.map_err(TransactionValidationError::NotValid)?;
                         transaction_executor
                             .validate(tx, state_block)
                             .map_err(|(_tx, error)| TransactionValidationError::NotValid(error))?;
@@ -459,6 +562,9 @@ mod valid {
         }
 
         /// Add additional signatures for [`Self`].
+        ///
+        /// Can be called repeatedly to collect signatures of several peers,
+        /// which are then checked against the topology in [`Self::commit`].
         #[must_use]
         pub fn sign(self, key_pair: &KeyPair) -> ValidBlock {
             ValidBlock(self.0.sign(key_pair))
@@ -476,6 +582,33 @@ mod valid {
             self.0.add_signature(signature)
         }
 
+        /// Replace signatures of [`Self`] with a single BLS signature aggregated from them.
+        ///
+        /// This reduces the size of the block. Aggregated signature is accepted on commit
+        /// if it was aggregated from the signatures of enough peers of the `topology`.
+        ///
+        /// # Errors
+        ///
+        /// - If a signature doesn't belong to a peer of the `topology`
+        /// - If signatures can't be aggregated, e.g. not all of them are BLS signatures
+        pub fn aggregate_signatures(
+            mut self,
+            topology: &Topology,
+        ) -> Result<Self, iroha_crypto::error::Error> {
+            let mut signers = vec![0_u8; topology.ordered_peers.len().div_ceil(8)];
+            for signature in self.as_ref().signatures() {
+                let position = topology
+                    .ordered_peers
+                    .iter()
+                    .position(|peer| peer.public_key() == signature.public_key())
+                    .ok_or(iroha_crypto::error::Error::BadSignature)?;
+                signers[position / 8] |= 1 << (position % 8);
+            }
+
+            self.0.aggregate_signatures(signers)?;
+            Ok(self)
+        }
+
         #[cfg(test)]
         pub(crate) fn new_dummy() -> Self {
             Self::new_dummy_and_modify_payload(|_| {})
@@ -514,6 +647,10 @@ mod valid {
         /// - Not enough signatures
         /// - Missing proxy tail signature
         fn verify_signatures(&self, topology: &Topology) -> Result<(), SignatureVerificationError> {
+            if !self.as_ref().aggregated_signers().is_empty() {
+                return self.verify_aggregated_signature(topology);
+            }
+
             // TODO: Should the peer that serves genesis have a fixed role of ProxyTail in topology?
             if !self.as_ref().header().is_genesis()
                 && topology.is_consensus_required().is_some()
@@ -548,6 +685,64 @@ mod valid {
 
             Ok(())
         }
+
+        /// Check that the only signature of the block is aggregated from the signatures
+        /// of enough peers of the topology, proxy tail included.
+        ///
+        /// That the signature matches the payload is checked when the block is decoded.
+        fn verify_aggregated_signature(
+            &self,
+            topology: &Topology,
+        ) -> Result<(), SignatureVerificationError> {
+            let signers = self.as_ref().aggregated_signers();
+            let mut signatures = self.as_ref().signatures().iter();
+            let (Some(signature), None) = (signatures.next(), signatures.next()) else {
+                return Err(SignatureVerificationError::UnknownSignature);
+            };
+
+            let is_signer = |position: usize| {
+                signers
+                    .get(position / 8)
+                    .is_some_and(|byte| byte & (1 << (position % 8)) != 0)
+            };
+            let signer_peers = topology
+                .ordered_peers
+                .iter()
+                .enumerate()
+                .filter_map(|(position, peer)| is_signer(position).then_some(peer))
+                .collect::<Vec<_>>();
+            let signers_count = signers
+                .iter()
+                .map(|byte| byte.count_ones() as usize)
+                .sum::<usize>();
+            if signers_count != signer_peers.len() {
+                // Signers outside of the topology
+                return Err(SignatureVerificationError::UnknownSignature);
+            }
+
+            if let Some(consensus_topology) = topology.is_consensus_required() {
+                if !signer_peers.contains(&consensus_topology.proxy_tail()) {
+                    return Err(SignatureVerificationError::ProxyTailMissing);
+                }
+            }
+
+            let votes_count = signer_peers.len();
+            if votes_count < topology.min_votes_for_commit() {
+                return Err(SignatureVerificationError::NotEnoughSignatures {
+                    votes_count,
+                    min_votes_for_commit: topology.min_votes_for_commit(),
+                });
+            }
+
+            let signers_key =
+                PublicKey::aggregate(signer_peers.iter().map(|peer| peer.public_key()))
+                    .map_err(|_| SignatureVerificationError::UnknownSignature)?;
+            if &signers_key != signature.public_key() {
+                return Err(SignatureVerificationError::UnknownSignature);
+            }
+
+            Ok(())
+        }
     }
 
     impl From<ValidBlock> for SignedBlock {
@@ -638,6 +833,39 @@ mod valid {
             )
         }
 
+        /// Check that signatures collected one by one are accepted once there are $2f + 1$ of them
+        #[test]
+        fn commit_requires_signatures_of_min_votes_for_commit_peers() {
+            let key_pairs = core::iter::repeat_with(KeyPair::random)
+                .take(4)
+                .collect::<Vec<_>>();
+            let mut key_pairs_iter = key_pairs.iter();
+            let peers = test_peers![0, 1, 2, 3: key_pairs_iter];
+            let topology = Topology::new(peers);
+            assert_eq!(topology.min_votes_for_commit(), 3);
+
+            // Leader and proxy tail
+            let block = ValidBlock::new_dummy()
+                .sign(&key_pairs[0])
+                .sign(&key_pairs[2]);
+            let (block, error) = block
+                .commit(&topology)
+                .unpack(|_| {})
+                .expect_err("Block must not be committed with 2 signatures");
+            assert!(matches!(
+                error,
+                BlockValidationError::SignatureVerification(
+                    SignatureVerificationError::NotEnoughSignatures {
+                        votes_count: 2,
+                        min_votes_for_commit: 3,
+                    }
+                )
+            ));
+
+            let block = block.sign(&key_pairs[1]);
+            assert!(block.commit(&topology).unpack(|_| {}).is_ok());
+        }
+
         /// Check requirement of having leader signature
         #[test]
         fn signature_verification_miss_proxy_tail_signature() {
@@ -663,6 +891,96 @@ mod valid {
                 Err(SignatureVerificationError::ProxyTailMissing)
             )
         }
+
+        fn bls_key_pairs(count: usize) -> Vec<KeyPair> {
+            core::iter::repeat_with(|| {
+                KeyPair::random_with_algorithm(iroha_crypto::Algorithm::BlsNormal)
+            })
+            .take(count)
+            .collect()
+        }
+
+        fn signed_by(key_pairs: &[KeyPair]) -> ValidBlock {
+            let mut block = ValidBlock::new_dummy();
+            let payload = payload(&block).clone();
+            let signatures = key_pairs
+                .iter()
+                .map(|key_pair| SignatureOf::new(key_pair, &payload))
+                .collect::<SignaturesOf<_>>();
+            assert!(block.0.replace_signatures(signatures));
+            block
+        }
+
+        /// Check that signatures of all voting peers can be aggregated into one
+        #[test]
+        fn signature_verification_aggregated_ok() {
+            let key_pairs = bls_key_pairs(3);
+            let mut key_pairs_iter = key_pairs.iter();
+            let peers = test_peers![0, 1, 2: key_pairs_iter];
+            let topology = Topology::new(peers);
+
+            let block = signed_by(&key_pairs)
+                .aggregate_signatures(&topology)
+                .expect("Failed to aggregate signatures");
+            assert_eq!(block.as_ref().signatures().len(), 1);
+            assert_eq!(block.as_ref().aggregated_signers(), [0b111]);
+
+            assert!(block.commit(&topology).unpack(|_| {}).is_ok());
+        }
+
+        /// Check that aggregated signature of enough peers is accepted without the rest
+        #[test]
+        fn signature_verification_aggregated_consensus_threshold() {
+            let key_pairs = bls_key_pairs(4);
+            let mut key_pairs_iter = key_pairs.iter();
+            let peers = test_peers![0, 1, 2, 3: key_pairs_iter];
+            let topology = Topology::new(peers);
+            assert_eq!(topology.min_votes_for_commit(), 3);
+
+            let block = signed_by(&key_pairs[..3])
+                .aggregate_signatures(&topology)
+                .expect("Failed to aggregate signatures");
+
+            assert_eq!(block.verify_signatures(&topology), Ok(()));
+        }
+
+        /// Check that aggregated signature must include proxy tail
+        #[test]
+        fn signature_verification_aggregated_proxy_tail_missing() {
+            let key_pairs = bls_key_pairs(3);
+            let mut key_pairs_iter = key_pairs.iter();
+            let peers = test_peers![0, 1, 2: key_pairs_iter];
+            let topology = Topology::new(peers);
+
+            let block = signed_by(&key_pairs[..2])
+                .aggregate_signatures(&topology)
+                .expect("Failed to aggregate signatures");
+
+            assert_eq!(
+                block.verify_signatures(&topology),
+                Err(SignatureVerificationError::ProxyTailMissing)
+            )
+        }
+
+        /// Check that aggregated signature can't claim peers which didn't sign
+        #[test]
+        fn signature_verification_aggregated_unsigned_peers_claimed() {
+            let key_pairs = bls_key_pairs(3);
+            let mut key_pairs_iter = key_pairs.iter();
+            let peers = test_peers![0, 1, 2: key_pairs_iter];
+            let topology = Topology::new(peers);
+
+            let mut block = signed_by(&key_pairs[..2]);
+            block
+                .0
+                .aggregate_signatures(vec![0b111])
+                .expect("Failed to aggregate signatures");
+
+            assert_eq!(
+                block.verify_signatures(&topology),
+                Err(SignatureVerificationError::UnknownSignature)
+            )
+        }
     }
 }
 
@@ -686,6 +1004,18 @@ mod commit {
         }
     }
 
+    impl CommittedBlock {
+        /// Compact proof that the transaction with the given hash is included in this block,
+        /// see [`verify_inclusion`](iroha_data_model::block::verify_inclusion).
+        /// Returns `None` if the block doesn't contain such transaction
+        pub fn inclusion_proof(
+            &self,
+            transaction_hash: &HashOf<SignedTransaction>,
+        ) -> Option<InclusionProof> {
+            self.as_ref().inclusion_proof(transaction_hash)
+        }
+    }
+
     impl AsRef<SignedBlock> for CommittedBlock {
         fn as_ref(&self) -> &SignedBlock {
             &self.0 .0
@@ -770,13 +1100,27 @@ mod event {
 
     impl EventProducer for CommittedBlock {
         fn produce_events(&self) -> impl Iterator<Item = PipelineEventBox> {
-            let block_event = core::iter::once(BlockEvent {
+            let block_event = BlockEvent {
                 header: self.as_ref().header().clone(),
                 hash: self.as_ref().hash(),
                 status: BlockStatus::Committed,
-            });
+            };
+
+            let transactions = self
+                .as_ref()
+                .transactions()
+                .map(|tx| TransactionSummary {
+                    hash: tx.as_ref().hash(),
+                    is_rejected: tx.error.is_some(),
+                })
+                .collect();
+            let block_committed_event = BlockCommittedEvent {
+                height: self.as_ref().header().height,
+                hash: self.as_ref().hash(),
+                transactions,
+            };
 
-            block_event.map(Into::into)
+            [block_event.into(), block_committed_event.into()].into_iter()
         }
     }
 
@@ -815,6 +1159,135 @@ mod tests {
         assert_eq!(valid_block.0.hash(), committed_block.as_ref().hash())
     }
 
+    #[tokio::test]
+    async fn chained_block_exposes_transactions_before_signing() {
+        let chain_id = ChainId::from("0");
+
+        // Predefined world state
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        let domain_id = DomainId::from_str("wonderland").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(&alice_id);
+        assert!(domain.add_account(account).is_none());
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let mut state_block = state.block();
+
+        let asset_definition_id = AssetDefinitionId::from_str("xor#wonderland").expect("Valid");
+        let transaction_limits = &state_block.transaction_executor().transaction_limits;
+        let tx = TransactionBuilder::new(chain_id.clone(), alice_id)
+            .with_instructions([Register::asset_definition(AssetDefinition::numeric(
+                asset_definition_id,
+            ))])
+            .sign(&alice_keypair);
+        let tx = AcceptedTransaction::accept(tx, &chain_id, transaction_limits).expect("Valid");
+
+        let chained_block =
+            BlockBuilder::new(vec![tx], Topology::new(UniqueVec::new()), Vec::new())
+                .chain(0, &mut state_block);
+
+        assert_eq!(chained_block.transactions().len(), 1);
+        assert!(chained_block.transactions().all(|tx| tx.error.is_none()));
+    }
+
+    #[tokio::test]
+    async fn failed_instruction_index_is_reported() {
+        let chain_id = ChainId::from("0");
+
+        // Predefined world state
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        let domain_id = DomainId::from_str("wonderland").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(&alice_id);
+        assert!(domain.add_account(account).is_none());
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let mut state_block = state.block();
+
+        let missing_domain_asset_id = AssetDefinitionId::from_str("xor#missing").expect("Valid");
+        let instructions: [InstructionBox; 4] = [
+            Register::domain(Domain::new("first".parse().expect("Valid"))).into(),
+            Register::domain(Domain::new("second".parse().expect("Valid"))).into(),
+            Register::asset_definition(AssetDefinition::numeric(missing_domain_asset_id)).into(),
+            Register::domain(Domain::new("third".parse().expect("Valid"))).into(),
+        ];
+        let transaction_limits = &state_block.transaction_executor().transaction_limits;
+        let tx = TransactionBuilder::new(chain_id.clone(), alice_id)
+            .with_instructions(instructions)
+            .sign(&alice_keypair);
+        let tx = AcceptedTransaction::accept(tx, &chain_id, transaction_limits).expect("Valid");
+
+        let chained_block =
+            BlockBuilder::new(vec![tx], Topology::new(UniqueVec::new()), Vec::new())
+                .chain(0, &mut state_block);
+
+        let error = chained_block
+            .transactions()
+            .next()
+            .expect("Block has one transaction")
+            .error
+            .clone();
+        let Some(TransactionRejectionReason::InstructionFailed(FailedInstruction {
+            index,
+            rolled_back,
+            ..
+        })) = error
+        else {
+            panic!("Unexpected rejection reason: {error:?}");
+        };
+        assert_eq!(index, 2);
+        // Registration of the first two domains is reported as rolled back
+        assert_eq!(rolled_back.len(), 2);
+        assert!(rolled_back
+            .iter()
+            .all(|event| matches!(event, DataEvent::Domain(DomainEvent::Created(_)))));
+        // Domains registered before the failed instruction were rolled back
+        assert_eq!(state_block.world.domains_iter().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn payload_hash_before_signing_matches_committed_block() {
+        let chain_id = ChainId::from("0");
+
+        // Predefined world state
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        let domain_id = DomainId::from_str("wonderland").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(&alice_id);
+        assert!(domain.add_account(account).is_none());
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let mut state_block = state.block();
+
+        let asset_definition_id = AssetDefinitionId::from_str("xor#wonderland").expect("Valid");
+        let transaction_limits = &state_block.transaction_executor().transaction_limits;
+        let tx = TransactionBuilder::new(chain_id.clone(), alice_id)
+            .with_instructions([Register::asset_definition(AssetDefinition::numeric(
+                asset_definition_id,
+            ))])
+            .sign(&alice_keypair);
+        let tx = AcceptedTransaction::accept(tx, &chain_id, transaction_limits).expect("Valid");
+
+        let topology = Topology::new(UniqueVec::new());
+        let chained_block =
+            BlockBuilder::new(vec![tx], topology.clone(), Vec::new()).chain(0, &mut state_block);
+        let payload_hash = chained_block.payload_hash();
+
+        let committed_block = chained_block
+            .sign(&KeyPair::random())
+            .unpack(|_| {})
+            .commit(&topology)
+            .unpack(|_| {})
+            .expect("Block is valid");
+        assert_eq!(committed_block.as_ref().hash_of_payload(), payload_hash);
+    }
+
     #[tokio::test]
     async fn should_reject_due_to_repetition() {
         let chain_id = ChainId::from("0");
@@ -870,6 +1343,213 @@ mod tests {
             .is_some());
     }
 
+    #[tokio::test]
+    async fn committed_transaction_inclusion_proof() {
+        let chain_id = ChainId::from("0");
+
+        // Predefined world state
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        let domain_id = DomainId::from_str("wonderland").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(&alice_id);
+        assert!(domain.add_account(account).is_none());
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let mut state_block = state.block();
+
+        // Making transactions registering different asset definitions
+        let transaction_limits = &state_block.transaction_executor().transaction_limits;
+        let transactions = ["xor#wonderland", "rose#wonderland", "tulip#wonderland"]
+            .into_iter()
+            .map(|asset_definition_id| {
+                let asset_definition_id =
+                    AssetDefinitionId::from_str(asset_definition_id).expect("Valid");
+                let tx = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
+                    .with_instructions([Register::asset_definition(AssetDefinition::numeric(
+                        asset_definition_id,
+                    ))])
+                    .sign(&alice_keypair);
+                AcceptedTransaction::accept(tx, &chain_id, transaction_limits).expect("Valid")
+            })
+            .collect::<Vec<_>>();
+
+        let topology = Topology::new(UniqueVec::new());
+        let committed_block = BlockBuilder::new(transactions, topology, Vec::new())
+            .chain(0, &mut state_block)
+            .sign(&alice_keypair)
+            .unpack(|_| {})
+            .commit(&Topology::new(UniqueVec::new()))
+            .unpack(|_| {})
+            .unwrap();
+        let header = committed_block.as_ref().header();
+        let hashes = committed_block
+            .as_ref()
+            .transactions()
+            .map(|tx| tx.as_ref().hash())
+            .collect::<Vec<_>>();
+
+        for hash in &hashes {
+            let proof = committed_block.inclusion_proof(hash).expect("Included");
+            assert!(verify_inclusion(&proof, header));
+        }
+
+        let mut tampered = committed_block
+            .inclusion_proof(&hashes[0])
+            .expect("Included");
+        tampered.transaction_hash = hashes[1];
+        assert!(!verify_inclusion(&tampered, header));
+
+        let not_included = TransactionBuilder::new(chain_id, alice_id)
+            .with_instructions([Log::new(
+                iroha_data_model::Level::INFO,
+                "not included".to_owned(),
+            )])
+            .sign(&alice_keypair);
+        assert!(committed_block
+            .inclusion_proof(&not_included.hash())
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn deterministic_tx_order_ignores_arrival_order() {
+        let chain_id = ChainId::from("0");
+
+        // Predefined world state
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        let domain_id = DomainId::from_str("wonderland").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(&alice_id);
+        assert!(domain.add_account(account).is_none());
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let topology = Topology::new(UniqueVec::new());
+
+        let transaction_limits = state.view().config.transaction_limits;
+        let transactions = ["xor#wonderland", "rose#wonderland", "tulip#wonderland"]
+            .into_iter()
+            .map(|asset_definition_id| {
+                let asset_definition_id =
+                    AssetDefinitionId::from_str(asset_definition_id).expect("Valid");
+                let tx = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
+                    .with_instructions([Register::asset_definition(AssetDefinition::numeric(
+                        asset_definition_id,
+                    ))])
+                    .sign(&alice_keypair);
+                AcceptedTransaction::accept(tx, &chain_id, &transaction_limits).expect("Valid")
+            })
+            .collect::<Vec<_>>();
+
+        // Genesis keeps the order of its transactions, so commit a block before
+        let mut state_block = state.block();
+        state_block.config.deterministic_tx_order = true;
+        let log = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
+            .with_instructions([Log::new(iroha_data_model::Level::INFO, "first".to_owned())])
+            .sign(&alice_keypair);
+        let log = AcceptedTransaction::accept(log, &chain_id, &transaction_limits).expect("Valid");
+        let first_block = BlockBuilder::new(vec![log], topology.clone(), Vec::new())
+            .chain(0, &mut state_block)
+            .sign(&alice_keypair)
+            .unpack(|_| {})
+            .commit(&topology)
+            .unpack(|_| {})
+            .expect("Block is valid");
+        let _events = state_block
+            .apply(&first_block)
+            .expect("Failed to apply block");
+        state_block.commit();
+
+        let build_block = |transactions: Vec<AcceptedTransaction>| {
+            let mut state_block = state.block();
+            BlockBuilder::new(transactions, topology.clone(), Vec::new())
+                .chain(0, &mut state_block)
+                .sign(&alice_keypair)
+                .unpack(|_| {})
+        };
+        let block = build_block(transactions.clone());
+        let reversed_block = build_block(transactions.into_iter().rev().collect());
+
+        let hashes = |block: &ValidBlock| {
+            block
+                .as_ref()
+                .transactions()
+                .map(|tx| tx.as_ref().hash())
+                .collect::<Vec<_>>()
+        };
+        let block_hashes = hashes(&block);
+        assert!(block_hashes.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(block_hashes, hashes(&reversed_block));
+        assert_eq!(
+            block.as_ref().header().transactions_hash,
+            reversed_block.as_ref().header().transactions_hash
+        );
+    }
+
+    #[tokio::test]
+    async fn sorted_transactions_ignore_input_order() {
+        let chain_id = ChainId::from("0");
+
+        // Predefined world state
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        let domain_id = DomainId::from_str("wonderland").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(&alice_id);
+        assert!(domain.add_account(account).is_none());
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let topology = Topology::new(UniqueVec::new());
+
+        let transaction_limits = state.view().config.transaction_limits;
+        let transactions = ["xor#wonderland", "rose#wonderland", "tulip#wonderland"]
+            .into_iter()
+            .map(|asset_definition_id| {
+                let asset_definition_id =
+                    AssetDefinitionId::from_str(asset_definition_id).expect("Valid");
+                let tx = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
+                    .with_instructions([Register::asset_definition(AssetDefinition::numeric(
+                        asset_definition_id,
+                    ))])
+                    .sign(&alice_keypair);
+                AcceptedTransaction::accept(tx, &chain_id, &transaction_limits).expect("Valid")
+            })
+            .collect::<Vec<_>>();
+
+        let build_block = |transactions: Vec<AcceptedTransaction>| {
+            let mut state_block = state.block();
+            BlockBuilder::new(transactions, topology.clone(), Vec::new())
+                .with_sorted_transactions()
+                .chain(0, &mut state_block)
+                .sign(&alice_keypair)
+                .unpack(|_| {})
+                .commit(&topology)
+                .unpack(|_| {})
+                .expect("Block is valid")
+        };
+        let block = build_block(transactions.clone());
+        let reversed_block = build_block(transactions.into_iter().rev().collect());
+
+        let hashes = |block: &CommittedBlock| {
+            block
+                .as_ref()
+                .transactions()
+                .map(|tx| tx.as_ref().hash())
+                .collect::<Vec<_>>()
+        };
+        let block_hashes = hashes(&block);
+        assert!(block_hashes.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(block_hashes, hashes(&reversed_block));
+        // Block hashes differ only by the timestamp taken when chaining
+        assert_eq!(
+            block.as_ref().header().transactions_hash,
+            reversed_block.as_ref().header().transactions_hash
+        );
+    }
+
     #[tokio::test]
     async fn tx_order_same_in_validation_and_revalidation() {
         let chain_id = ChainId::from("0");
@@ -1017,6 +1697,99 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn transactions_outside_max_clock_skew_are_rejected() {
+        let chain_id = ChainId::from("0");
+
+        // Predefined world state
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        let domain_id = DomainId::from_str("wonderland").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(&alice_id);
+        assert!(domain.add_account(account).is_none());
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let max_clock_skew = Parameter::new(
+            iroha_data_model::parameter::default::MAX_CLOCK_SKEW
+                .parse()
+                .expect("Valid"),
+            60_000_u64.into(),
+        );
+        let state = State::with_parameters(
+            [max_clock_skew].into_iter().collect(),
+            world,
+            kura,
+            query_handle,
+        )
+        .expect("Valid parameters");
+        let mut state_block = state.block();
+
+        // Clock skew isn't checked for genesis, so commit some block first
+        let topology = Topology::new(UniqueVec::new());
+        let genesis = ValidBlock::new_dummy_and_modify_payload(|payload| {
+            payload.header.height = 1;
+        })
+        .commit(&topology)
+        .unpack(|_| {})
+        .unwrap();
+        let _events = state_block.apply_without_execution(&genesis);
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::SystemTime::UNIX_EPOCH)
+            .expect("Failed to get the current system time");
+        let transaction_limits = &state_block.transaction_executor().transaction_limits;
+
+        let mut in_future =
+            TransactionBuilder::new(chain_id.clone(), alice_id.clone()).with_instructions([
+                Log::new(iroha_data_model::Level::INFO, "in future".to_owned()),
+            ]);
+        in_future.set_creation_time(now + Duration::from_secs(60 * 60));
+        let in_future = AcceptedTransaction::accept(
+            in_future.sign(&alice_keypair),
+            &chain_id,
+            transaction_limits,
+        )
+        .expect("Valid");
+
+        let mut within_skew = TransactionBuilder::new(chain_id.clone(), alice_id)
+            .with_instructions([Log::new(
+                iroha_data_model::Level::INFO,
+                "within skew".to_owned(),
+            )]);
+        within_skew.set_creation_time(now + Duration::from_secs(30));
+        let within_skew = AcceptedTransaction::accept(
+            within_skew.sign(&alice_keypair),
+            &chain_id,
+            transaction_limits,
+        )
+        .expect("Valid");
+
+        let transactions = vec![in_future, within_skew];
+        let valid_block = BlockBuilder::new(transactions, topology, Vec::new())
+            .chain(0, &mut state_block)
+            .sign(&alice_keypair)
+            .unpack(|_| {});
+
+        // The transaction stamped an hour in the future should be rejected
+        assert!(matches!(
+            valid_block.as_ref().transactions().next().unwrap().error,
+            Some(TransactionRejectionReason::ClockSkew(ClockSkewError {
+                max_clock_skew_ms: 60_000,
+                ..
+            }))
+        ));
+
+        // The transaction within the clock skew should be accepted
+        assert!(valid_block
+            .as_ref()
+            .transactions()
+            .nth(1)
+            .unwrap()
+            .error
+            .is_none());
+    }
+
     #[tokio::test]
     async fn genesis_public_key_is_checked() {
         let chain_id = ChainId::from("0");
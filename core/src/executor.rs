@@ -4,8 +4,8 @@ use derive_more::DebugCustom;
 use iroha_data_model::{
     account::AccountId,
     executor as data_model_executor,
-    isi::InstructionBox,
-    query::QueryBox,
+    isi::{error::IndexedInstructionError, InstructionBox},
+    query::{domain::FindAllDomains, QueryBox},
     transaction::{Executable, SignedTransaction},
     ValidationFail,
 };
@@ -27,7 +27,7 @@ impl From<wasm::error::Error> for ValidationFail {
                 use wasm::error::ExportFnCallError::*;
 
                 match call_error {
-                    ExecutionLimitsExceeded(_) => Self::TooComplex,
+                    ExecutionLimitsExceeded(_) | OutOfMemory { .. } => Self::TooComplex,
                     HostExecution(error) | Other(error) => {
                         Self::InternalError(format!("{error:#}"))
                     }
@@ -148,8 +148,9 @@ impl Executor {
                 else {
                     return Ok(());
                 };
-                for isi in instructions {
-                    isi.execute(authority, state_transaction)?
+                for (index, isi) in instructions.into_iter().enumerate() {
+                    isi.execute(authority, state_transaction)
+                        .map_err(|error| IndexedInstructionError::new(index, error))?
                 }
                 Ok(())
             }
@@ -274,6 +275,36 @@ impl Executor {
     }
 }
 
+/// Estimate the amount of fuel `raw_executor` consumes to validate a representative operation.
+///
+/// Lets operators get a rough idea of the executor cost before submitting an
+/// [`Upgrade`](iroha_data_model::isi::Upgrade) with it.
+///
+/// # Errors
+///
+/// - Failed to load `raw_executor`;
+/// - Failed to prepare runtime for WASM execution;
+/// - Failed to execute the entrypoint of the WASM blob.
+pub fn estimate_validation_fuel<S: StateReadOnly>(
+    state_ro: &S,
+    authority: &AccountId,
+    raw_executor: data_model_executor::Executor,
+) -> Result<u64, wasm::error::Error> {
+    let loaded_executor = LoadedExecutor::load(state_ro.engine(), raw_executor)?;
+
+    let runtime = wasm::RuntimeBuilder::<wasm::state::executor::ValidateQuery<S>>::new()
+        .with_engine(state_ro.engine().clone()) // Cloning engine is cheap, see [`wasmtime::Engine`] docs
+        .with_config(state_ro.config().executor_runtime)
+        .build()?;
+
+    runtime.estimate_executor_validate_query_fuel(
+        state_ro,
+        authority,
+        &loaded_executor.module,
+        QueryBox::from(FindAllDomains),
+    )
+}
+
 /// [`Executor`] with [`Module`](wasmtime::Module) for execution.
 ///
 /// Creating a [`wasmtime::Module`] is expensive, so we do it once on [`migrate()`](Executor::migrate)
@@ -337,3 +368,110 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, LoadedExecutor> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iroha_crypto::{Hash, HashOf};
+    use iroha_data_model::prelude::*;
+    use test_samples::gen_account_in;
+
+    use super::*;
+    use crate::{
+        kura::Kura,
+        query::store::LiveQueryStore,
+        smartcontracts::{isi::Registrable as _, Execute as _},
+        state::{State, WorldReadOnly as _},
+        PeersIds, World,
+    };
+
+    #[tokio::test]
+    async fn estimate_validation_fuel_of_bundled_executor() {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let mut domain = Domain::new(authority.domain_id.clone()).build(&authority);
+        assert!(domain
+            .add_account(Account::new(authority.clone()).build(&authority))
+            .is_none());
+        let world = World::with([domain], PeersIds::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let state_view = state.view();
+
+        let path_to_executor = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../configs/swarm/executor.wasm");
+        let wasm = std::fs::read(&path_to_executor)
+            .unwrap_or_else(|_| panic!("Failed to read file: {}", path_to_executor.display()));
+        let executor = data_model_executor::Executor::new(WasmSmartContract::from_compiled(wasm));
+
+        let fuel = estimate_validation_fuel(&state_view, &authority, executor.clone())
+            .expect("Bundled executor should be valid");
+        assert!(fuel > 0);
+        assert_eq!(
+            estimate_validation_fuel(&state_view, &authority, executor).unwrap(),
+            fuel
+        );
+    }
+    #[tokio::test]
+    async fn dry_run_reports_denial_without_changing_state() {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let (mallory, _mallory_keypair) = gen_account_in("wonderland");
+        let domain_id = authority.domain_id.clone();
+        let mut domain = Domain::new(domain_id.clone()).build(&authority);
+        for account_id in [&authority, &mallory] {
+            assert!(domain
+                .add_account(Account::new(account_id.clone()).build(&authority))
+                .is_none());
+        }
+        let world = World::with([domain], PeersIds::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+
+        let path_to_executor = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../configs/swarm/executor.wasm");
+        let wasm = std::fs::read(&path_to_executor)
+            .unwrap_or_else(|_| panic!("Failed to read file: {}", path_to_executor.display()));
+        let executor = data_model_executor::Executor::new(WasmSmartContract::from_compiled(wasm));
+        {
+            let mut state_block = state.block();
+            let mut state_transaction = state_block.transaction();
+            Upgrade::new(executor)
+                .execute(&authority, &mut state_transaction)
+                .expect("Bundled executor should be valid");
+            state_transaction.apply();
+            // Leave genesis, in which the executor permits everything
+            state_block
+                .block_hashes
+                .push(HashOf::from_untyped_unchecked(Hash::new([1_u8])));
+            state_block.commit();
+        }
+
+        let unregister = Unregister::domain(domain_id.clone());
+        let dry_run_error = state
+            .dry_run_instruction(&mallory, unregister.clone())
+            .expect_err("Only the domain owner can unregister it");
+        assert!(matches!(dry_run_error, ValidationFail::NotPermitted(_)));
+        assert!(state.view().world.domain(&domain_id).is_ok());
+
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let error = state_transaction
+            .world
+            .executor
+            .clone()
+            .validate_instruction(&mut state_transaction, &mallory, unregister.clone().into())
+            .expect_err("Only the domain owner can unregister it");
+        assert_eq!(error, dry_run_error);
+        drop(state_transaction);
+        drop(state_block);
+
+        let events = state
+            .dry_run_instruction(&authority, unregister)
+            .expect("Domain owner can unregister it");
+        assert!(events.iter().any(|event| matches!(
+            event,
+            EventBox::Data(DataEvent::Domain(DomainEvent::Deleted(id))) if *id == domain_id
+        )));
+        assert!(state.view().world.domain(&domain_id).is_ok());
+    }
+}
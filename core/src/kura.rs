@@ -3,6 +3,7 @@
 //! new [`Block`](`crate::block::SignedBlock`)s on the
 //! blockchain.
 use std::{
+    borrow::Cow,
     fmt::Debug,
     fs,
     io::{BufWriter, Read, Seek, SeekFrom, Write},
@@ -10,7 +11,10 @@ use std::{
     sync::Arc,
 };
 
-use iroha_config::{kura::InitMode, parameters::actual::Kura as Config};
+use iroha_config::{
+    kura::{BlockCodec, InitMode},
+    parameters::actual::Kura as Config,
+};
 use iroha_crypto::{Hash, HashOf};
 use iroha_data_model::block::SignedBlock;
 use iroha_logger::prelude::*;
@@ -27,6 +31,18 @@ const LOCK_FILE_NAME: &str = "kura.lock";
 
 const SIZE_OF_BLOCK_HASH: u64 = Hash::LENGTH as u64;
 
+/// First byte of a block stored in the framed format, followed by a codec byte
+/// and the block encoded with that codec.
+///
+/// Blocks stored without compression (including those written by older versions)
+/// are plain versioned SCALE and start with the block version byte instead.
+/// A new framed format must use another header byte.
+const FRAMED_BLOCK_HEADER_V1: u8 = 0xF1;
+/// Codec byte of an uncompressed block in the framed format
+const UNCOMPRESSED_CODEC_ID: u8 = 0;
+/// Codec byte of a zstd compressed block in the framed format
+const ZSTD_CODEC_ID: u8 = 1;
+
 /// The interface of Kura subsystem
 #[derive(Debug)]
 pub struct Kura {
@@ -51,7 +67,8 @@ impl Kura {
     /// path.
     pub fn new(config: &Config) -> Result<(Arc<Self>, BlockCount)> {
         let store_dir = config.store_dir.resolve_relative_path();
-        let mut block_store = BlockStore::new(&store_dir, LockStatus::Unlocked);
+        let mut block_store =
+            BlockStore::new(&store_dir, LockStatus::Unlocked).with_codec(config.block_codec);
         block_store.create_files_if_they_do_not_exist()?;
 
         let block_plain_text_path = config
@@ -81,6 +98,29 @@ impl Kura {
         })
     }
 
+    /// Create a kura instance persisting blocks into a fresh temporary directory.
+    ///
+    /// The directory is removed when the returned guard is dropped, keep it around
+    /// to reopen the same block store with [`Kura::open_for_testing`].
+    #[cfg(test)]
+    pub(crate) fn temp_dir_for_testing() -> (Arc<Kura>, tempfile::TempDir) {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temporary directory");
+        let (kura, _block_count) = Self::open_for_testing(temp_dir.path());
+        (kura, temp_dir)
+    }
+
+    /// Open a kura instance persisting blocks into `store_dir`, reading blocks stored there before.
+    #[cfg(test)]
+    pub(crate) fn open_for_testing(store_dir: &Path) -> (Arc<Kura>, BlockCount) {
+        Self::new(&Config {
+            init_mode: InitMode::Strict,
+            store_dir: iroha_config::base::WithOrigin::inline(store_dir.to_path_buf()),
+            block_codec: BlockCodec::default(),
+            debug_output_new_blocks: false,
+        })
+        .expect("Failed to open block store")
+    }
+
     /// Start the Kura thread
     pub fn start(kura: Arc<Self>) -> ThreadHandler {
         // Oneshot channel to allow forcefully stopping the thread.
@@ -163,7 +203,7 @@ impl Kura {
             let mut block_data_buffer = vec![0_u8; block.length.try_into()?];
 
             match block_store.read_block_data(block.start, &mut block_data_buffer) {
-                Ok(()) => match SignedBlock::decode_all_versioned(&block_data_buffer) {
+                Ok(()) => match decode_block(&block_data_buffer) {
                     Ok(decoded_block) => {
                         if prev_block_hash != decoded_block.header().previous_block_hash {
                             error!("Block has wrong previous block hash. Not reading any blocks beyond this height.");
@@ -319,13 +359,27 @@ impl Kura {
         block_store
             .read_block_data(start, &mut block_buf)
             .expect("Failed to read block data.");
-        let block = SignedBlock::decode_all_versioned(&block_buf).expect("Failed to decode block");
+        let block = decode_block(&block_buf).expect("Failed to decode block");
 
         let block_arc = Arc::new(block);
         data_array_guard[block_number].1 = Some(Arc::clone(&block_arc));
         Some(block_arc)
     }
 
+    /// Iterate over blocks with heights from `start` to `end` inclusive,
+    /// loading from disk only the requested ones.
+    ///
+    /// The range is clamped to the stored blocks, so it yields nothing if
+    /// `start` is beyond the latest block.
+    pub fn blocks_in_range(
+        &self,
+        start: u64,
+        end: u64,
+    ) -> impl Iterator<Item = Arc<SignedBlock>> + '_ {
+        let end = end.min(self.block_data.lock().len() as u64);
+        (start.max(1)..=end).map_while(|block_height| self.get_block_by_height(block_height))
+    }
+
     /// Get a reference to block by hash, loading it from disk if needed.
     ///
     /// Internally this function searches linearly for the block's height and
@@ -365,6 +419,7 @@ pub struct BlockCount(pub usize);
 #[derive(Debug)]
 pub struct BlockStore {
     path_to_blockchain: PathBuf,
+    codec: BlockCodec,
 }
 
 impl Drop for BlockStore {
@@ -434,9 +489,19 @@ impl BlockStore {
         }
         BlockStore {
             path_to_blockchain: store_path.as_ref().to_path_buf(),
+            codec: BlockCodec::default(),
         }
     }
 
+    /// Set the codec used to compress appended blocks.
+    ///
+    /// Blocks are read back regardless of the codec they were written with.
+    #[must_use]
+    pub fn with_codec(mut self, codec: BlockCodec) -> Self {
+        self.codec = codec;
+        self
+    }
+
     /// Read a series of block indices from the block index file and
     /// attempt to fill all of `dest_buffer`.
     ///
@@ -781,7 +846,7 @@ impl BlockStore {
     /// Fails if any of the required platform-specific functions
     /// fail.
     pub fn append_block_to_chain(&mut self, block: &SignedBlock) -> Result<()> {
-        let bytes = block.encode_versioned();
+        let bytes = encode_block(block, self.codec)?;
         let new_block_height = self.read_index_count()?;
         let start_location_in_data_file = if new_block_height == 0 {
             0
@@ -802,6 +867,38 @@ impl BlockStore {
     }
 }
 
+/// Encode `block` to be stored on disk, compressing it with `codec`
+fn encode_block(block: &SignedBlock, codec: BlockCodec) -> Result<Vec<u8>> {
+    let bytes = block.encode_versioned();
+
+    match codec {
+        BlockCodec::None => Ok(bytes),
+        BlockCodec::Zstd => {
+            let mut framed = vec![FRAMED_BLOCK_HEADER_V1, ZSTD_CODEC_ID];
+            zstd::stream::copy_encode(bytes.as_slice(), &mut framed, 0)
+                .map_err(Error::Compression)?;
+            Ok(framed)
+        }
+    }
+}
+
+/// Decode block stored on disk, detecting the codec it was stored with
+///
+/// # Errors
+/// Fails if the codec is unknown, decompression fails or the block is malformed
+pub fn decode_block(bytes: &[u8]) -> Result<SignedBlock> {
+    let bytes = match bytes {
+        [FRAMED_BLOCK_HEADER_V1, UNCOMPRESSED_CODEC_ID, block @ ..] => Cow::Borrowed(block),
+        [FRAMED_BLOCK_HEADER_V1, ZSTD_CODEC_ID, block @ ..] => {
+            Cow::Owned(zstd::stream::decode_all(block).map_err(Error::Compression)?)
+        }
+        [FRAMED_BLOCK_HEADER_V1, codec_id, ..] => return Err(Error::UnknownCodec(*codec_id)),
+        legacy => Cow::Borrowed(legacy),
+    };
+
+    SignedBlock::decode_all_versioned(&bytes).map_err(Error::Decode)
+}
+
 type Result<T, E = Error> = std::result::Result<T, E>;
 /// Error variants for persistent storage logic
 #[derive(thiserror::Error, Debug, displaydoc::Display)]
@@ -812,6 +909,12 @@ pub enum Error {
     MkDir(#[source] std::io::Error, PathBuf),
     /// Failed to serialize/deserialize block
     Codec(#[from] parity_scale_codec::Error),
+    /// Failed to decode versioned block
+    Decode(#[source] iroha_version::error::Error),
+    /// Failed to compress/decompress block
+    Compression(#[source] std::io::Error),
+    /// Block is stored with unknown codec {0}
+    UnknownCodec(u8),
     /// Failed to allocate buffer
     Alloc(#[from] std::collections::TryReserveError),
     /// Tried reading block data out of bounds: `start_block_height`, `block_count`
@@ -848,8 +951,10 @@ mod tests {
 
     use tempfile::TempDir;
 
+    use iroha_primitives::unique_vec::UniqueVec;
+
     use super::*;
-    use crate::block::ValidBlock;
+    use crate::{block::ValidBlock, sumeragi::network_topology::Topology};
 
     fn indices<const N: usize>(value: [(u64, u64); N]) -> [BlockIndex; N] {
         let mut ret = [BlockIndex {
@@ -1016,6 +1121,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn zstd_compressed_block_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut block_store =
+            BlockStore::new(dir.path(), LockStatus::Unlocked).with_codec(BlockCodec::Zstd);
+        block_store.create_files_if_they_do_not_exist().unwrap();
+
+        let dummy_block: SignedBlock = ValidBlock::new_dummy().into();
+        block_store.append_block_to_chain(&dummy_block).unwrap();
+
+        let BlockIndex { start, length } = block_store.read_block_index(0).unwrap();
+        let mut block_data = vec![0_u8; length.try_into().unwrap()];
+        block_store.read_block_data(start, &mut block_data).unwrap();
+        assert_eq!(block_data[..2], [FRAMED_BLOCK_HEADER_V1, ZSTD_CODEC_ID]);
+        assert_eq!(
+            decode_block(&block_data).unwrap().hash(),
+            dummy_block.hash()
+        );
+    }
+
+    #[tokio::test]
+    async fn legacy_uncompressed_block_is_read_with_zstd_codec() {
+        let dir = tempfile::tempdir().unwrap();
+        let dummy_block: SignedBlock = ValidBlock::new_dummy_and_modify_payload(|payload| {
+            payload.header.height = 1;
+            payload.header.previous_block_hash = None;
+        })
+        .into();
+        {
+            let mut block_store = BlockStore::new(dir.path(), LockStatus::Unlocked);
+            block_store.create_files_if_they_do_not_exist().unwrap();
+            block_store.append_block_to_chain(&dummy_block).unwrap();
+        }
+
+        let (kura, block_count) = Kura::new(&Config {
+            init_mode: InitMode::Strict,
+            store_dir: iroha_config::base::WithOrigin::inline(dir.path().to_path_buf()),
+            block_codec: BlockCodec::Zstd,
+            debug_output_new_blocks: false,
+        })
+        .unwrap();
+        assert_eq!(block_count.0, 1);
+        assert_eq!(
+            kura.get_block_by_height(1).unwrap().hash(),
+            dummy_block.hash()
+        );
+    }
+
     #[test]
     fn lock_and_unlock() {
         let dir = tempfile::tempdir().unwrap();
@@ -1048,6 +1201,69 @@ mod tests {
             .expect("Lockfile should have been created");
     }
 
+    #[tokio::test]
+    async fn reopened_kura_reads_back_committed_blocks() {
+        const BLOCK_COUNT: u64 = 5;
+
+        let topology = Topology::new(UniqueVec::new());
+        let (kura, temp_dir) = Kura::temp_dir_for_testing();
+        let kura_thread = Kura::start(Arc::clone(&kura));
+        let mut block_hashes = Vec::new();
+        for height in 1..=BLOCK_COUNT {
+            let block = ValidBlock::new_dummy_and_modify_payload(|payload| {
+                payload.header.height = height;
+                payload.header.previous_block_hash = block_hashes.last().copied();
+            })
+            .commit(&topology)
+            .unpack(|_| {})
+            .unwrap();
+            block_hashes.push(block.as_ref().hash());
+            kura.store_block(block);
+        }
+        // Shutting down the thread flushes all blocks to disk
+        drop(kura_thread);
+        drop(kura);
+
+        let (kura, block_count) = Kura::open_for_testing(temp_dir.path());
+        assert_eq!(block_count.0 as u64, BLOCK_COUNT);
+        for (height, hash) in (1..=BLOCK_COUNT).zip(&block_hashes) {
+            assert_eq!(kura.get_block_hash(height).as_ref(), Some(hash));
+            let block = kura.get_block_by_height(height).expect("Block was stored");
+            assert_eq!(block.hash(), *hash);
+        }
+    }
+
+    #[tokio::test]
+    async fn blocks_in_range_are_clamped_to_stored_blocks() {
+        const BLOCK_COUNT: u64 = 10;
+
+        let topology = Topology::new(UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let mut block_hashes = Vec::new();
+        for height in 1..=BLOCK_COUNT {
+            let block = ValidBlock::new_dummy_and_modify_payload(|payload| {
+                payload.header.height = height;
+                payload.header.previous_block_hash = block_hashes.last().copied();
+            })
+            .commit(&topology)
+            .unpack(|_| {})
+            .unwrap();
+            block_hashes.push(block.as_ref().hash());
+            kura.store_block(block);
+        }
+        let heights = |start, end| {
+            kura.blocks_in_range(start, end)
+                .map(|block| block.header().height())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(heights(3, 6), vec![3, 4, 5, 6]);
+        assert_eq!(heights(0, 2), vec![1, 2]);
+        assert_eq!(heights(8, 100), vec![8, 9, 10]);
+        assert!(heights(11, 20).is_empty());
+        assert!(heights(6, 3).is_empty());
+    }
+
     #[tokio::test]
     async fn strict_init_kura() {
         let temp_dir = TempDir::new().unwrap();
@@ -1056,6 +1272,7 @@ mod tests {
             store_dir: iroha_config::base::WithOrigin::inline(
                 temp_dir.path().to_str().unwrap().into(),
             ),
+            block_codec: BlockCodec::default(),
             debug_output_new_blocks: false,
         })
         .unwrap();
@@ -388,6 +388,7 @@ pub mod tests {
     use std::{str::FromStr, sync::Arc, thread, time::Duration};
 
     use iroha_data_model::{prelude::*, transaction::TransactionLimits};
+    use iroha_version::scale::{DecodeVersioned as _, EncodeVersioned as _};
     use nonzero_ext::nonzero;
     use rand::Rng as _;
     use test_samples::gen_account_in;
@@ -477,6 +478,66 @@ pub mod tests {
             .expect("Failed to push tx into queue");
     }
 
+    #[test]
+    async fn push_tx_signed_by_external_signer() {
+        /// Stand-in for a hardware security module, the private key never leaves it
+        struct MockHsm {
+            key_pair: KeyPair,
+        }
+
+        impl iroha_crypto::Signer for MockHsm {
+            fn public_key(&self) -> &PublicKey {
+                self.key_pair.public_key()
+            }
+
+            fn sign(&self, payload: &[u8]) -> Result<iroha_crypto::Signature, iroha_crypto::Error> {
+                Ok(iroha_crypto::Signature::new(&self.key_pair, payload))
+            }
+        }
+
+        let hsm = MockHsm {
+            key_pair: KeyPair::random(),
+        };
+        let domain_id = DomainId::from_str("wonderland").expect("Valid");
+        let account_id = AccountId::new(
+            domain_id.clone(),
+            iroha_crypto::Signer::public_key(&hsm).clone(),
+        );
+        let mut domain = Domain::new(domain_id).build(&account_id);
+        let account = Account::new(account_id.clone()).build(&account_id);
+        assert!(domain.add_account(account).is_none());
+
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let world = World::with([domain], PeersIds::new());
+        let state = Arc::new(State::new(world, kura, query_handle));
+        let state_view = state.view();
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+        let chain_id = ChainId::from("0");
+        let tx =
+            TransactionBuilder::new_with_time_source(chain_id.clone(), account_id, &time_source)
+                .with_instructions([Fail {
+                    message: "signed by HSM".to_owned(),
+                }])
+                .sign_with(&hsm)
+                .expect("Mock HSM never fails to sign");
+        // Signature is verified when the transaction is decoded
+        let tx = SignedTransaction::decode_all_versioned(&tx.encode_versioned())
+            .expect("Signature produced by the HSM should be valid");
+        let limits = TransactionLimits {
+            max_instruction_number: 4096,
+            max_wasm_size_bytes: 0,
+        };
+        let tx = AcceptedTransaction::accept(tx, &chain_id, &limits)
+            .expect("Failed to accept Transaction.");
+
+        let queue = Queue::test(config_factory(), &time_source);
+        queue
+            .push(tx, &state_view)
+            .expect("Failed to push tx into queue");
+    }
+
     #[test]
     async fn push_tx_overflow() {
         let capacity = nonzero!(10_usize);
@@ -97,6 +97,16 @@ pub mod isi {
             let asset_id = self.object_id;
             let account_id = asset_id.account_id.clone();
 
+            if state_transaction
+                .world
+                .asset(&asset_id)
+                .is_ok_and(|asset| asset.is_locked())
+            {
+                return Err(Error::InvariantViolation(format!(
+                    "Can't unregister asset `{asset_id}` while part of it is locked"
+                )));
+            }
+
             let asset = state_transaction
                 .world
                 .account_mut(&account_id)
@@ -205,6 +215,55 @@ pub mod isi {
         }
     }
 
+    impl Execute for SetAccountMetadata {
+        #[metrics(+"set_account_metadata")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account_id;
+
+            let account_metadata_limits = state_transaction.config.account_metadata_limits;
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            // Apply entries to a copy, so that the metadata is either updated in full or not at all
+            let mut metadata = if self.replace {
+                Metadata::new()
+            } else {
+                account.metadata.clone()
+            };
+            for (key, value) in &self.metadata {
+                metadata.insert_with_limits(key.clone(), value.clone(), account_metadata_limits)?;
+            }
+            let previous = core::mem::replace(&mut account.metadata, metadata);
+
+            let removed = previous
+                .iter()
+                .filter(|(key, _)| !account.metadata.contains(key))
+                .map(|(key, value)| {
+                    AccountEvent::MetadataRemoved(MetadataChanged {
+                        target_id: account_id.clone(),
+                        key: key.clone(),
+                        value: value.clone(),
+                    })
+                })
+                .collect::<Vec<_>>();
+            let inserted = self.metadata.into_iter().map(|(key, value)| {
+                AccountEvent::MetadataInserted(MetadataChanged {
+                    target_id: account_id.clone(),
+                    key,
+                    value,
+                })
+            });
+            state_transaction
+                .world
+                .emit_events(removed.into_iter().chain(inserted));
+
+            Ok(())
+        }
+    }
+
     impl Execute for RemoveKeyValue<Account> {
         #[metrics(+"remove_account_key_value")]
         fn execute(
@@ -221,6 +221,68 @@ pub mod isi {
         }
     }
 
+    impl Execute for MintBatch {
+        #[metrics(+"mint_batch")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_definition_id = self.asset_definition_id;
+            if self.mints.is_empty() {
+                return Ok(());
+            }
+
+            let asset_definition = assert_asset_type(
+                &asset_definition_id,
+                state_transaction,
+                expected_asset_value_type_numeric,
+            )?;
+            // Check the whole batch before touching any asset, so that it's either applied in full or not at all
+            let mut total = Numeric::ZERO;
+            for (account_id, amount) in &self.mints {
+                assert_numeric_spec(amount, &asset_definition)?;
+                state_transaction.world.account(account_id)?;
+                total = total.checked_add(*amount).ok_or(MathError::Overflow)?;
+            }
+            state_transaction
+                .world
+                .asset_total_amount(&asset_definition_id)?
+                .checked_add(total)
+                .ok_or(MathError::Overflow)?;
+
+            assert_can_mint(&asset_definition, state_transaction)?;
+            for (account_id, amount) in self.mints {
+                let asset_id = AssetId::new(asset_definition_id.clone(), account_id);
+                let asset = state_transaction
+                    .world
+                    .asset_or_insert(asset_id.clone(), Numeric::ZERO)?;
+                let AssetValue::Numeric(quantity) = &mut asset.value else {
+                    return Err(Error::Conversion("Expected numeric asset type".to_owned()));
+                };
+                *quantity = quantity.checked_add(amount).ok_or(MathError::Overflow)?;
+
+                #[allow(clippy::float_arithmetic)]
+                state_transaction
+                    .new_tx_amounts
+                    .lock()
+                    .push(amount.to_f64());
+
+                state_transaction
+                    .world
+                    .emit_events(Some(AssetEvent::Added(AssetChanged {
+                        asset_id,
+                        amount: amount.into(),
+                    })));
+            }
+            state_transaction
+                .world
+                .increase_asset_total_amount(&asset_definition_id, total)?;
+
+            Ok(())
+        }
+    }
+
     impl Execute for Burn<Numeric, Asset> {
         fn execute(
             self,
@@ -241,11 +303,13 @@ pub mod isi {
                 .assets
                 .get_mut(&asset_id)
                 .ok_or_else(|| FindError::Asset(asset_id.clone()))?;
+            let locked = asset.locked();
             let AssetValue::Numeric(quantity) = &mut asset.value else {
                 return Err(Error::Conversion("Expected numeric asset type".to_owned()));
             };
             *quantity = quantity
                 .checked_sub(self.object)
+                .filter(|remaining| *remaining >= locked)
                 .ok_or(MathError::NotEnoughQuantity)?;
 
             if asset.value.is_zero_value() {
@@ -297,11 +361,13 @@ pub mod isi {
                     .assets
                     .get_mut(&source_id)
                     .ok_or_else(|| FindError::Asset(source_id.clone()))?;
+                let locked = asset.locked();
                 let AssetValue::Numeric(quantity) = &mut asset.value else {
                     return Err(Error::Conversion("Expected numeric asset type".to_owned()));
                 };
                 *quantity = quantity
                     .checked_sub(self.object)
+                    .filter(|remaining| *remaining >= locked)
                     .ok_or(MathError::NotEnoughQuantity)?;
                 if asset.value.is_zero_value() {
                     assert!(account.remove_asset(&source_id).is_some());
@@ -343,6 +409,74 @@ pub mod isi {
         }
     }
 
+    impl Execute for Lock<Numeric, Asset> {
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.destination_id;
+
+            let asset_definition = assert_asset_type(
+                &asset_id.definition_id,
+                state_transaction,
+                expected_asset_value_type_numeric,
+            )?;
+            assert_numeric_spec(&self.object, &asset_definition)?;
+
+            let asset = state_transaction.world.asset_mut(&asset_id)?;
+            let AssetValue::Numeric(quantity) = asset.value else {
+                return Err(Error::Conversion("Expected numeric asset type".to_owned()));
+            };
+            asset
+                .locked()
+                .checked_add(self.object)
+                .filter(|locked| *locked <= quantity)
+                .ok_or(MathError::NotEnoughQuantity)?;
+            let locked_by_authority = asset
+                .locks
+                .entry(authority.clone())
+                .or_insert(Numeric::ZERO);
+            *locked_by_authority = locked_by_authority
+                .checked_add(self.object)
+                .ok_or(MathError::Overflow)?;
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Unlock<Numeric, Asset> {
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.destination_id;
+
+            let asset_definition = assert_asset_type(
+                &asset_id.definition_id,
+                state_transaction,
+                expected_asset_value_type_numeric,
+            )?;
+            assert_numeric_spec(&self.object, &asset_definition)?;
+
+            // only the account which locked the amount can release it
+            let asset = state_transaction.world.asset_mut(&asset_id)?;
+            let locked_by_authority = asset
+                .locks
+                .get(authority)
+                .and_then(|locked| locked.checked_sub(self.object))
+                .ok_or(MathError::NotEnoughQuantity)?;
+            if locked_by_authority.is_zero() {
+                asset.locks.remove(authority);
+            } else {
+                asset.locks.insert(authority.clone(), locked_by_authority);
+            }
+
+            Ok(())
+        }
+    }
+
     /// Assert that asset type is Numeric and that it satisfy asset definition spec
     pub(crate) fn assert_numeric_spec(
         object: &Numeric,
@@ -426,7 +560,7 @@ pub mod isi {
 pub mod query {
     use eyre::Result;
     use iroha_data_model::{
-        asset::{Asset, AssetDefinition, AssetValue},
+        asset::{Asset, AssetDefinition, AssetValue, AssetValueType},
         metadata::MetadataValueBox,
         query::{asset::FindAssetDefinitionById, error::QueryExecutionFail as Error},
     };
@@ -657,6 +791,40 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindTotalAssetQuantityByDomainIdAndAssetDefinitionId {
+        #[metrics(+"find_total_asset_quantity_by_domain_id_and_asset_definition_id")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Numeric, Error> {
+            let domain_id = &self.domain_id;
+            let asset_definition_id = &self.asset_definition_id;
+            iroha_logger::trace!(%domain_id, %asset_definition_id);
+            let domain = state_ro.world().domain(domain_id)?;
+            let definition = state_ro.world().asset_definition(asset_definition_id)?;
+            if matches!(definition.value_type, AssetValueType::Store) {
+                return Err(Error::Conversion(
+                    "Can't get quantity for store asset".to_string(),
+                ));
+            }
+
+            domain
+                .accounts
+                .values()
+                .filter_map(|account| {
+                    account.assets.get(&AssetId::new(
+                        asset_definition_id.clone(),
+                        account.id().clone(),
+                    ))
+                })
+                .try_fold(Numeric::ZERO, |total, asset| match asset.value {
+                    AssetValue::Numeric(quantity) => {
+                        total.checked_add(quantity).ok_or(Error::Overflow)
+                    }
+                    AssetValue::Store(_) => Err(Error::Conversion(
+                        "Can't get quantity for store asset".to_string(),
+                    )),
+                })
+        }
+    }
+
     impl ValidQuery for FindAssetKeyValueByIdAndKey {
         #[metrics(+"find_asset_key_value_by_id_and_key")]
         fn execute(&self, state_ro: &impl StateReadOnly) -> Result<MetadataValueBox, Error> {
@@ -3,7 +3,7 @@ use eyre::Result;
 use iroha_data_model::{
     block::{BlockHeader, SignedBlock},
     query::{
-        block::FindBlockHeaderByHash,
+        block::{FindBlockByHash, FindBlockByHeight, FindBlockHeaderByHash},
         error::{FindError, QueryExecutionFail},
     },
 };
@@ -52,3 +52,34 @@ impl ValidQuery for FindBlockHeaderByHash {
         Ok(block.header().clone())
     }
 }
+
+impl ValidQuery for FindBlockByHeight {
+    #[metrics(+"find_block_by_height")]
+    fn execute(&self, state_ro: &impl StateReadOnly) -> Result<SignedBlock, QueryExecutionFail> {
+        let height = self.height;
+
+        // Kura might already store blocks which are not yet applied to this state
+        let block_count = state_ro.block_hashes().len() as u64;
+        (1..=block_count)
+            .contains(&height)
+            .then(|| state_ro.kura().get_block_by_height(height))
+            .flatten()
+            .map(|block| (*block).clone())
+            .ok_or(QueryExecutionFail::Find(FindError::BlockHeight(height)))
+    }
+}
+
+impl ValidQuery for FindBlockByHash {
+    #[metrics(+"find_block_by_hash")]
+    fn execute(&self, state_ro: &impl StateReadOnly) -> Result<SignedBlock, QueryExecutionFail> {
+        let hash = self.hash;
+
+        state_ro
+            .block_hashes()
+            .iter()
+            .position(|block_hash| *block_hash == hash)
+            .and_then(|index| state_ro.kura().get_block_by_height(index as u64 + 1))
+            .map(|block| (*block).clone())
+            .ok_or(QueryExecutionFail::Find(FindError::Block(hash)))
+    }
+}
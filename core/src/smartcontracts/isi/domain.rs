@@ -35,7 +35,11 @@ impl Registrable for iroha_data_model::domain::NewDomain {
 /// - update metadata
 /// - transfer, etc.
 pub mod isi {
-    use iroha_data_model::isi::error::{InstructionExecutionError, RepetitionError};
+    use std::collections::BTreeSet;
+
+    use iroha_data_model::isi::error::{
+        BatchItemError, InstructionExecutionError, RepetitionError,
+    };
     use iroha_logger::prelude::*;
 
     use super::*;
@@ -50,20 +54,9 @@ pub mod isi {
             let account: Account = self.object.build(authority);
             let account_id = account.id().clone();
 
-            if *account_id.domain_id() == *iroha_genesis::GENESIS_DOMAIN_ID {
-                return Err(InstructionExecutionError::InvariantViolation(
-                    "Not allowed to register account in genesis domain".to_owned(),
-                ));
-            }
+            check_new_account(&account_id, state_transaction)?;
 
             let domain = state_transaction.world.domain_mut(&account_id.domain_id)?;
-            if domain.accounts.contains_key(&account_id) {
-                return Err(RepetitionError {
-                    instruction_type: InstructionType::Register,
-                    id: IdBox::AccountId(account_id),
-                }
-                .into());
-            }
             domain.add_account(account.clone());
 
             state_transaction
@@ -74,6 +67,87 @@ pub mod isi {
         }
     }
 
+    impl Execute for RegisterMany<Account> {
+        #[metrics(+"register_many_accounts")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            // Validate the whole batch before registering anything
+            let mut account_ids = BTreeSet::new();
+            for (index, new_account) in self.objects.iter().enumerate() {
+                let account_id = &new_account.id;
+                if !account_ids.insert(account_id) {
+                    return Err(BatchItemError::new(
+                        index,
+                        RepetitionError {
+                            instruction_type: InstructionType::RegisterMany,
+                            id: IdBox::AccountId(account_id.clone()),
+                        },
+                    )
+                    .into());
+                }
+                check_new_account(account_id, state_transaction)
+                    .map_err(|error| BatchItemError::new(index, error))?;
+            }
+
+            for (index, new_account) in self.objects.into_iter().enumerate() {
+                Register::account(new_account)
+                    .execute(authority, state_transaction)
+                    .map_err(|error| BatchItemError::new(index, error))?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RegisterIfAbsent<Account> {
+        #[metrics(+"register_account_if_absent")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = &self.object.id;
+
+            if state_transaction.world.account(account_id).is_ok() {
+                let account_id = account_id.clone();
+                state_transaction
+                    .world
+                    .emit_events(Some(DomainEvent::Account(AccountEvent::AlreadyRegistered(
+                        account_id,
+                    ))));
+                return Ok(());
+            }
+
+            Register::account(self.object).execute(authority, state_transaction)
+        }
+    }
+
+    /// Check that an account with `account_id` can be registered
+    fn check_new_account(
+        account_id: &AccountId,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        if *account_id.domain_id() == *iroha_genesis::GENESIS_DOMAIN_ID {
+            return Err(InstructionExecutionError::InvariantViolation(
+                "Not allowed to register account in genesis domain".to_owned(),
+            ));
+        }
+
+        let domain = state_transaction.world.domain(&account_id.domain_id)?;
+        if domain.accounts.contains_key(account_id) {
+            return Err(RepetitionError {
+                instruction_type: InstructionType::Register,
+                id: IdBox::AccountId(account_id.clone()),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
     impl Execute for Unregister<Account> {
         #[metrics(+"unregister_account")]
         fn execute(
@@ -83,6 +157,16 @@ pub mod isi {
         ) -> Result<(), Error> {
             let account_id = self.object_id;
 
+            if state_transaction
+                .world
+                .account(&account_id)
+                .is_ok_and(|account| account.assets.values().any(Asset::is_locked))
+            {
+                return Err(InstructionExecutionError::InvariantViolation(format!(
+                    "Can't unregister account `{account_id}` while part of its assets is locked"
+                )));
+            }
+
             let domain = state_transaction.world.domain_mut(&account_id.domain_id)?;
             if domain.remove_account(&account_id).is_none() {
                 return Err(FindError::Account(account_id).into());
@@ -106,24 +190,13 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let asset_definition = self.object.build(authority);
-            asset_definition
-                .id()
-                .name
-                .validate_len(state_transaction.config.ident_length_limits)
-                .map_err(Error::from)?;
-
             let asset_definition_id = asset_definition.id().clone();
+
+            check_new_asset_definition(&asset_definition_id, state_transaction)?;
+
             let domain = state_transaction
                 .world
                 .domain_mut(&asset_definition_id.domain_id)?;
-            if domain.asset_definitions.contains_key(&asset_definition_id) {
-                return Err(RepetitionError {
-                    instruction_type: InstructionType::Register,
-                    id: IdBox::AssetDefinitionId(asset_definition_id),
-                }
-                .into());
-            }
-
             domain.add_asset_total_quantity(asset_definition_id, Numeric::ZERO);
 
             domain.add_asset_definition(asset_definition.clone());
@@ -138,6 +211,93 @@ pub mod isi {
         }
     }
 
+    impl Execute for RegisterMany<AssetDefinition> {
+        #[metrics(+"register_many_asset_definitions")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            // Validate the whole batch before registering anything
+            let mut asset_definition_ids = BTreeSet::new();
+            for (index, new_asset_definition) in self.objects.iter().enumerate() {
+                let asset_definition_id = &new_asset_definition.id;
+                if !asset_definition_ids.insert(asset_definition_id) {
+                    return Err(BatchItemError::new(
+                        index,
+                        RepetitionError {
+                            instruction_type: InstructionType::RegisterMany,
+                            id: IdBox::AssetDefinitionId(asset_definition_id.clone()),
+                        },
+                    )
+                    .into());
+                }
+                check_new_asset_definition(asset_definition_id, state_transaction)
+                    .map_err(|error| BatchItemError::new(index, error))?;
+            }
+
+            for (index, new_asset_definition) in self.objects.into_iter().enumerate() {
+                Register::asset_definition(new_asset_definition)
+                    .execute(authority, state_transaction)
+                    .map_err(|error| BatchItemError::new(index, error))?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RegisterIfAbsent<AssetDefinition> {
+        #[metrics(+"register_asset_definition_if_absent")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_definition_id = &self.object.id;
+
+            let is_registered = state_transaction
+                .world
+                .domain(&asset_definition_id.domain_id)
+                .is_ok_and(|domain| domain.asset_definitions.contains_key(asset_definition_id));
+            if is_registered {
+                let asset_definition_id = asset_definition_id.clone();
+                state_transaction
+                    .world
+                    .emit_events(Some(DomainEvent::AssetDefinition(
+                        AssetDefinitionEvent::AlreadyRegistered(asset_definition_id),
+                    )));
+                return Ok(());
+            }
+
+            Register::asset_definition(self.object).execute(authority, state_transaction)
+        }
+    }
+
+    /// Check that an asset definition with `asset_definition_id` can be registered
+    fn check_new_asset_definition(
+        asset_definition_id: &AssetDefinitionId,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        asset_definition_id
+            .name
+            .validate_len(state_transaction.config.ident_length_limits)
+            .map_err(Error::from)?;
+        validate_naming_policy(&asset_definition_id.name, state_transaction)?;
+
+        let domain = state_transaction
+            .world
+            .domain(&asset_definition_id.domain_id)?;
+        if domain.asset_definitions.contains_key(asset_definition_id) {
+            return Err(RepetitionError {
+                instruction_type: InstructionType::Register,
+                id: IdBox::AssetDefinitionId(asset_definition_id.clone()),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
     impl Execute for Unregister<AssetDefinition> {
         #[metrics(+"unregister_asset_definition")]
         fn execute(
@@ -203,6 +363,19 @@ pub mod isi {
         }
     }
 
+    impl Execute for MigrateAssetValueType {
+        #[metrics(+"migrate_asset_value_type")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            state_transaction
+                .world
+                .migrate_asset_value_type(&self.asset_definition_id, self.value_type)
+        }
+    }
+
     impl Execute for SetKeyValue<AssetDefinition> {
         #[metrics(+"set_key_value_asset_definition")]
         fn execute(
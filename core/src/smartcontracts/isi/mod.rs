@@ -12,7 +12,10 @@ pub mod world;
 
 use eyre::Result;
 use iroha_data_model::{
-    isi::{error::InstructionExecutionError as Error, *},
+    isi::{
+        error::{InstructionExecutionError as Error, InvalidParameterError},
+        *,
+    },
     prelude::*,
 };
 use iroha_logger::prelude::*;
@@ -44,9 +47,13 @@ impl Execute for InstructionBox {
 
         match self {
             Self::Register(isi) => isi.execute(authority, state_transaction),
+            Self::RegisterMany(isi) => isi.execute(authority, state_transaction),
+            Self::RegisterIfAbsent(isi) => isi.execute(authority, state_transaction),
             Self::Unregister(isi) => isi.execute(authority, state_transaction),
             Self::Mint(isi) => isi.execute(authority, state_transaction),
             Self::Burn(isi) => isi.execute(authority, state_transaction),
+            Self::Lock(isi) => isi.execute(authority, state_transaction),
+            Self::Unlock(isi) => isi.execute(authority, state_transaction),
             Self::Transfer(isi) => isi.execute(authority, state_transaction),
             Self::Fail(isi) => isi.execute(authority, state_transaction),
             Self::SetKeyValue(isi) => isi.execute(authority, state_transaction),
@@ -55,6 +62,11 @@ impl Execute for InstructionBox {
             Self::Revoke(isi) => isi.execute(authority, state_transaction),
             Self::ExecuteTrigger(isi) => isi.execute(authority, state_transaction),
             Self::SetParameter(isi) => isi.execute(authority, state_transaction),
+            Self::SetParameterIf(isi) => isi.execute(authority, state_transaction),
+            Self::SetParameters(isi) => isi.execute(authority, state_transaction),
+            Self::MintBatch(isi) => isi.execute(authority, state_transaction),
+            Self::SetAccountMetadata(isi) => isi.execute(authority, state_transaction),
+            Self::MigrateAssetValueType(isi) => isi.execute(authority, state_transaction),
             Self::NewParameter(isi) => isi.execute(authority, state_transaction),
             Self::Upgrade(isi) => isi.execute(authority, state_transaction),
             Self::Log(isi) => isi.execute(authority, state_transaction),
@@ -81,6 +93,36 @@ impl Execute for RegisterBox {
     }
 }
 
+impl Execute for RegisterManyBox {
+    #[iroha_logger::log(name = "register_many", skip_all)]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Domain(isi) => isi.execute(authority, state_transaction),
+            Self::Account(isi) => isi.execute(authority, state_transaction),
+            Self::AssetDefinition(isi) => isi.execute(authority, state_transaction),
+        }
+    }
+}
+
+impl Execute for RegisterIfAbsentBox {
+    #[iroha_logger::log(name = "register_if_absent", skip_all)]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Domain(isi) => isi.execute(authority, state_transaction),
+            Self::Account(isi) => isi.execute(authority, state_transaction),
+            Self::AssetDefinition(isi) => isi.execute(authority, state_transaction),
+        }
+    }
+}
+
 impl Execute for UnregisterBox {
     #[iroha_logger::log(name = "unregister", skip_all, fields(id))]
     fn execute(
@@ -128,6 +170,32 @@ impl Execute for BurnBox {
     }
 }
 
+impl Execute for LockBox {
+    #[iroha_logger::log(name = "lock", skip_all, fields(destination))]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Asset(isi) => isi.execute(authority, state_transaction),
+        }
+    }
+}
+
+impl Execute for UnlockBox {
+    #[iroha_logger::log(name = "unlock", skip_all, fields(destination))]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Asset(isi) => isi.execute(authority, state_transaction),
+        }
+    }
+}
+
 impl Execute for TransferBox {
     #[iroha_logger::log(name = "transfer", skip_all, fields(from, to))]
     fn execute(
@@ -230,6 +298,19 @@ impl Execute for RevokeBox {
     }
 }
 
+/// Check that the `name` of a registered entity conforms to the configured naming policy, if any
+pub(crate) fn validate_naming_policy(
+    name: &Name,
+    state_transaction: &StateTransaction<'_, '_>,
+) -> Result<(), Error> {
+    match &state_transaction.config.ident_naming_policy {
+        Some(policy) if !policy.is_match(name.as_ref()) => {
+            Err(InvalidParameterError::NamingPolicy(name.clone()).into())
+        }
+        _ => Ok(()),
+    }
+}
+
 pub mod prelude {
     //! Re-export important traits and types for glob import `(::*)`
     pub use super::*;
@@ -304,6 +385,364 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn locked_asset_cannot_be_transferred() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let (receiver_id, _receiver_keypair) = gen_account_in("wonderland");
+        let (escrow_id, _escrow_keypair) = gen_account_in("wonderland");
+        let asset_definition_id = AssetDefinitionId::from_str("tulip#wonderland")?;
+        let asset_id = AssetId::new(asset_definition_id.clone(), account_id.clone());
+        Register::account(Account::new(receiver_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Register::account(Account::new(escrow_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(10_u32, asset_id.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+
+        Lock::asset_numeric(5_u32, asset_id.clone()).execute(&escrow_id, &mut state_transaction)?;
+        assert_eq!(
+            state_transaction.world.asset(&asset_id)?.locked(),
+            Numeric::new(5, 0)
+        );
+        assert!(
+            Transfer::asset_numeric(asset_id.clone(), 10_u32, receiver_id.clone())
+                .execute(&account_id, &mut state_transaction)
+                .is_err()
+        );
+        assert!(Lock::asset_numeric(6_u32, asset_id.clone())
+            .execute(&account_id, &mut state_transaction)
+            .is_err());
+        // the owner can't release the lock set by the escrow
+        assert!(Unlock::asset_numeric(5_u32, asset_id.clone())
+            .execute(&account_id, &mut state_transaction)
+            .is_err());
+        assert!(Unregister::asset(asset_id.clone())
+            .execute(&account_id, &mut state_transaction)
+            .is_err());
+        assert!(Unregister::account(account_id.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+            .is_err());
+
+        Unlock::asset_numeric(5_u32, asset_id.clone())
+            .execute(&escrow_id, &mut state_transaction)?;
+        assert!(!state_transaction.world.asset(&asset_id)?.is_locked());
+        Transfer::asset_numeric(asset_id.clone(), 10_u32, receiver_id)
+            .execute(&account_id, &mut state_transaction)?;
+        assert!(state_transaction.world.asset(&asset_id).is_err());
+        Ok(())
+    }
+
+    #[test]
+    async fn mint_batch_is_applied_atomically() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let (carol_id, _carol_keypair) = gen_account_in("wonderland");
+        let asset_definition_id = AssetDefinitionId::from_str("tulip#wonderland")?;
+        for account_id in [&bob_id, &carol_id] {
+            Register::account(Account::new(account_id.clone()))
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        }
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        let balance = |state_transaction: &StateTransaction<'_, '_>, account_id: &AccountId| {
+            let asset_id = AssetId::new(asset_definition_id.clone(), account_id.clone());
+            state_transaction
+                .world
+                .asset(&asset_id)
+                .map(|asset| asset.value.clone())
+        };
+
+        MintBatch::new(
+            asset_definition_id.clone(),
+            [
+                (ALICE_ID.clone(), numeric!(10)),
+                (bob_id.clone(), numeric!(20)),
+                (carol_id.clone(), numeric!(30)),
+            ],
+        )
+        .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        assert_eq!(balance(&state_transaction, &ALICE_ID)?, numeric!(10).into());
+        assert_eq!(balance(&state_transaction, &bob_id)?, numeric!(20).into());
+        assert_eq!(balance(&state_transaction, &carol_id)?, numeric!(30).into());
+        assert_eq!(
+            state_transaction
+                .world
+                .asset_total_amount(&asset_definition_id)?,
+            numeric!(60)
+        );
+
+        assert!(MintBatch::new(
+            asset_definition_id.clone(),
+            [
+                (ALICE_ID.clone(), numeric!(1)),
+                (bob_id.clone(), Numeric::MAX)
+            ],
+        )
+        .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+        .is_err());
+        assert_eq!(balance(&state_transaction, &ALICE_ID)?, numeric!(10).into());
+        assert_eq!(balance(&state_transaction, &bob_id)?, numeric!(20).into());
+        assert_eq!(
+            state_transaction
+                .world
+                .asset_total_amount(&asset_definition_id)?,
+            numeric!(60)
+        );
+        Ok(())
+    }
+
+    #[test]
+    async fn unregister_asset_definition_removes_all_holdings() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let (carol_id, _carol_keypair) = gen_account_in("wonderland");
+        let asset_definition_id = AssetDefinitionId::from_str("tulip#wonderland")?;
+        for account_id in [&bob_id, &carol_id] {
+            Register::account(Account::new(account_id.clone()))
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        }
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        let holders = [ALICE_ID.clone(), bob_id, carol_id];
+        MintBatch::new(
+            asset_definition_id.clone(),
+            holders
+                .iter()
+                .map(|account_id| (account_id.clone(), numeric!(10))),
+        )
+        .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+
+        Unregister::asset_definition(asset_definition_id.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+
+        for account_id in &holders {
+            let asset_id = AssetId::new(asset_definition_id.clone(), account_id.clone());
+            assert!(state_transaction.world.asset(&asset_id).is_err());
+        }
+        assert!(state_transaction
+            .world
+            .asset_definition(&asset_definition_id)
+            .is_err());
+        assert!(state_transaction
+            .world
+            .asset_total_amount(&asset_definition_id)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    async fn grant_then_revoke_permission_leaves_account_without_it() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let permission = Permission::new("CanDoThings".parse()?, serde_json::json!(null));
+        state_transaction
+            .world
+            .executor_data_model
+            .get_mut()
+            .permissions
+            .insert(permission.id.clone());
+        let has_permission = |state_transaction: &StateTransaction<'_, '_>| {
+            state_transaction
+                .world
+                .account_contains_inherent_permission(&ALICE_ID, &permission)
+        };
+
+        let grant: InstructionBox = Grant::permission(permission.clone(), ALICE_ID.clone()).into();
+        grant.execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        assert!(has_permission(&state_transaction));
+
+        let revoke: InstructionBox =
+            Revoke::permission(permission.clone(), ALICE_ID.clone()).into();
+        revoke.execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        assert!(!has_permission(&state_transaction));
+        assert_eq!(
+            state_transaction
+                .world
+                .account_permissions_iter(&ALICE_ID)?
+                .count(),
+            0
+        );
+        Ok(())
+    }
+
+    #[test]
+    async fn register_many_is_applied_atomically() -> Result<()> {
+        use iroha_data_model::isi::error::BatchItemError;
+
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let new_domain = |i: usize| Domain::new(format!("domain_{i}").parse().expect("Valid"));
+
+        RegisterMany::domains((0..100).map(new_domain))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        // `wonderland` is registered too
+        assert_eq!(state_transaction.world.domains_iter().count(), 101);
+
+        // `domain_42` is already registered, so none of the new domains are
+        let err = RegisterMany::domains([new_domain(100), new_domain(42), new_domain(101)])
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+            .expect_err("Domain is registered twice");
+        let Error::Batch(BatchItemError { index, error }) = err else {
+            panic!("Unexpected error: {err}");
+        };
+        assert_eq!(index, 1);
+        assert!(matches!(*error, Error::Repetition(_)));
+        assert_eq!(state_transaction.world.domains_iter().count(), 101);
+
+        // Same goes for a domain repeated within the batch
+        let err = RegisterMany::domains([new_domain(100), new_domain(100)])
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+            .expect_err("Domain is registered twice");
+        assert!(matches!(err, Error::Batch(BatchItemError { index: 1, .. })));
+        assert_eq!(state_transaction.world.domains_iter().count(), 101);
+        Ok(())
+    }
+
+    #[test]
+    async fn register_if_absent_twice_is_a_no_op() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let asset_definition_id = AssetDefinitionId::from_str("tulip#wonderland")?;
+        let register_if_absent = RegisterIfAbsent::asset_definition(AssetDefinition::numeric(
+            asset_definition_id.clone(),
+        ));
+
+        register_if_absent
+            .clone()
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        register_if_absent.execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        let domain = state_transaction
+            .world
+            .domain(&asset_definition_id.domain_id)?;
+        assert_eq!(domain.asset_definitions.len(), 2);
+
+        // The existing definition is left as is even if the new one differs
+        RegisterIfAbsent::asset_definition(AssetDefinition::store(asset_definition_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        let asset_definition = state_transaction
+            .world
+            .asset_definition(&asset_definition_id)?;
+        assert!(matches!(
+            asset_definition.value_type(),
+            AssetValueType::Numeric(_)
+        ));
+
+        // Whereas a plain registration still fails
+        let err = Register::asset_definition(AssetDefinition::numeric(asset_definition_id))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+            .expect_err("Asset definition is registered twice");
+        assert!(matches!(err, Error::Repetition(_)));
+        Ok(())
+    }
+
+    #[test]
+    async fn set_parameters_is_applied_atomically() -> Result<()> {
+        use iroha_data_model::parameter::default::{BLOCK_TIME, COMMIT_TIME_LIMIT};
+
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        for parameter in ["?BlockTime=2000", "?CommitTimeLimit=4000"] {
+            NewParameter::new(Parameter::from_str(parameter)?)
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        }
+        let param = |state_transaction: &StateTransaction<'_, '_>, name: &str| {
+            state_transaction.world.query_param::<u64, _>(name)
+        };
+
+        // `MaxTransactionsInBlock` isn't registered, so nothing is changed
+        assert!(SetParameters::new([
+            Parameter::from_str("?BlockTime=1000")?,
+            Parameter::from_str("?MaxTransactionsInBlock=512")?,
+        ])
+        .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+        .is_err());
+        assert_eq!(param(&state_transaction, BLOCK_TIME), Some(2000));
+
+        SetParameters::new([
+            Parameter::from_str("?BlockTime=1000")?,
+            Parameter::from_str("?CommitTimeLimit=3000")?,
+        ])
+        .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        assert_eq!(param(&state_transaction, BLOCK_TIME), Some(1000));
+        assert_eq!(param(&state_transaction, COMMIT_TIME_LIMIT), Some(3000));
+        Ok(())
+    }
+
+    #[test]
+    async fn sumeragi_parameters_are_validated() -> Result<()> {
+        use iroha_data_model::{
+            isi::error::{InvalidParameterError, ParameterConstraint, ParameterValueError},
+            parameter::{default::BLOCK_TIME, ParameterValueBox},
+        };
+
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        for parameter in ["?BlockTime=2000", "?CommitTimeLimit=4000"] {
+            NewParameter::new(Parameter::from_str(parameter)?)
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        }
+        let rejection = |parameter: &str, constraint| {
+            Error::InvalidParameter(InvalidParameterError::ParameterValue(ParameterValueError {
+                parameter_id: parameter.parse().expect("Valid"),
+                constraint,
+            }))
+        };
+
+        let error = SetParameter::new(Parameter::from_str("?BlockTime=0")?)
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+            .expect_err("Zero block time must be rejected");
+        assert_eq!(
+            error,
+            rejection("BlockTime", ParameterConstraint::PositiveMilliseconds)
+        );
+
+        let error = SetParameter::new(Parameter::from_str("?CommitTimeLimit=1999")?)
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+            .expect_err("Commit time limit below block time must be rejected");
+        assert_eq!(
+            error,
+            rejection(
+                "CommitTimeLimit",
+                ParameterConstraint::NotLessThan("BlockTime".parse()?)
+            )
+        );
+
+        for value in [1_u64, 4000] {
+            SetParameter::new(Parameter::new(
+                BLOCK_TIME.parse()?,
+                ParameterValueBox::from(value),
+            ))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+            assert_eq!(
+                state_transaction.world.query_param::<u64, _>(BLOCK_TIME),
+                Some(value)
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     async fn account_metadata() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
@@ -336,6 +775,48 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn account_metadata_is_set_from_map() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let metadata = |state_transaction: &StateTransaction<'_, '_>| {
+            state_transaction
+                .world
+                .map_account(&account_id, |account| account.metadata().clone())
+        };
+
+        SetKeyValue::account(account_id.clone(), Name::from_str("stale")?, 0_u32)
+            .execute(&account_id, &mut state_transaction)?;
+        SetAccountMetadata::merge(
+            account_id.clone(),
+            [
+                (Name::from_str("one")?, 1_u32.into()),
+                (Name::from_str("two")?, 2_u32.into()),
+                (Name::from_str("three")?, 3_u32.into()),
+            ],
+        )
+        .execute(&account_id, &mut state_transaction)?;
+        let merged = metadata(&state_transaction)?;
+        assert_eq!(merged.iter().count(), 4);
+        assert_eq!(merged.get("one"), Some(&1_u32.into()));
+        assert_eq!(merged.get("two"), Some(&2_u32.into()));
+        assert_eq!(merged.get("three"), Some(&3_u32.into()));
+        assert_eq!(merged.get("stale"), Some(&0_u32.into()));
+
+        SetAccountMetadata::replace(
+            account_id.clone(),
+            [(Name::from_str("one")?, 10_u32.into())],
+        )
+        .execute(&account_id, &mut state_transaction)?;
+        let replaced = metadata(&state_transaction)?;
+        assert_eq!(replaced.iter().count(), 1);
+        assert_eq!(replaced.get("one"), Some(&10_u32.into()));
+        Ok(())
+    }
+
     #[test]
     async fn asset_definition_metadata() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
@@ -501,4 +982,58 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[test]
+    async fn naming_policy_is_enforced_on_register() -> Result<()> {
+        use iroha_data_model::isi::error::{
+            InvalidParameterError, ParameterConstraint, ParameterValueError,
+        };
+
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let naming_policy = Parameter::from_str("?WSVIdentNamingPolicy=[a-z0-9]+_NP")?;
+        let state = State::with_parameters(
+            [naming_policy].into_iter().collect(),
+            World::default(),
+            kura,
+            query_handle,
+        )?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let account_id = SAMPLE_GENESIS_ACCOUNT_ID.clone();
+
+        assert_eq!(
+            Register::domain(Domain::new(DomainId::from_str("Wonderland")?))
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvalidParameter(InvalidParameterError::NamingPolicy("Wonderland".parse()?))
+        );
+        Register::domain(Domain::new(DomainId::from_str("wonderland")?))
+            .execute(&account_id, &mut state_transaction)?;
+
+        assert_eq!(
+            Register::asset_definition(AssetDefinition::numeric(AssetDefinitionId::from_str(
+                "Rose#wonderland"
+            )?))
+            .execute(&account_id, &mut state_transaction)
+            .expect_err("Error expected"),
+            Error::InvalidParameter(InvalidParameterError::NamingPolicy("Rose".parse()?))
+        );
+        Register::asset_definition(AssetDefinition::numeric(AssetDefinitionId::from_str(
+            "rose#wonderland",
+        )?))
+        .execute(&account_id, &mut state_transaction)?;
+
+        assert_eq!(
+            SetParameter::new(Parameter::from_str("?WSVIdentNamingPolicy=[a-z_NP")?)
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("Invalid regular expression must be rejected"),
+            Error::InvalidParameter(InvalidParameterError::ParameterValue(ParameterValueError {
+                parameter_id: "WSVIdentNamingPolicy".parse()?,
+                constraint: ParameterConstraint::Regex,
+            }))
+        );
+
+        Ok(())
+    }
 }
@@ -249,8 +249,11 @@ impl ValidQuery for QueryBox {
                 FindAssetDefinitionById,
                 FindAssetQuantityById,
                 FindTotalAssetQuantityByAssetDefinitionId,
+                FindTotalAssetQuantityByDomainIdAndAssetDefinitionId,
                 FindDomainById,
                 FindBlockHeaderByHash,
+                FindBlockByHeight,
+                FindBlockByHash,
                 FindTransactionByHash,
                 FindTriggerById,
                 FindRoleByRoleId,
@@ -260,6 +263,7 @@ impl ValidQuery for QueryBox {
                 FindAssetDefinitionKeyValueByIdAndKey,
                 FindTriggerKeyValueByIdAndKey,
                 FindExecutorDataModel,
+                FindTopology,
             }
 
             FindAllAccounts,
@@ -285,6 +289,7 @@ impl ValidQuery for QueryBox {
             FindAllRoleIds,
             FindRolesByAccountId,
             FindAllParameters,
+            FindParametersBySubsystem,
         }
     }
 }
@@ -523,6 +528,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn find_block_by_height_and_hash() -> Result<()> {
+        let state = state_with_test_blocks_and_transactions(3, 1, 1)?;
+        let state_view = state.view();
+        let block = state_view.all_blocks().nth(1).expect("state has 3 blocks");
+
+        assert_eq!(FindBlockByHeight::new(2).execute(&state_view)?, *block);
+        assert_eq!(
+            FindBlockByHash::new(block.hash()).execute(&state_view)?,
+            *block
+        );
+
+        for height in [0, 4] {
+            assert_eq!(
+                FindBlockByHeight::new(height).execute(&state_view),
+                Err(Error::Find(FindError::BlockHeight(height)))
+            );
+        }
+        assert!(
+            FindBlockByHash::new(HashOf::from_untyped_unchecked(Hash::new([42])))
+                .execute(&state_view)
+                .is_err()
+        );
+
+        Ok(())
+    }
+
     #[test]
     async fn find_all_transactions() -> Result<()> {
         let num_blocks = 100;
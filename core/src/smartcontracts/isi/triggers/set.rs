@@ -617,6 +617,13 @@ impl<'set> SetBlock<'set> {
     pub fn extract_matched_ids(&mut self) -> Vec<(EventBox, TriggerId)> {
         core::mem::take(&mut self.matched_ids)
     }
+
+    /// Put back extracted `matched_ids` which weren't executed,
+    /// so that they are executed before the ones matched later
+    pub fn defer_matched_ids(&mut self, ids: Vec<(EventBox, TriggerId)>) {
+        let matched_later = core::mem::replace(&mut *self.matched_ids, ids);
+        self.matched_ids.extend(matched_later);
+    }
 }
 
 impl<'block, 'set> SetTransaction<'block, 'set> {
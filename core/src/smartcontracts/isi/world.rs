@@ -20,8 +20,13 @@ pub mod isi {
     use std::collections::BTreeSet;
 
     use eyre::Result;
+    use iroha_config::naming::NamingPolicy;
+    use iroha_crypto::HashOf;
     use iroha_data_model::{
-        isi::error::{InstructionExecutionError, InvalidParameterError, RepetitionError},
+        isi::error::{
+            BatchItemError, InstructionExecutionError, InvalidParameterError, ParameterConstraint,
+            ParameterValueError, RepetitionError,
+        },
         prelude::*,
         query::error::FindError,
         Level,
@@ -86,26 +91,9 @@ pub mod isi {
             let domain: Domain = self.object.build(authority);
             let domain_id = domain.id().clone();
 
-            domain_id
-                .name
-                .validate_len(state_transaction.config.ident_length_limits)
-                .map_err(Error::from)?;
-
-            if domain_id == *iroha_genesis::GENESIS_DOMAIN_ID {
-                return Err(InstructionExecutionError::InvariantViolation(
-                    "Not allowed to register genesis domain".to_owned(),
-                ));
-            }
+            check_new_domain(&domain_id, state_transaction)?;
 
             let world = &mut state_transaction.world;
-            if world.domains.get(&domain_id).is_some() {
-                return Err(RepetitionError {
-                    instruction_type: InstructionType::Register,
-                    id: IdBox::DomainId(domain_id),
-                }
-                .into());
-            }
-
             world.domains.insert(domain_id, domain.clone());
 
             world.emit_events(Some(DomainEvent::Created(domain)));
@@ -114,6 +102,90 @@ pub mod isi {
         }
     }
 
+    impl Execute for RegisterMany<Domain> {
+        #[metrics(+"register_many_domains")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            // Validate the whole batch before registering anything
+            let mut domain_ids = BTreeSet::new();
+            for (index, new_domain) in self.objects.iter().enumerate() {
+                let domain_id = &new_domain.id;
+                if !domain_ids.insert(domain_id) {
+                    return Err(BatchItemError::new(
+                        index,
+                        RepetitionError {
+                            instruction_type: InstructionType::RegisterMany,
+                            id: IdBox::DomainId(domain_id.clone()),
+                        },
+                    )
+                    .into());
+                }
+                check_new_domain(domain_id, state_transaction)
+                    .map_err(|error| BatchItemError::new(index, error))?;
+            }
+
+            for (index, new_domain) in self.objects.into_iter().enumerate() {
+                Register::domain(new_domain)
+                    .execute(authority, state_transaction)
+                    .map_err(|error| BatchItemError::new(index, error))?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RegisterIfAbsent<Domain> {
+        #[metrics(+"register_domain_if_absent")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let domain_id = &self.object.id;
+
+            if state_transaction.world.domains.get(domain_id).is_some() {
+                let domain_id = domain_id.clone();
+                state_transaction
+                    .world
+                    .emit_events(Some(DomainEvent::AlreadyRegistered(domain_id)));
+                return Ok(());
+            }
+
+            Register::domain(self.object).execute(authority, state_transaction)
+        }
+    }
+
+    /// Check that a domain with `domain_id` can be registered
+    fn check_new_domain(
+        domain_id: &DomainId,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        domain_id
+            .name
+            .validate_len(state_transaction.config.ident_length_limits)
+            .map_err(Error::from)?;
+        validate_naming_policy(&domain_id.name, state_transaction)?;
+
+        if *domain_id == *iroha_genesis::GENESIS_DOMAIN_ID {
+            return Err(InstructionExecutionError::InvariantViolation(
+                "Not allowed to register genesis domain".to_owned(),
+            ));
+        }
+
+        if state_transaction.world.domains.get(domain_id).is_some() {
+            return Err(RepetitionError {
+                instruction_type: InstructionType::Register,
+                id: IdBox::DomainId(domain_id.clone()),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
     impl Execute for Unregister<Domain> {
         #[metrics("unregister_domain")]
         fn execute(
@@ -303,13 +375,109 @@ pub mod isi {
             let parameter_id = parameter.id.clone();
 
             let world = &mut state_transaction.world;
-            if !world.parameters.remove(&parameter) {
+            check_parameter_values(world, &[&parameter])
+                .map_err(InvalidParameterError::ParameterValue)?;
+            let Some(old_parameter) = world.parameters.swap_take(&parameter) else {
+                return Err(FindError::Parameter(parameter_id).into());
+            };
+            let new_value = parameter.val.clone();
+
+            world.parameters.insert(parameter);
+
+            world.emit_events(Some(ConfigurationEvent::Changed(ParameterChanged {
+                parameter_id,
+                old_value: old_parameter.val,
+                new_value,
+            })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for SetParameterIf {
+        #[metrics(+"set_parameter_if")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let parameter = self.parameter;
+            let parameter_id = parameter.id.clone();
+
+            let world = &mut state_transaction.world;
+            let Some(current) = world.parameters.get(&parameter) else {
                 return Err(FindError::Parameter(parameter_id).into());
+            };
+            if current.val != self.expected_current {
+                return Err(InstructionExecutionError::InvariantViolation(format!(
+                    "Parameter `{parameter_id}` has value `{}`, expected `{}`",
+                    current.val, self.expected_current
+                )));
             }
+            check_parameter_values(world, &[&parameter])
+                .map_err(InvalidParameterError::ParameterValue)?;
 
+            let old_value = current.val.clone();
+            let new_value = parameter.val.clone();
+            world.parameters.remove(&parameter);
             world.parameters.insert(parameter);
 
-            world.emit_events(Some(ConfigurationEvent::Changed(parameter_id)));
+            world.emit_events(Some(ConfigurationEvent::Changed(ParameterChanged {
+                parameter_id,
+                old_value,
+                new_value,
+            })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for SetParameters {
+        #[metrics(+"set_parameters")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let world = &mut state_transaction.world;
+
+            // Validate the whole batch before changing anything
+            let mut parameter_ids = BTreeSet::new();
+            for parameter in &self.parameters {
+                if !parameter_ids.insert(&parameter.id) {
+                    return Err(RepetitionError {
+                        instruction_type: InstructionType::SetParameters,
+                        id: IdBox::ParameterId(parameter.id.clone()),
+                    }
+                    .into());
+                }
+                let Some(current) = world.parameters.get(parameter) else {
+                    return Err(FindError::Parameter(parameter.id.clone()).into());
+                };
+                if core::mem::discriminant(&current.val) != core::mem::discriminant(&parameter.val)
+                {
+                    return Err(InstructionExecutionError::InvariantViolation(format!(
+                        "Parameter `{}` has value `{}` of a different kind than `{}`",
+                        parameter.id, current.val, parameter.val
+                    )));
+                }
+            }
+            check_parameter_values(world, &self.parameters.iter().collect::<Vec<_>>())
+                .map_err(InvalidParameterError::ParameterValue)?;
+
+            let mut events = Vec::with_capacity(self.parameters.len());
+            for parameter in self.parameters {
+                if let Some(old_parameter) = world.parameters.swap_take(&parameter) {
+                    events.push(ConfigurationEvent::Changed(ParameterChanged {
+                        parameter_id: parameter.id.clone(),
+                        old_value: old_parameter.val,
+                        new_value: parameter.val.clone(),
+                    }));
+                }
+                world.parameters.insert(parameter);
+            }
+
+            world.emit_events(events);
 
             Ok(())
         }
@@ -326,6 +494,8 @@ pub mod isi {
             let parameter_id = parameter.id.clone();
 
             let world = &mut state_transaction.world;
+            check_parameter_values(world, &[&parameter])
+                .map_err(InvalidParameterError::ParameterValue)?;
             if !world.parameters.insert(parameter) {
                 return Err(RepetitionError {
                     instruction_type: InstructionType::NewParameter,
@@ -348,6 +518,7 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let raw_executor = self.executor;
+            let new_executor_hash = HashOf::new(&raw_executor);
 
             let permissions_before = state_transaction
                 .world
@@ -374,6 +545,7 @@ pub mod isi {
             state_transaction
                 .world
                 .emit_events(std::iter::once(ExecutorEvent::Upgraded(ExecutorUpgrade {
+                    new_executor_hash,
                     new_data_model: state_transaction.world.executor_data_model.clone(),
                 })));
 
@@ -442,6 +614,80 @@ pub mod isi {
             .collect()
     }
 
+    /// Check that the parameters among `changed` get values Iroha can run with, i.e. the naming
+    /// policy is a valid regular expression and Sumeragi can run with the consensus timing.
+    /// Values of the parameters which aren't changed are taken from `world`.
+    pub(crate) fn check_parameter_values(
+        world: &impl WorldReadOnly,
+        changed: &[&Parameter],
+    ) -> Result<(), ParameterValueError> {
+        use iroha_data_model::parameter::{
+            default::{BLOCK_TIME, COMMIT_TIME_LIMIT, WSV_IDENT_NAMING_POLICY},
+            IdentNamingPolicy,
+        };
+
+        let find_changed = |name: &str| {
+            changed
+                .iter()
+                .copied()
+                .find(|parameter| parameter.id.name.as_ref() == name)
+        };
+
+        if let Some(parameter) = find_changed(WSV_IDENT_NAMING_POLICY) {
+            let is_valid = IdentNamingPolicy::try_from(parameter.val.clone())
+                .is_ok_and(|policy| policy.pattern.parse::<NamingPolicy>().is_ok());
+            if !is_valid {
+                return Err(ParameterValueError {
+                    parameter_id: parameter.id.clone(),
+                    constraint: ParameterConstraint::Regex,
+                });
+            }
+        }
+        let milliseconds = |name: &str| {
+            let Some(parameter) = find_changed(name) else {
+                return Ok(world.parameters().get(name).and_then(|parameter| {
+                    let value = u64::try_from(parameter.val.clone()).ok()?;
+                    Some((parameter.id.clone(), value))
+                }));
+            };
+
+            match u64::try_from(parameter.val.clone()) {
+                Ok(value) if value > 0 => Ok(Some((parameter.id.clone(), value))),
+                _ => Err(ParameterValueError {
+                    parameter_id: parameter.id.clone(),
+                    constraint: ParameterConstraint::PositiveMilliseconds,
+                }),
+            }
+        };
+
+        let block_time_changed = find_changed(BLOCK_TIME).is_some();
+        if !block_time_changed && find_changed(COMMIT_TIME_LIMIT).is_none() {
+            return Ok(());
+        }
+
+        let (Some((block_time_id, block_time)), Some((commit_time_id, commit_time))) =
+            (milliseconds(BLOCK_TIME)?, milliseconds(COMMIT_TIME_LIMIT)?)
+        else {
+            return Ok(());
+        };
+        if block_time <= commit_time {
+            return Ok(());
+        }
+
+        // Blame the parameter being changed
+        Err(if block_time_changed {
+            ParameterValueError {
+                parameter_id: block_time_id,
+                constraint: ParameterConstraint::NotGreaterThan(commit_time_id),
+            }
+        } else {
+            ParameterValueError {
+                parameter_id: commit_time_id,
+                constraint: ParameterConstraint::NotLessThan(block_time_id),
+            }
+        })
+    }
+
     impl Execute for Log {
         fn execute(
             self,
@@ -468,14 +714,14 @@ pub mod query {
     use eyre::Result;
     use iroha_data_model::{
         parameter::Parameter,
-        peer::Peer,
+        peer::{self, Peer},
         prelude::*,
         query::error::{FindError, QueryExecutionFail as Error},
         role::{Role, RoleId},
     };
 
     use super::*;
-    use crate::state::StateReadOnly;
+    use crate::{state::StateReadOnly, sumeragi::network_topology::Topology};
 
     impl ValidQuery for FindAllRoles {
         #[metrics(+"find_all_roles")]
@@ -536,6 +782,20 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindTopology {
+        #[metrics("find_topology")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<peer::Topology, Error> {
+            let peers = state_ro.world().peers().cloned().collect();
+            // Topology the next round starts with, i.e. assuming there were no view changes
+            let topology = match state_ro.latest_block_ref() {
+                Some(block) => Topology::recreate_topology(&block, 0, peers),
+                None => Topology::new(peers),
+            };
+
+            Ok(peer::Topology::new(topology.ordered_peers.into()))
+        }
+    }
+
     impl ValidQuery for FindExecutorDataModel {
         #[metrics("find_executor_data_model")]
         fn execute(&self, state_ro: &impl StateReadOnly) -> Result<ExecutorDataModel, Error> {
@@ -552,4 +812,24 @@ pub mod query {
             Ok(Box::new(state_ro.world().parameters_iter().cloned()))
         }
     }
+
+    impl ValidQuery for FindParametersBySubsystem {
+        #[metrics("find_parameters_by_subsystem")]
+        fn execute<'state>(
+            &self,
+            state_ro: &'state impl StateReadOnly,
+        ) -> Result<Box<dyn Iterator<Item = Parameter> + 'state>, Error> {
+            let subsystem = self.subsystem;
+
+            Ok(Box::new(
+                state_ro
+                    .world()
+                    .parameters_iter()
+                    .filter(move |parameter| {
+                        ParameterSubsystem::of(parameter.id()) == Some(subsystem)
+                    })
+                    .cloned(),
+            ))
+        }
+    }
 }
@@ -22,7 +22,7 @@ use iroha_logger::{error_span as wasm_log_span, prelude::tracing::Span};
 use iroha_wasm_codec::{self as codec, WasmUsize};
 use parity_scale_codec::Decode;
 use wasmtime::{
-    Caller, Config as WasmtimeConfig, Engine, Linker, Module, Store, StoreLimits,
+    Caller, Config as WasmtimeConfig, Engine, Linker, Module, ResourceLimiter, Store, StoreLimits,
     StoreLimitsBuilder, TypedFunc,
 };
 
@@ -215,12 +215,21 @@ pub mod error {
         HostExecution(#[source] wasmtime::Error),
         /// Execution limits exceeded
         ExecutionLimitsExceeded(#[source] wasmtime::Error),
+        /// Linear memory of the module exceeded the configured limit of {limit} bytes
+        OutOfMemory {
+            /// Configured memory limit in bytes
+            limit: u32,
+        },
         /// Other kind of trap
         Other(#[source] wasmtime::Error),
     }
 
     impl From<wasmtime::Error> for ExportFnCallError {
         fn from(err: wasmtime::Error) -> Self {
+            if let Some(&OutOfMemoryError { limit }) = err.downcast_ref() {
+                return Self::OutOfMemory { limit };
+            }
+
             match err.downcast_ref() {
                 Some(&trap) => match trap {
                     Trap::StackOverflow
@@ -235,6 +244,16 @@ pub mod error {
             }
         }
     }
+
+    /// Memory limit of {limit} bytes exceeded
+    ///
+    /// Raised by [`Limiter`](super::state::Limiter) when linear memory grows beyond the limit
+    #[derive(Debug, Copy, Clone, thiserror::Error, displaydoc::Display)]
+    #[ignore_extra_doc_attributes]
+    pub struct OutOfMemoryError {
+        /// Configured memory limit in bytes
+        pub limit: u32,
+    }
 }
 
 /// [`Result`] type for this module
@@ -337,26 +356,72 @@ pub mod state {
     use self::chain_state::ConstState;
     use super::*;
 
-    /// Construct [`StoreLimits`] from [`Configuration`]
-    ///
-    /// # Panics
-    ///
-    /// Panics if failed to convert `u32` into `usize` which should not happen
-    /// on any supported platform
-    pub fn store_limits_from_config(config: &Config) -> StoreLimits {
-        StoreLimitsBuilder::new()
-            .memory_size(config.max_memory_bytes as usize)
-            .instances(1)
-            .memories(1)
-            .tables(1)
-            .build()
+    /// [`StoreLimits`] which fail with [`OutOfMemoryError`] instead of
+    /// silently refusing to grow linear memory beyond the configured limit
+    pub struct Limiter {
+        store_limits: StoreLimits,
+        max_memory_bytes: u32,
+    }
+
+    impl Limiter {
+        /// Construct [`Limiter`] from [`Configuration`]
+        pub fn from_config(config: &Config) -> Self {
+            Self {
+                store_limits: StoreLimitsBuilder::new()
+                    .memory_size(config.max_memory_bytes as usize)
+                    .instances(1)
+                    .memories(1)
+                    .tables(1)
+                    .build(),
+                max_memory_bytes: config.max_memory_bytes,
+            }
+        }
+    }
+
+    impl ResourceLimiter for Limiter {
+        fn memory_growing(
+            &mut self,
+            current: usize,
+            desired: usize,
+            maximum: Option<usize>,
+        ) -> wasmtime::Result<bool> {
+            if desired > self.max_memory_bytes as usize {
+                return Err(OutOfMemoryError {
+                    limit: self.max_memory_bytes,
+                }
+                .into());
+            }
+
+            self.store_limits.memory_growing(current, desired, maximum)
+        }
+
+        fn table_growing(
+            &mut self,
+            current: u32,
+            desired: u32,
+            maximum: Option<u32>,
+        ) -> wasmtime::Result<bool> {
+            self.store_limits.table_growing(current, desired, maximum)
+        }
+
+        fn instances(&self) -> usize {
+            self.store_limits.instances()
+        }
+
+        fn tables(&self) -> usize {
+            self.store_limits.tables()
+        }
+
+        fn memories(&self) -> usize {
+            self.store_limits.memories()
+        }
     }
 
     /// State for most common operations.
     /// Generic over chain state type and specific executable state.
     pub struct CommonState<W, S> {
         pub(super) authority: AccountId,
-        pub(super) store_limits: StoreLimits,
+        pub(super) limiter: Limiter,
         /// Span inside of which all logs are recorded for this smart contract
         pub(super) log_span: Span,
         pub(super) executed_queries: IndexSet<QueryId>,
@@ -377,7 +442,7 @@ pub mod state {
         ) -> Self {
             Self {
                 authority,
-                store_limits: store_limits_from_config(&config),
+                limiter: Limiter::from_config(&config),
                 log_span,
                 executed_queries: IndexSet::new(),
                 state,
@@ -736,7 +801,7 @@ impl<W, S> Runtime<state::CommonState<W, S>> {
     fn create_store(&self, state: state::CommonState<W, S>) -> Store<state::CommonState<W, S>> {
         let mut store = Store::new(&self.engine, state);
 
-        store.limiter(|s| &mut s.store_limits);
+        store.limiter(|s| &mut s.limiter);
         store
             .set_fuel(self.config.fuel_limit)
             .expect("Wasm Runtime config is malformed, this is a bug");
@@ -752,6 +817,17 @@ impl<W: state::chain_state::ConstState, S> Runtime<state::CommonState<W, S>> {
         state: state::CommonState<W, S>,
         validate_fn_name: &'static str,
     ) -> Result<executor::Result> {
+        self.execute_executor_validate_internal_with_fuel(module, state, validate_fn_name)
+            .map(|(validation_res, _fuel_consumed)| validation_res)
+    }
+
+    /// Same as [`Self::execute_executor_validate_internal`], but also returns the amount of fuel consumed
+    fn execute_executor_validate_internal_with_fuel(
+        &self,
+        module: &wasmtime::Module,
+        state: state::CommonState<W, S>,
+        validate_fn_name: &'static str,
+    ) -> Result<(executor::Result, u64)> {
         let mut store = self.create_store(state);
         let instance = self.instantiate_module(module, &mut store)?;
 
@@ -770,6 +846,10 @@ impl<W: state::chain_state::ConstState, S> Runtime<state::CommonState<W, S>> {
         let validation_res =
             codec::decode_with_length_prefix_from_memory(&memory, &dealloc_fn, &mut store, offset)
                 .map_err(Error::Decode)?;
+        let fuel_consumed = self.config.fuel_limit
+            - store
+                .get_fuel()
+                .expect("Fuel consumption is enabled for every engine created by `create_engine`");
 
         let mut state = store.into_data();
         let executed_queries = state.take_executed_queries();
@@ -777,7 +857,7 @@ impl<W: state::chain_state::ConstState, S> Runtime<state::CommonState<W, S>> {
             state.state.state().borrow().query_handle(),
             executed_queries,
         )?;
-        Ok(validation_res)
+        Ok((validation_res, fuel_consumed))
     }
 }
 
@@ -861,6 +941,16 @@ impl<'wrld, 'state, 'block, S>
     }
 }
 
+/// Result of a smart contract execution along with the amount of fuel it consumed
+#[derive(Debug)]
+pub struct SmartContractOutcome {
+    /// Result of the execution
+    pub result: Result<()>,
+    /// Amount of fuel consumed by the execution, including
+    /// fuel consumed before the smart contract trapped
+    pub fuel_consumed: u64,
+}
+
 impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, 'block, 'state>> {
     /// Executes the given wasm smartcontract
     ///
@@ -875,6 +965,17 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         authority: AccountId,
         bytes: impl AsRef<[u8]>,
     ) -> Result<()> {
+        self.execute_with_fuel(state_transaction, authority, bytes)
+            .result
+    }
+
+    /// Same as [`Self::execute`], but also reports the amount of fuel consumed
+    pub fn execute_with_fuel(
+        &mut self,
+        state_transaction: &'wrld mut StateTransaction<'block, 'state>,
+        authority: AccountId,
+        bytes: impl AsRef<[u8]>,
+    ) -> SmartContractOutcome {
         let span = wasm_log_span!("Smart contract execution", %authority);
         let state = state::SmartContract::new(
             authority,
@@ -901,6 +1002,18 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         bytes: impl AsRef<[u8]>,
         max_instruction_count: u64,
     ) -> Result<()> {
+        self.validate_with_fuel(state_transaction, authority, bytes, max_instruction_count)
+            .result
+    }
+
+    /// Same as [`Self::validate`], but also reports the amount of fuel consumed
+    pub fn validate_with_fuel(
+        &mut self,
+        state_transaction: &'wrld mut StateTransaction<'block, 'state>,
+        authority: AccountId,
+        bytes: impl AsRef<[u8]>,
+        max_instruction_count: u64,
+    ) -> SmartContractOutcome {
         let span = wasm_log_span!("Smart contract validation", %authority);
         let state = state::SmartContract::new(
             authority,
@@ -917,20 +1030,39 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         &mut self,
         bytes: impl AsRef<[u8]>,
         state: state::SmartContract<'wrld, 'block, 'state>,
-    ) -> Result<()> {
+    ) -> SmartContractOutcome {
         let mut store = self.create_store(state);
-        let smart_contract = self.create_smart_contract(&mut store, bytes)?;
+        let result = self.call_smart_contract_main(&mut store, bytes);
+        // NOTE: Fuel is read before inspecting the result so that
+        // fuel burnt by a contract which trapped midway is accounted for
+        let fuel_consumed = self.config.fuel_limit
+            - store
+                .get_fuel()
+                .expect("Fuel consumption is enabled for every engine created by `create_engine`");
+
+        let result = result.and_then(|()| {
+            let mut state = store.into_data();
+            let executed_queries = state.take_executed_queries();
+            forget_all_executed_queries(state.state.0.query_handle, executed_queries)
+        });
+        SmartContractOutcome {
+            result,
+            fuel_consumed,
+        }
+    }
 
-        let main_fn =
-            Self::get_typed_func(&smart_contract, &mut store, import::SMART_CONTRACT_MAIN)?;
+    fn call_smart_contract_main(
+        &self,
+        store: &mut Store<state::SmartContract<'wrld, 'block, 'state>>,
+        bytes: impl AsRef<[u8]>,
+    ) -> Result<()> {
+        let smart_contract = self.create_smart_contract(store, bytes)?;
+
+        let main_fn = Self::get_typed_func(&smart_contract, store, import::SMART_CONTRACT_MAIN)?;
 
         // NOTE: This function takes ownership of the pointer
-        main_fn
-            .call(&mut store, ())
-            .map_err(ExportFnCallError::from)?;
-        let mut state = store.into_data();
-        let executed_queries = state.take_executed_queries();
-        forget_all_executed_queries(state.state.0.query_handle, executed_queries)
+        main_fn.call(store, ()).map_err(ExportFnCallError::from)?;
+        Ok(())
     }
 
     #[codec::wrap]
@@ -967,12 +1099,11 @@ impl<'wrld, 'block, 'state>
 }
 
 impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block, 'state>> {
-    /// Executes the given wasm trigger module
-    ///
-    /// # Errors
+    /// Executes the given wasm trigger module and reports the amount of fuel consumed
     ///
+    /// The execution fails:
     /// - if unable to find expected main function export
-    /// - if the execution of the smartcontract fails
+    /// - if the execution of the trigger fails
     pub fn execute_trigger_module(
         &mut self,
         state_transaction: &'wrld mut StateTransaction<'block, 'state>,
@@ -980,7 +1111,7 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block,
         authority: AccountId,
         module: &wasmtime::Module,
         event: EventBox,
-    ) -> Result<()> {
+    ) -> SmartContractOutcome {
         let span = wasm_log_span!("Trigger execution", %id, %authority);
         let state = state::Trigger::new(
             authority,
@@ -991,18 +1122,37 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block,
         );
 
         let mut store = self.create_store(state);
-        let instance = self.instantiate_module(module, &mut store)?;
+        let result = self.call_trigger_main(&mut store, module);
+        // NOTE: Fuel is read before inspecting the result so that
+        // fuel burnt by a trigger which trapped midway is accounted for
+        let fuel_consumed = self.config.fuel_limit
+            - store
+                .get_fuel()
+                .expect("Fuel consumption is enabled for every engine created by `create_engine`");
+
+        let result = result.and_then(|()| {
+            let mut state = store.into_data();
+            let executed_queries = state.take_executed_queries();
+            forget_all_executed_queries(state.state.0.query_handle, executed_queries)
+        });
+        SmartContractOutcome {
+            result,
+            fuel_consumed,
+        }
+    }
 
-        let main_fn = Self::get_typed_func(&instance, &mut store, import::TRIGGER_MAIN)?;
+    fn call_trigger_main(
+        &self,
+        store: &mut Store<state::Trigger<'wrld, 'block, 'state>>,
+        module: &wasmtime::Module,
+    ) -> Result<()> {
+        let instance = self.instantiate_module(module, store)?;
 
-        // NOTE: This function takes ownership of the pointer
-        main_fn
-            .call(&mut store, ())
-            .map_err(ExportFnCallError::from)?;
+        let main_fn = Self::get_typed_func(&instance, store, import::TRIGGER_MAIN)?;
 
-        let mut state = store.into_data();
-        let executed_queries = state.take_executed_queries();
-        forget_all_executed_queries(state.state.0.query_handle, executed_queries)
+        // NOTE: This function takes ownership of the pointer
+        main_fn.call(store, ()).map_err(ExportFnCallError::from)?;
+        Ok(())
     }
 
     #[codec::wrap]
@@ -1287,6 +1437,38 @@ impl<'wrld, S: StateReadOnly> Runtime<state::executor::ValidateQuery<'wrld, S>>
             import::EXECUTOR_VALIDATE_QUERY,
         )
     }
+
+    /// Execute `validate_query()` entrypoint of the given module of runtime executor
+    /// and return the amount of fuel consumed by it regardless of the verdict
+    ///
+    /// # Errors
+    ///
+    /// - if failed to instantiate provided `module`
+    /// - if unable to find expected function export
+    /// - if the execution of the smartcontract fails
+    /// - if unable to decode [`executor::Result`]
+    pub fn estimate_executor_validate_query_fuel(
+        &self,
+        state_ro: &'wrld S,
+        authority: &AccountId,
+        module: &wasmtime::Module,
+        query: QueryBox,
+    ) -> Result<u64> {
+        let span = wasm_log_span!("Estimating `validate_query()` fuel");
+
+        self.execute_executor_validate_internal_with_fuel(
+            module,
+            state::executor::ValidateQuery::new(
+                authority.clone(),
+                self.config,
+                span,
+                state::chain_state::WithConst(state_ro),
+                state::specific::executor::ValidateQuery::new(query),
+            ),
+            import::EXECUTOR_VALIDATE_QUERY,
+        )
+        .map(|(_validation_res, fuel_consumed)| fuel_consumed)
+    }
 }
 
 impl<'wrld, S: StateReadOnly>
@@ -1998,6 +2180,105 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn fuel_consumption_is_reported() -> Result<(), Error> {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world_with_test_account(&authority), kura, query_handle);
+
+        // Burns fuel in a loop and then runs `tail`
+        let wat = |tail: &str| {
+            format!(
+                r#"
+                (module
+                    {memory_and_alloc}
+
+                    ;; Function which starts the smartcontract execution
+                    (func (export "{main_fn_name}") (param) (local $i i32)
+                        (loop $burn
+                            (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                            (br_if $burn (i32.lt_u (local.get $i) (i32.const 100))))
+
+                        {tail}))
+                "#,
+                main_fn_name = import::SMART_CONTRACT_MAIN,
+                memory_and_alloc = memory_and_alloc(""),
+            )
+        };
+        let fuel_limit = Config::default().fuel_limit;
+
+        let mut runtime = RuntimeBuilder::<state::SmartContract>::new().build()?;
+        let completed = runtime.execute_with_fuel(
+            &mut state.block().transaction(),
+            authority.clone(),
+            wat("nop"),
+        );
+        completed.result.expect("Execution failed");
+        assert!(completed.fuel_consumed >= 100);
+        assert!(completed.fuel_consumed < 10_000);
+
+        let trapped = runtime.execute_with_fuel(
+            &mut state.block().transaction(),
+            authority,
+            wat("unreachable"),
+        );
+        assert!(matches!(
+            trapped.result,
+            Err(Error::ExportFnCall(ExportFnCallError::Other(_)))
+        ));
+        assert!(trapped.fuel_consumed >= 100);
+        assert!(trapped.fuel_consumed < fuel_limit);
+
+        Ok(())
+    }
+
+    #[test]
+    async fn memory_limit_exceeded() -> Result<(), Error> {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world_with_test_account(&authority), kura, query_handle);
+
+        let wat = format!(
+            r#"
+            (module
+                {memory_and_alloc}
+
+                ;; Function which starts the smartcontract execution
+                (func (export "{main_fn_name}") (param)
+                    ;; Grow memory by a page until the host refuses
+                    (loop $grow
+                        (drop (memory.grow (i32.const 1)))
+                        (br $grow))))
+            "#,
+            main_fn_name = import::SMART_CONTRACT_MAIN,
+            memory_and_alloc = memory_and_alloc(""),
+        );
+
+        // Room for the initial page and a couple more
+        let max_memory_bytes = 3 * 65536;
+        let mut runtime = RuntimeBuilder::<state::SmartContract>::new()
+            .with_config(Config {
+                max_memory_bytes,
+                ..Config::default()
+            })
+            .build()?;
+        let err = runtime
+            .execute(&mut state.block().transaction(), authority, wat)
+            .expect_err("Execution should fail");
+
+        let Error::ExportFnCall(call_error @ ExportFnCallError::OutOfMemory { limit }) = err else {
+            panic!("Expected out of memory error, got: {err:?}");
+        };
+        assert_eq!(limit, max_memory_bytes);
+        assert!(call_error
+            .to_string()
+            .contains(&max_memory_bytes.to_string()));
+
+        Ok(())
+    }
+
     #[test]
     async fn trigger_related_func_is_not_linked_for_smart_contract() -> Result<(), Error> {
         let (authority, _authority_keypair) = gen_account_in("wonderland");
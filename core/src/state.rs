@@ -1,25 +1,34 @@
 //! This module provides the [`State`] — an in-memory representation of the current blockchain state.
-use std::{borrow::Borrow, collections::BTreeSet, marker::PhantomData, sync::Arc, time::Duration};
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+    marker::PhantomData,
+    sync::Arc,
+    time::Duration,
+};
 
 use eyre::Result;
-use iroha_config::parameters::actual::ChainWide as Config;
+use iroha_config::parameters::actual::{ChainWide as Config, WasmRuntime};
 use iroha_crypto::HashOf;
 use iroha_data_model::{
     account::AccountId,
+    asset::AssetValueType,
     block::SignedBlock,
     events::{
-        pipeline::BlockEvent,
+        pipeline::{BlockEvent, BlockEventFilter},
         time::TimeEvent,
         trigger_completed::{TriggerCompletedEvent, TriggerCompletedOutcome},
         EventBox,
     },
     executor::ExecutorDataModel,
-    isi::error::{InstructionExecutionError as Error, MathError},
+    isi::error::{InstructionExecutionError as Error, InvalidParameterError, MathError, TypeError},
     parameter::{Parameter, ParameterValueBox},
     permission::Permissions,
     prelude::*,
     query::error::{FindError, QueryExecutionFail},
     role::RoleId,
+    ValidationFail,
 };
 use iroha_logger::prelude::*;
 use iroha_primitives::{must_use::MustUse, numeric::Numeric, small::SmallVec};
@@ -38,12 +47,13 @@ use storage::{
 };
 
 use crate::{
-    block::CommittedBlock,
+    block::{BlockValidationError, CommittedBlock, ValidBlock},
     executor::Executor,
     kura::Kura,
     query::store::LiveQueryStoreHandle,
     role::RoleIdWithOwner,
     smartcontracts::{
+        isi::world::isi::check_parameter_values,
         triggers::{
             self,
             set::{
@@ -54,6 +64,7 @@ use crate::{
         },
         wasm, Execute,
     },
+    sumeragi::network_topology::Topology,
     tx::TransactionExecutor,
     Parameters, PeersIds,
 };
@@ -160,6 +171,42 @@ pub struct WorldView<'world> {
     pub(crate) executor_data_model: CellView<'world, ExecutorDataModel>,
 }
 
+/// Copy of a single [`Domain`] together with permissions and roles granted to its accounts
+/// and assets of its asset definitions held by accounts of other domains.
+///
+/// Taken with [`WorldReadOnly::snapshot_domain`] and put back with [`WorldTransaction::restore_domain`].
+#[derive(Debug, Clone)]
+pub struct DomainSnapshot {
+    domain: Domain,
+    account_permissions: Vec<(AccountId, Permissions)>,
+    account_roles: Vec<RoleIdWithOwner>,
+    foreign_assets: Vec<Asset>,
+}
+
+/// Copy of the whole [`World`] except for triggers, e.g. to reset the state between test cases.
+///
+/// Taken with [`WorldReadOnly::snapshot_world`] and put back with [`WorldTransaction::restore_world`].
+#[derive(Debug, Clone)]
+pub struct WorldSnapshot {
+    parameters: Parameters,
+    trusted_peers_ids: PeersIds,
+    domains: Vec<Domain>,
+    roles: Vec<Role>,
+    account_permissions: Vec<(AccountId, Permissions)>,
+    account_roles: Vec<RoleIdWithOwner>,
+    executor: Executor,
+    executor_data_model: ExecutorDataModel,
+}
+
+/// Signed change of an asset balance, as returned by [`StateReadOnly::net_flow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delta {
+    /// Balance grew by the given amount.
+    Increase(Numeric),
+    /// Balance shrank by the given amount.
+    Decrease(Numeric),
+}
+
 /// Current state of the blockchain
 #[derive(Serialize)]
 pub struct State {
@@ -365,12 +412,21 @@ pub trait WorldReadOnly {
         Ok(value)
     }
 
-    /// Returns reference for domains map
+    /// Returns iterator over domains, borrowing them rather than cloning.
+    ///
+    /// The iterator walks the snapshot this view was taken from,
+    /// so changes made concurrently by a block being applied are not observed
     #[inline]
     fn domains_iter(&self) -> impl Iterator<Item = &Domain> {
         self.domains().iter().map(|(_, domain)| domain)
     }
 
+    /// Returns iterator over domains paired with the accounts which own them
+    #[inline]
+    fn domains_with_owners(&self) -> impl Iterator<Item = (&Domain, &AccountId)> {
+        self.domains_iter().map(|domain| (domain, &domain.owned_by))
+    }
+
     // Account-related methods
 
     /// Get `Account` and return reference to it.
@@ -386,6 +442,18 @@ pub trait WorldReadOnly {
         })
     }
 
+    /// Returns iterator over accounts of the domain with the given `id`,
+    /// borrowing them rather than cloning
+    ///
+    /// # Errors
+    /// Fails if there is no domain
+    fn accounts_iter(
+        &self,
+        id: &DomainId,
+    ) -> Result<impl ExactSizeIterator<Item = &Account>, FindError> {
+        self.domain(id).map(Domain::accounts)
+    }
+
     /// Get `Account` and pass it to closure.
     ///
     /// # Errors
@@ -403,6 +471,169 @@ pub trait WorldReadOnly {
         Ok(f(account))
     }
 
+    /// Returns iterator over accounts which hold no assets and own no domains,
+    /// i.e. the candidates for removal during cleanup
+    fn empty_accounts(&self) -> impl Iterator<Item = &AccountId> {
+        let domain_owners = self
+            .domains_iter()
+            .map(Domain::owned_by)
+            .collect::<BTreeSet<_>>();
+
+        self.domains_iter()
+            .flat_map(Domain::accounts)
+            .filter(move |account| {
+                account.assets.is_empty() && !domain_owners.contains(&account.id)
+            })
+            .map(|account| &account.id)
+    }
+
+    /// Take a [`DomainSnapshot`] of the domain with the given `id`,
+    /// i.e. its accounts, assets, asset definitions and grants of its accounts,
+    /// as well as assets of its asset definitions held by accounts of other domains
+    ///
+    /// # Errors
+    /// Fails if there is no domain
+    fn snapshot_domain(&self, id: &DomainId) -> Result<DomainSnapshot, FindError> {
+        let domain = self.domain(id)?;
+
+        let account_permissions = domain
+            .accounts
+            .keys()
+            .filter_map(|account_id| {
+                self.account_permissions()
+                    .get(account_id)
+                    .map(|permissions| (account_id.clone(), permissions.clone()))
+            })
+            .collect();
+        let account_roles = domain
+            .accounts
+            .keys()
+            .flat_map(|account_id| {
+                self.account_roles_iter(account_id)
+                    .map(|role_id| RoleIdWithOwner::new(account_id.clone(), role_id.clone()))
+            })
+            .collect();
+        let foreign_assets = self
+            .domains_iter()
+            .filter(|other| other.id != *id)
+            .flat_map(Domain::accounts)
+            .flat_map(|account| account.assets.values())
+            .filter(|asset| asset.id.definition_id.domain_id == *id)
+            .cloned()
+            .collect();
+
+        Ok(DomainSnapshot {
+            domain: domain.clone(),
+            account_permissions,
+            account_roles,
+            foreign_assets,
+        })
+    }
+
+    /// Take a [`WorldSnapshot`] of the whole world, i.e. everything but triggers
+    fn snapshot_world(&self) -> WorldSnapshot {
+        WorldSnapshot {
+            parameters: self.parameters().clone(),
+            trusted_peers_ids: self.trusted_peers_ids().clone(),
+            domains: self.domains_iter().cloned().collect(),
+            roles: self.roles().iter().map(|(_, role)| role.clone()).collect(),
+            account_permissions: self
+                .account_permissions()
+                .iter()
+                .map(|(account_id, permissions)| (account_id.clone(), permissions.clone()))
+                .collect(),
+            account_roles: self
+                .account_roles()
+                .iter()
+                .map(|(role, ())| role.clone())
+                .collect(),
+            executor: self.executor().clone(),
+            executor_data_model: self.executor_data_model().clone(),
+        }
+    }
+
+    /// Returns public keys which are signatories of more than one account
+    /// together with the accounts sharing them
+    fn duplicate_signatories(&self) -> Vec<(PublicKey, Vec<AccountId>)> {
+        let mut accounts_by_signatory = BTreeMap::<&PublicKey, Vec<AccountId>>::new();
+        for account in self.domains_iter().flat_map(Domain::accounts) {
+            accounts_by_signatory
+                .entry(account.signatory())
+                .or_default()
+                .push(account.id.clone());
+        }
+
+        accounts_by_signatory
+            .into_iter()
+            .filter(|(_, accounts)| accounts.len() > 1)
+            .map(|(signatory, accounts)| (signatory.clone(), accounts))
+            .collect()
+    }
+
+    /// Returns up to `n` accounts holding the largest number of distinct asset types
+    /// together with that number, most diverse first
+    fn accounts_by_asset_diversity(&self, n: usize) -> Vec<(AccountId, usize)> {
+        let mut accounts = self
+            .domains_iter()
+            .flat_map(Domain::accounts)
+            .map(|account| (account.id.clone(), account.assets.len()))
+            .collect::<Vec<_>>();
+        accounts.sort_by(|(id_a, count_a), (id_b, count_b)| {
+            count_b.cmp(count_a).then_with(|| id_a.cmp(id_b))
+        });
+        accounts.truncate(n);
+        accounts
+    }
+
+    /// Returns holders of `asset_definition_id` whose balance exceeds `threshold` together with that balance
+    ///
+    /// Only numeric assets are compared against the threshold, store assets are never returned
+    fn accounts_exceeding(
+        &self,
+        asset_definition_id: &AssetDefinitionId,
+        threshold: Numeric,
+    ) -> Vec<(AccountId, AssetValue)> {
+        self.domains_iter()
+            .flat_map(Domain::accounts)
+            .filter_map(|account| {
+                account.assets.get(&AssetId::new(
+                    asset_definition_id.clone(),
+                    account.id.clone(),
+                ))
+            })
+            .filter(|asset| matches!(asset.value, AssetValue::Numeric(value) if value > threshold))
+            .map(|asset| (asset.id.account_id.clone(), asset.value.clone()))
+            .collect()
+    }
+
+    /// Get an iterator over asset definitions owned by `account_id` in all domains
+    fn asset_definitions_owned_by<'slf>(
+        &'slf self,
+        account_id: &'slf AccountId,
+    ) -> impl Iterator<Item = &'slf AssetDefinition> {
+        self.domains_iter()
+            .flat_map(Domain::asset_definitions)
+            .filter(move |asset_definition| asset_definition.owned_by == *account_id)
+    }
+
+    /// Returns ids of triggers scheduled to fire on the block at `at_height`
+    ///
+    /// Only pipeline triggers with a block filter for exactly this height are included,
+    /// time triggers are not bound to block height
+    fn scheduled_triggers(&self, at_height: u64) -> Vec<TriggerId> {
+        self.triggers()
+            .pipeline_triggers()
+            .iter()
+            .filter(|(_, action)| {
+                matches!(
+                    &action.filter,
+                    PipelineEventFilterBox::Block(filter) if filter.height() == Some(at_height)
+                )
+            })
+            .map(|(trigger_id, _)| trigger_id.clone())
+            .collect()
+    }
+
     /// Get `Account`'s `Asset`s
     ///
     /// # Errors
@@ -453,6 +684,61 @@ pub trait WorldReadOnly {
         Ok(tokens.into_iter())
     }
 
+    /// Returns permissions, granted to accounts directly or through roles,
+    /// whose payload references the entity with the given id
+    fn grants_referencing(&self, entity_id: &IdBox) -> Vec<(AccountId, Permission)> {
+        fn references(value: &serde_json::Value, id: &str) -> bool {
+            match value {
+                serde_json::Value::String(value) => value == id,
+                serde_json::Value::Array(values) => {
+                    values.iter().any(|value| references(value, id))
+                }
+                serde_json::Value::Object(fields) => {
+                    fields.values().any(|value| references(value, id))
+                }
+                _ => false,
+            }
+        }
+
+        let id = entity_id.to_string();
+        let is_referencing = |permission: &Permission| {
+            permission
+                .payload
+                .deserialize::<serde_json::Value>()
+                .is_ok_and(|payload| references(&payload, &id))
+        };
+
+        let inherent = self
+            .account_permissions()
+            .iter()
+            .flat_map(|(account_id, permissions)| {
+                permissions
+                    .iter()
+                    .map(move |permission| (account_id, permission))
+            });
+        let from_roles = self
+            .account_roles()
+            .iter()
+            .filter_map(|(role, ())| {
+                self.roles()
+                    .get(&role.role_id)
+                    .map(|found| (&role.account_id, found))
+            })
+            .flat_map(|(account_id, role)| {
+                role.permissions
+                    .iter()
+                    .map(move |permission| (account_id, permission))
+            });
+
+        inherent
+            .chain(from_roles)
+            .filter(|(_, permission)| is_referencing(permission))
+            .map(|(account_id, permission)| (account_id.clone(), permission.clone()))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
     /// Return a set of permission tokens granted to this account not as part of any role.
     ///
     /// # Errors
@@ -646,6 +932,117 @@ impl WorldTransaction<'_, '_> {
         self.events_buffer.events_created_in_transaction = 0;
     }
 
+    /// Put back the domain from a [`DomainSnapshot`] discarding all changes made to it
+    /// (including grants of its accounts and assets of its asset definitions held elsewhere)
+    /// since the snapshot was taken. Other domains are otherwise left untouched.
+    pub fn restore_domain(&mut self, snapshot: DomainSnapshot) {
+        let DomainSnapshot {
+            domain,
+            account_permissions,
+            account_roles,
+            foreign_assets,
+        } = snapshot;
+
+        let current_foreign_assets = self
+            .domains_iter()
+            .filter(|other| other.id != domain.id)
+            .flat_map(Domain::accounts)
+            .flat_map(|account| account.assets.keys())
+            .filter(|asset_id| asset_id.definition_id.domain_id == domain.id)
+            .cloned()
+            .collect::<Vec<_>>();
+        for asset_id in current_foreign_assets {
+            if let Ok(account) = self.account_mut(&asset_id.account_id) {
+                account.remove_asset(&asset_id);
+            }
+        }
+        // Accounts removed since the snapshot was taken belong to other domains, so they aren't restored
+        for asset in foreign_assets {
+            if let Ok(account) = self.account_mut(&asset.id.account_id) {
+                account.add_asset(asset);
+            }
+        }
+
+        let current_accounts = self
+            .domains
+            .get(&domain.id)
+            .map(|domain| domain.accounts.keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+        for account_id in current_accounts {
+            let roles = self
+                .account_roles_iter(&account_id)
+                .map(|role_id| RoleIdWithOwner::new(account_id.clone(), role_id.clone()))
+                .collect::<Vec<_>>();
+            for role in roles {
+                self.account_roles.remove(role);
+            }
+            self.account_permissions.remove(account_id);
+        }
+
+        for (account_id, permissions) in account_permissions {
+            self.account_permissions.insert(account_id, permissions);
+        }
+        for role in account_roles {
+            self.account_roles.insert(role, ());
+        }
+        self.domains.insert(domain.id.clone(), domain);
+    }
+
+    /// Put back the whole world from a [`WorldSnapshot`] discarding all changes
+    /// made since the snapshot was taken. Triggers are left untouched.
+    pub fn restore_world(&mut self, snapshot: &WorldSnapshot) {
+        let domain_ids = self
+            .domains
+            .iter()
+            .map(|(domain_id, _)| domain_id.clone())
+            .collect::<Vec<_>>();
+        for domain_id in domain_ids {
+            self.domains.remove(domain_id);
+        }
+        let role_ids = self
+            .roles
+            .iter()
+            .map(|(role_id, _)| role_id.clone())
+            .collect::<Vec<_>>();
+        for role_id in role_ids {
+            self.roles.remove(role_id);
+        }
+        let account_ids = self
+            .account_permissions
+            .iter()
+            .map(|(account_id, _)| account_id.clone())
+            .collect::<Vec<_>>();
+        for account_id in account_ids {
+            self.account_permissions.remove(account_id);
+        }
+        let account_roles = self
+            .account_roles
+            .iter()
+            .map(|(role, ())| role.clone())
+            .collect::<Vec<_>>();
+        for role in account_roles {
+            self.account_roles.remove(role);
+        }
+
+        for domain in &snapshot.domains {
+            self.domains.insert(domain.id.clone(), domain.clone());
+        }
+        for role in &snapshot.roles {
+            self.roles.insert(role.id.clone(), role.clone());
+        }
+        for (account_id, permissions) in &snapshot.account_permissions {
+            self.account_permissions
+                .insert(account_id.clone(), permissions.clone());
+        }
+        for role in &snapshot.account_roles {
+            self.account_roles.insert(role.clone(), ());
+        }
+        *self.parameters.get_mut() = snapshot.parameters.clone();
+        *self.trusted_peers_ids.get_mut() = snapshot.trusted_peers_ids.clone();
+        *self.executor.get_mut() = snapshot.executor.clone();
+        *self.executor_data_model.get_mut() = snapshot.executor_data_model.clone();
+    }
+
     /// Get `Domain` with an ability to modify it.
     ///
     /// # Errors
@@ -774,6 +1171,44 @@ impl WorldTransaction<'_, '_> {
         })
     }
 
+    /// Change the value type of [`AssetDefinition`] to another numeric one.
+    ///
+    /// Balances keep their values, so the change is applied only if every one of them
+    /// is representable with the new [`NumericSpec`], otherwise nothing is changed.
+    ///
+    /// # Errors
+    /// - [`AssetDefinition`], [`Domain`] not found
+    /// - Either the current or the new value type is not numeric
+    /// - Some balance doesn't satisfy the new [`NumericSpec`]
+    pub fn migrate_asset_value_type(
+        &mut self,
+        id: &AssetDefinitionId,
+        value_type: AssetValueType,
+    ) -> Result<(), Error> {
+        let AssetValueType::Numeric(spec) = value_type else {
+            return Err(TypeError::NumericAssetValueTypeExpected(value_type).into());
+        };
+        let current_value_type = self.asset_definition(id)?.value_type;
+        if !matches!(current_value_type, AssetValueType::Numeric(_)) {
+            return Err(TypeError::NumericAssetValueTypeExpected(current_value_type).into());
+        }
+
+        for asset in self
+            .domains_iter()
+            .flat_map(Domain::accounts)
+            .flat_map(|account| account.assets.values())
+            .filter(|asset| asset.id.definition_id == *id)
+        {
+            if let AssetValue::Numeric(value) = asset.value {
+                spec.check(&value)
+                    .map_err(|_| TypeError::NumericSpecViolation(value))?;
+            }
+        }
+
+        self.asset_definition_mut(id)?.value_type = value_type;
+        Ok(())
+    }
+
     /// Increase [`Asset`] total amount by given value
     ///
     /// # Errors
@@ -860,6 +1295,17 @@ impl WorldTransaction<'_, '_> {
         self.events_buffer.push(event.into());
     }
 
+    /// Data events emitted so far by the transaction being executed
+    pub fn transaction_data_events(&self) -> impl Iterator<Item = DataEvent> + '_ {
+        self.events_buffer
+            .transaction_events()
+            .iter()
+            .filter_map(|event| match event {
+                EventBox::Data(event) => Some(event.clone()),
+                _ => None,
+            })
+    }
+
     /// The function puts events produced by iterator into `events_buffer`.
     /// Events should be produced in the order of expanding scope: from specific to general.
     /// Example: account events before domain events.
@@ -893,6 +1339,11 @@ impl TransactionEventBuffer<'_> {
         self.events_created_in_transaction += 1;
         self.events_buffer.push(event);
     }
+
+    /// Events produced during execution of the current transaction
+    fn transaction_events(&self) -> &[EventBox] {
+        &self.events_buffer[self.events_buffer.len() - self.events_created_in_transaction..]
+    }
 }
 
 impl Extend<EventBox> for TransactionEventBuffer<'_> {
@@ -941,6 +1392,42 @@ impl State {
         }
     }
 
+    /// Construct [`State`] with the given chain-wide `parameters` set on top of the default configuration,
+    /// e.g. to reproduce the limits of a running network in tests and benchmarks.
+    ///
+    /// The parameters are validated the same way as with [`SetParameter`] and
+    /// are reflected in the configuration as if they were set in a committed block.
+    ///
+    /// # Errors
+    /// Fails if a parameter has a value which [`SetParameter`] would reject
+    pub fn with_parameters(
+        parameters: Parameters,
+        world: World,
+        kura: Arc<Kura>,
+        query_handle: LiveQueryStoreHandle,
+    ) -> Result<Self, Error> {
+        let state = Self::new(world, kura, query_handle);
+
+        {
+            let mut state_block = state.block();
+            let mut state_transaction = state_block.transaction();
+            check_parameter_values(
+                &state_transaction.world,
+                &parameters.iter().collect::<Vec<_>>(),
+            )
+            .map_err(InvalidParameterError::ParameterValue)?;
+            for parameter in parameters {
+                state_transaction.world.parameters.replace(parameter);
+            }
+            state_transaction.apply();
+
+            state_block.apply_parameters();
+            state_block.commit();
+        }
+
+        Ok(state)
+    }
+
     /// Create structure to execute a block
     pub fn block(&self) -> StateBlock<'_> {
         StateBlock {
@@ -982,6 +1469,119 @@ impl State {
             new_tx_amounts: &self.new_tx_amounts,
         }
     }
+
+    /// Take a [`WorldSnapshot`] of the latest committed world
+    pub fn snapshot(&self) -> WorldSnapshot {
+        self.world.view().snapshot_world()
+    }
+
+    /// Reset the world to the `snapshot` without replaying the instructions which led to it,
+    /// e.g. to start every test case from the same base state.
+    ///
+    /// Blocks and transactions are left as they are.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn restore(&self, snapshot: &WorldSnapshot) {
+        let mut state_block = self.block();
+        let mut state_transaction = state_block.transaction();
+        state_transaction.world.restore_world(snapshot);
+        state_transaction.apply();
+
+        state_block.apply_parameters();
+        state_block.commit();
+    }
+
+    /// Validate and execute `instruction` on behalf of `authority` the same way
+    /// as it would be done during block execution, but without committing any changes.
+    ///
+    /// Returns events which the instruction would emit.
+    /// Note that this waits for the block currently being applied, if any.
+    ///
+    /// # Errors
+    /// Fails if the instruction is denied by the executor or fails to execute
+    pub fn dry_run_instruction(
+        &self,
+        authority: &AccountId,
+        instruction: impl Into<InstructionBox>,
+    ) -> Result<Vec<EventBox>, ValidationFail> {
+        // The block is never committed, so all changes are discarded on drop
+        let mut state_block = self.block();
+        state_block.try_instruction(authority, instruction.into())
+    }
+
+    /// Validate and execute `instructions` one after another on behalf of `authority`
+    /// like [`Self::dry_run_instruction`] does, without committing any changes.
+    ///
+    /// Every instruction observes the changes made by the preceding instructions,
+    /// while changes made by a rejected instruction are rolled back.
+    /// Returns the outcome of each instruction in the same order.
+    pub fn validate_instructions(
+        &self,
+        authority: &AccountId,
+        instructions: impl IntoIterator<Item = InstructionBox>,
+    ) -> Vec<Result<(), ValidationFail>> {
+        // The block is never committed, so all changes are discarded on drop
+        let mut state_block = self.block();
+        instructions
+            .into_iter()
+            .map(|instruction| {
+                state_block
+                    .try_instruction(authority, instruction)
+                    .map(|_events| ())
+            })
+            .collect()
+    }
+
+    /// Bootstrap state from a stream of blocks exported from another peer.
+    ///
+    /// Every block is validated against the state built so far (signatures,
+    /// topology and linkage to the previous block) before being applied and
+    /// stored in [`Kura`].
+    ///
+    /// # Errors
+    /// Fails on the first block which doesn't pass validation,
+    /// blocks preceding it stay applied.
+    pub fn import_blocks(
+        &self,
+        blocks: impl IntoIterator<Item = SignedBlock>,
+        chain_id: &ChainId,
+        genesis_public_key: &PublicKey,
+    ) -> Result<(), BlockValidationError> {
+        for block in blocks {
+            let mut state_block = self.block();
+            let view_change_index = block.header().view_change_index;
+            let topology = match state_block.latest_block_ref() {
+                None => Topology::new(state_block.world.trusted_peers_ids.clone()),
+                Some(latest_block) => Topology::recreate_topology(
+                    &latest_block,
+                    view_change_index,
+                    state_block.world.peers().cloned().collect(),
+                ),
+            };
+
+            let block = ValidBlock::validate(
+                block,
+                &topology,
+                chain_id,
+                genesis_public_key,
+                &mut state_block,
+            )
+            .unpack(|_| {})
+            .map_err(|(_, error)| error)?
+            .commit(&topology)
+            .unpack(|_| {})
+            .map_err(|(_, error)| error)?;
+
+            if block.as_ref().header().is_genesis() {
+                *state_block.world.trusted_peers_ids = block.as_ref().commit_topology().clone();
+            }
+
+            let _events = state_block.apply_without_execution(&block);
+            self.kura.store_block(block);
+            state_block.commit();
+        }
+
+        Ok(())
+    }
 }
 
 /// Trait to perform read-only operations on [`StateBlock`], [`StateTransaction`] and [`StateView`]
@@ -1032,6 +1632,136 @@ pub trait StateReadOnly {
         })
     }
 
+    /// Reconstruct the history of the total supply of the given asset definition from
+    /// the mints and burns of committed transactions.
+    /// Returns the supply at each block height where it changed.
+    ///
+    /// Only instructions submitted directly in transactions are taken into account,
+    /// changes made by triggers or WASM smart contracts are not reflected in the history.
+    ///
+    /// # Errors
+    /// - Supply overflows
+    /// - More is burnt than was minted, e.g. because some of it was minted by a trigger
+    fn supply_history(
+        &self,
+        definition_id: &AssetDefinitionId,
+    ) -> Result<Vec<(u64, Numeric)>, MathError> {
+        let mut history = Vec::new();
+        let mut supply = Numeric::ZERO;
+
+        for block in self.all_blocks() {
+            let supply_before = supply;
+
+            let instructions = block
+                .transactions()
+                .filter(|tx| tx.error.is_none())
+                .filter_map(|tx| match tx.as_ref().instructions() {
+                    Executable::Instructions(instructions) => Some(instructions),
+                    Executable::Wasm(_) => None,
+                })
+                .flatten();
+            for instruction in instructions {
+                match instruction {
+                    InstructionBox::Mint(MintBox::Asset(mint))
+                        if mint.destination_id.definition_id == *definition_id =>
+                    {
+                        supply = supply.checked_add(mint.object).ok_or(MathError::Overflow)?;
+                    }
+                    InstructionBox::Burn(BurnBox::Asset(burn))
+                        if burn.destination_id.definition_id == *definition_id =>
+                    {
+                        supply = supply
+                            .checked_sub(burn.object)
+                            .ok_or(MathError::NotEnoughQuantity)?;
+                    }
+                    _ => {}
+                }
+            }
+
+            if supply != supply_before {
+                history.push((block.header().height(), supply));
+            }
+        }
+
+        Ok(history)
+    }
+
+    /// Compute the net flow of numeric assets for the given account over the blocks
+    /// with heights in `from_height..=to_height`, summing mints, burns and transfers.
+    /// Asset definitions whose net flow is zero are omitted.
+    ///
+    /// Only instructions submitted directly in transactions are taken into account,
+    /// changes made by triggers or WASM smart contracts are not reflected.
+    fn net_flow(
+        &self,
+        account_id: &AccountId,
+        from_height: u64,
+        to_height: u64,
+    ) -> Vec<(AssetDefinitionId, Delta)> {
+        let mut flows: BTreeMap<AssetDefinitionId, (Numeric, Numeric)> = BTreeMap::new();
+        let mut add = |definition_id: &AssetDefinitionId, inflow: bool, amount: Numeric| {
+            let (incoming, outgoing) = flows
+                .entry(definition_id.clone())
+                .or_insert((Numeric::ZERO, Numeric::ZERO));
+            let total = if inflow { incoming } else { outgoing };
+            *total = total.checked_add(amount).unwrap_or(Numeric::MAX);
+        };
+
+        let block_count = self.block_hashes().len() as u64;
+        for height in from_height.max(1)..=to_height.min(block_count) {
+            let block = self
+                .kura()
+                .get_block_by_height(height)
+                .expect("Failed to load block.");
+            let instructions = block
+                .transactions()
+                .filter(|tx| tx.error.is_none())
+                .filter_map(|tx| match tx.as_ref().instructions() {
+                    Executable::Instructions(instructions) => Some(instructions),
+                    Executable::Wasm(_) => None,
+                })
+                .flatten();
+            for instruction in instructions {
+                match instruction {
+                    InstructionBox::Mint(MintBox::Asset(mint))
+                        if mint.destination_id.account_id == *account_id =>
+                    {
+                        add(&mint.destination_id.definition_id, true, mint.object);
+                    }
+                    InstructionBox::Burn(BurnBox::Asset(burn))
+                        if burn.destination_id.account_id == *account_id =>
+                    {
+                        add(&burn.destination_id.definition_id, false, burn.object);
+                    }
+                    InstructionBox::Transfer(TransferBox::Asset(AssetTransferBox::Numeric(
+                        transfer,
+                    ))) => {
+                        let definition_id = &transfer.source_id.definition_id;
+                        if transfer.source_id.account_id == *account_id {
+                            add(definition_id, false, transfer.object);
+                        }
+                        if transfer.destination_id == *account_id {
+                            add(definition_id, true, transfer.object);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        flows
+            .into_iter()
+            .filter_map(|(definition_id, (incoming, outgoing))| {
+                let delta = match incoming.cmp(&outgoing) {
+                    Ordering::Greater => Delta::Increase(incoming.checked_sub(outgoing)?),
+                    Ordering::Less => Delta::Decrease(outgoing.checked_sub(incoming)?),
+                    Ordering::Equal => return None,
+                };
+                Some((definition_id, delta))
+            })
+            .collect()
+    }
+
     /// Return a vector of blockchain blocks after the block with the given `hash`
     fn block_hashes_after_hash(
         &self,
@@ -1160,6 +1890,34 @@ impl<'state> StateBlock<'state> {
         self.world.commit();
     }
 
+    /// Validate and execute `instruction` on behalf of `authority` in a transaction of its own,
+    /// which is applied to the block only if the instruction succeeds.
+    ///
+    /// Returns events emitted by the instruction.
+    ///
+    /// # Errors
+    /// Fails if the instruction is denied by the executor or fails to execute
+    fn try_instruction(
+        &mut self,
+        authority: &AccountId,
+        instruction: InstructionBox,
+    ) -> Result<Vec<EventBox>, ValidationFail> {
+        let mut state_transaction = self.transaction();
+        state_transaction
+            .world
+            .executor
+            .clone() // Cloning executor is a cheap operation
+            .validate_instruction(&mut state_transaction, authority, instruction)?;
+
+        let events = state_transaction
+            .world
+            .events_buffer
+            .transaction_events()
+            .to_vec();
+        state_transaction.apply();
+        Ok(events)
+    }
+
     /// Commit `CommittedBlock` with changes in form of **Iroha Special
     /// Instructions** to `self`.
     ///
@@ -1275,13 +2033,21 @@ impl<'state> StateBlock<'state> {
         }
     }
 
-    /// Process every trigger in `matched_ids`
+    /// Process triggers in `matched_ids` until the block fuel is exhausted,
+    /// the remaining ones are deferred to the next block
+    ///
+    /// A wasm trigger is deferred unless the remaining block fuel covers its whole fuel limit,
+    /// the first one in a block is instead limited to the block fuel, so that it's never exceeded
     fn process_triggers(&mut self) -> Result<(), Vec<eyre::Report>> {
         // Cloning and clearing `self.matched_ids` so that `handle_` call won't deadlock
         let matched_ids = self.world.triggers.extract_matched_ids();
         let mut succeed = Vec::<TriggerId>::with_capacity(matched_ids.len());
         let mut errors = Vec::new();
-        for (event, id) in matched_ids {
+        let wasm_fuel_limit = self.config.wasm_runtime.fuel_limit;
+        let mut block_fuel = 0_u64;
+        let mut deferred = None;
+        let mut matched_ids = matched_ids.into_iter();
+        for (event, id) in &mut matched_ids {
             // Eliding the closure triggers a lifetime mismatch
             #[allow(clippy::redundant_closure_for_method_calls)]
             let action = self
@@ -1294,10 +2060,26 @@ impl<'state> StateBlock<'state> {
                         continue;
                     }
                 }
+                let remaining_fuel = self.config.max_block_fuel.saturating_sub(block_fuel);
+                let is_wasm = matches!(action.executable(), triggers::set::ExecutableRef::Wasm(_));
+                if remaining_fuel == 0
+                    || (is_wasm && block_fuel > 0 && remaining_fuel < wasm_fuel_limit)
+                {
+                    deferred = Some((event, id));
+                    break;
+                }
+
                 // Execute every trigger in it's own transaction
                 let event = {
                     let mut transaction = self.transaction();
-                    match transaction.process_trigger(&id, &action, event) {
+                    let (result, fuel_consumed) = transaction.process_trigger(
+                        &id,
+                        &action,
+                        event,
+                        remaining_fuel.min(wasm_fuel_limit),
+                    );
+                    block_fuel = block_fuel.saturating_add(fuel_consumed);
+                    match result {
                         Ok(()) => {
                             transaction.apply();
                             succeed.push(id.clone());
@@ -1317,6 +2099,17 @@ impl<'state> StateBlock<'state> {
             }
         }
 
+        if let Some(first) = deferred {
+            let deferred = core::iter::once(first)
+                .chain(matched_ids)
+                .collect::<Vec<_>>();
+            debug!(
+                count = deferred.len(),
+                "Block fuel is exhausted, deferring triggers to the next block"
+            );
+            self.world.triggers.defer_matched_ids(deferred);
+        }
+
         let mut transaction = self.transaction();
         transaction.world.triggers.decrease_repeats(&succeed);
         transaction.apply();
@@ -1325,7 +2118,7 @@ impl<'state> StateBlock<'state> {
     }
 
     fn apply_parameters(&mut self) {
-        use iroha_data_model::parameter::default::*;
+        use iroha_data_model::parameter::{default::*, IdentNamingPolicy};
 
         macro_rules! update_params {
             ($($param:expr => $config:expr),+ $(,)?) => {
@@ -1347,6 +2140,25 @@ impl<'state> StateBlock<'state> {
             WASM_FUEL_LIMIT => self.config.wasm_runtime.fuel_limit,
             WASM_MAX_MEMORY => self.config.wasm_runtime.max_memory_bytes,
             TRANSACTION_LIMITS => self.config.transaction_limits,
+            MAX_BLOCK_FUEL => self.config.max_block_fuel,
+        }
+
+        if let Some(max_clock_skew) = self.world.query_param(MAX_CLOCK_SKEW) {
+            self.config.max_clock_skew = Duration::from_millis(max_clock_skew);
+        }
+        if let Some(policy) = self
+            .world
+            .query_param::<IdentNamingPolicy, _>(WSV_IDENT_NAMING_POLICY)
+        {
+            // Compile the pattern only when it changes, it's checked to be valid when set
+            let is_changed = self
+                .config
+                .ident_naming_policy
+                .as_ref()
+                .map_or(true, |current| current.to_string() != policy.pattern);
+            if is_changed {
+                self.config.ident_naming_policy = policy.pattern.parse().ok();
+            }
         }
     }
 }
@@ -1370,9 +2182,9 @@ impl StateTransaction<'_, '_> {
                     .with_config(self.config.wasm_runtime)
                     .with_engine(self.engine.clone()) // Cloning engine is cheap
                     .build()?;
-                wasm_runtime
-                    .execute(self, authority, bytes)
-                    .map_err(Into::into)
+                let outcome = wasm_runtime.execute_with_fuel(self, authority, bytes);
+                debug!(fuel_consumed = outcome.fuel_consumed, "Wasm executed");
+                outcome.result.map_err(Into::into)
             }
         }
     }
@@ -1388,19 +2200,23 @@ impl StateTransaction<'_, '_> {
         })
     }
 
+    /// Execute the trigger giving it at most `fuel_limit` fuel if it's a wasm one,
+    /// returns the result of the execution along with the amount of fuel consumed
     fn process_trigger(
         &mut self,
         id: &TriggerId,
         action: &dyn LoadedActionTrait,
         event: EventBox,
-    ) -> Result<()> {
+        fuel_limit: u64,
+    ) -> (Result<()>, u64) {
         use triggers::set::ExecutableRef::*;
         let authority = action.authority();
 
         match action.executable() {
-            Instructions(instructions) => {
-                self.process_instructions(instructions.iter().cloned(), authority)
-            }
+            Instructions(instructions) => (
+                self.process_instructions(instructions.iter().cloned(), authority),
+                0,
+            ),
             Wasm(blob_hash) => {
                 let module = self
                     .world
@@ -1408,13 +2224,25 @@ impl StateTransaction<'_, '_> {
                     .get_compiled_contract(blob_hash)
                     .expect("contract is not present it's a bug")
                     .clone();
-                let mut wasm_runtime = wasm::RuntimeBuilder::<wasm::state::Trigger>::new()
-                    .with_config(self.config.wasm_runtime)
+                let wasm_runtime = wasm::RuntimeBuilder::<wasm::state::Trigger>::new()
+                    .with_config(WasmRuntime {
+                        fuel_limit,
+                        ..self.config.wasm_runtime
+                    })
                     .with_engine(self.engine.clone()) // Cloning engine is cheap
-                    .build()?;
-                wasm_runtime
-                    .execute_trigger_module(self, id, authority.clone(), &module, event)
-                    .map_err(Into::into)
+                    .build();
+                let mut wasm_runtime = match wasm_runtime {
+                    Ok(wasm_runtime) => wasm_runtime,
+                    Err(error) => return (Err(error.into()), 0),
+                };
+                let outcome = wasm_runtime.execute_trigger_module(
+                    self,
+                    id,
+                    authority.clone(),
+                    &module,
+                    event,
+                );
+                (outcome.result.map_err(Into::into), outcome.fuel_consumed)
             }
         }
     }
@@ -1750,14 +2578,20 @@ pub(crate) mod deserialize {
 
 #[cfg(test)]
 mod tests {
-    use iroha_data_model::block::BlockPayload;
-    use iroha_primitives::unique_vec::UniqueVec;
+    use iroha_data_model::{block::BlockPayload, transaction::CommittedTransaction};
+    use iroha_primitives::{unique_vec, unique_vec::UniqueVec};
     use test_samples::gen_account_in;
 
     use super::*;
     use crate::{
-        block::ValidBlock, query::store::LiveQueryStore, role::RoleIdWithOwner,
-        sumeragi::network_topology::Topology,
+        block::BlockBuilder,
+        query::store::LiveQueryStore,
+        role::RoleIdWithOwner,
+        smartcontracts::{
+            isi::Registrable as _,
+            triggers::specialized::{SpecializedAction, SpecializedTrigger},
+        },
+        tx::{AcceptTransactionFail, AcceptedTransaction},
     };
 
     /// Used to inject faulty payload for testing
@@ -1823,6 +2657,612 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn supply_history() {
+        let chain_id = ChainId::from("0");
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let rose_id: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let alice_rose_id = AssetId::new(rose_id.clone(), alice_id.clone());
+
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(World::default(), kura.clone(), query_handle);
+        let mut state_block = state.block();
+
+        let instructions: [InstructionBox; 4] = [
+            Log::new(iroha_data_model::Level::INFO, "no supply change".to_owned()).into(),
+            Mint::asset_numeric(10_u32, alice_rose_id.clone()).into(),
+            Burn::asset_numeric(3_u32, alice_rose_id.clone()).into(),
+            // more than was ever minted, i.e. minted outside of transaction instructions
+            Burn::asset_numeric(8_u32, alice_rose_id).into(),
+        ];
+        for (height, instruction) in (1..).zip(instructions) {
+            let tx = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
+                .with_instructions([instruction])
+                .sign(&alice_keypair);
+            let block = new_dummy_block_with_payload(|payload| {
+                payload.header.height = height;
+                payload.transactions.push(CommittedTransaction {
+                    value: tx,
+                    error: None,
+                });
+            });
+
+            let _events = state_block.apply_without_execution(&block);
+            kura.store_block(block);
+
+            if height == 3 {
+                assert_eq!(
+                    state_block.supply_history(&rose_id),
+                    Ok(vec![(2, Numeric::new(10, 0)), (3, Numeric::new(7, 0))])
+                );
+            }
+        }
+
+        assert_eq!(
+            state_block.supply_history(&rose_id),
+            Err(MathError::NotEnoughQuantity)
+        );
+    }
+
+    #[tokio::test]
+    async fn net_flow() {
+        let chain_id = ChainId::from("0");
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let rose_id: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let tulip_id: AssetDefinitionId = "tulip#wonderland".parse().unwrap();
+        let alice_rose_id = AssetId::new(rose_id.clone(), alice_id.clone());
+        let bob_rose_id = AssetId::new(rose_id.clone(), bob_id.clone());
+        let alice_tulip_id = AssetId::new(tulip_id.clone(), alice_id.clone());
+
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(World::default(), kura.clone(), query_handle);
+        let mut state_block = state.block();
+
+        let blocks: [Vec<InstructionBox>; 3] = [
+            vec![Mint::asset_numeric(10_u32, alice_rose_id.clone()).into()],
+            vec![
+                Transfer::asset_numeric(alice_rose_id, 4_u32, bob_id.clone()).into(),
+                Mint::asset_numeric(5_u32, alice_tulip_id.clone()).into(),
+            ],
+            vec![
+                Transfer::asset_numeric(bob_rose_id, 1_u32, alice_id.clone()).into(),
+                Burn::asset_numeric(2_u32, alice_tulip_id).into(),
+            ],
+        ];
+        for (height, instructions) in (1..).zip(blocks) {
+            let tx = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
+                .with_instructions(instructions)
+                .sign(&alice_keypair);
+            let block = new_dummy_block_with_payload(|payload| {
+                payload.header.height = height;
+                payload.transactions.push(CommittedTransaction {
+                    value: tx,
+                    error: None,
+                });
+            });
+
+            let _events = state_block.apply_without_execution(&block);
+            kura.store_block(block);
+        }
+
+        assert_eq!(
+            state_block.net_flow(&alice_id, 2, 3),
+            [
+                (rose_id.clone(), Delta::Decrease(Numeric::new(3, 0))),
+                (tulip_id, Delta::Increase(Numeric::new(3, 0))),
+            ]
+        );
+        assert_eq!(
+            state_block.net_flow(&bob_id, 2, 3),
+            [(rose_id.clone(), Delta::Increase(Numeric::new(3, 0)))]
+        );
+        assert_eq!(
+            state_block.net_flow(&alice_id, 1, 1),
+            [(rose_id, Delta::Increase(Numeric::new(10, 0)))]
+        );
+        assert!(state_block.net_flow(&alice_id, 4, 10).is_empty());
+    }
+
+    #[tokio::test]
+    async fn import_blocks() {
+        let chain_id = ChainId::from("0");
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let leader_key_pair = KeyPair::random();
+        let topology = Topology::new(unique_vec![PeerId::new(
+            "127.0.0.1:8080".parse().unwrap(),
+            leader_key_pair.public_key().clone(),
+        )]);
+        let new_state = |kura| {
+            let account = Account::new(alice_id.clone()).build(&alice_id);
+            let mut domain = Domain::new("wonderland".parse().unwrap()).build(&alice_id);
+            assert!(domain.add_account(account).is_none());
+            let world = World::with([domain], topology.ordered_peers.clone());
+            State::new(world, kura, LiveQueryStore::test().start())
+        };
+
+        let kura = Kura::blank_kura_for_testing();
+        let state = new_state(Arc::clone(&kura));
+        for asset_definition in ["rose#wonderland", "tulip#wonderland"] {
+            let mut state_block = state.block();
+            let tx = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
+                .with_instructions([Register::asset_definition(AssetDefinition::numeric(
+                    asset_definition.parse().unwrap(),
+                ))])
+                .sign(&alice_keypair);
+            let tx = AcceptedTransaction::accept(
+                tx,
+                &chain_id,
+                &state_block.transaction_executor().transaction_limits,
+            )
+            .unwrap();
+            let block = BlockBuilder::new(vec![tx], topology.clone(), Vec::new())
+                .chain(0, &mut state_block)
+                .sign(&leader_key_pair)
+                .unpack(|_| {})
+                .commit(&topology)
+                .unpack(|_| {})
+                .unwrap();
+            let _events = state_block.apply(&block).unwrap();
+            state_block.commit();
+            kura.store_block(block);
+        }
+
+        let exported = state
+            .view()
+            .all_blocks()
+            .map(|block| (*block).clone())
+            .collect::<Vec<_>>();
+        let imported = new_state(Kura::blank_kura_for_testing());
+        imported
+            .import_blocks(exported.clone(), &chain_id, alice_keypair.public_key())
+            .unwrap();
+
+        assert_eq!(imported.view().height(), 2);
+        assert_eq!(
+            imported.view().latest_block_hash(),
+            state.view().latest_block_hash()
+        );
+        assert_eq!(
+            serde_json::to_value(&imported).unwrap(),
+            serde_json::to_value(&state).unwrap()
+        );
+
+        // Import has to start from genesis
+        let error = new_state(Kura::blank_kura_for_testing())
+            .import_blocks(
+                exported.into_iter().skip(1),
+                &chain_id,
+                alice_keypair.public_key(),
+            )
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            BlockValidationError::LatestBlockHeightMismatch { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn state_with_parameters() {
+        use iroha_data_model::{
+            isi::error::InvalidParameterError,
+            parameter::default::{BLOCK_TIME, TRANSACTION_LIMITS},
+            transaction::TransactionLimits,
+            Level,
+        };
+
+        let chain_id = ChainId::from("0");
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let new_state = |parameters: &[(&str, ParameterValueBox)]| {
+            let domain = Domain::new("wonderland".parse().unwrap()).build(&alice_id);
+            let world = World::with([domain], UniqueVec::new());
+            let parameters = parameters
+                .iter()
+                .map(|(id, val)| Parameter::new(id.parse().unwrap(), val.clone()))
+                .collect();
+            State::with_parameters(
+                parameters,
+                world,
+                Kura::blank_kura_for_testing(),
+                LiveQueryStore::test().start(),
+            )
+        };
+
+        let limits = TransactionLimits::new(1, 1024);
+        let state = new_state(&[(TRANSACTION_LIMITS, limits.into())]).unwrap();
+        let state_view = state.view();
+        assert_eq!(state_view.transaction_executor().transaction_limits, limits);
+
+        let tx = |instruction_count| {
+            TransactionBuilder::new(chain_id.clone(), alice_id.clone())
+                .with_instructions(
+                    core::iter::repeat(Log::new(Level::INFO, "limits".to_owned()))
+                        .take(instruction_count),
+                )
+                .sign(&alice_keypair)
+        };
+        let limits = state_view.transaction_executor().transaction_limits;
+        assert!(AcceptedTransaction::accept(tx(1), &chain_id, &limits).is_ok());
+        assert!(matches!(
+            AcceptedTransaction::accept(tx(2), &chain_id, &limits),
+            Err(AcceptTransactionFail::TransactionLimit(_))
+        ));
+
+        let error = new_state(&[(BLOCK_TIME, 0_u64.into())])
+            .err()
+            .expect("Zero block time should be rejected");
+        assert!(matches!(
+            error,
+            Error::InvalidParameter(InvalidParameterError::ParameterValue(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn domains_with_owners() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, _bob_keypair) = gen_account_in("looking_glass");
+        let domains = [
+            Domain::new("wonderland".parse().unwrap()).build(&alice_id),
+            Domain::new("looking_glass".parse().unwrap()).build(&bob_id),
+            Domain::new("garden".parse().unwrap()).build(&alice_id),
+        ];
+        let world = World::with(domains, UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let state_view = state.view();
+
+        let owners = state_view
+            .world
+            .domains_with_owners()
+            .map(|(domain, owner)| (domain.id().name.to_string(), owner.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            owners,
+            [
+                ("garden".to_owned(), alice_id.clone()),
+                ("looking_glass".to_owned(), bob_id),
+                ("wonderland".to_owned(), alice_id),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn domains_and_accounts_iter() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let domain_ids: [DomainId; 3] = [
+            "garden".parse().unwrap(),
+            "looking_glass".parse().unwrap(),
+            "wonderland".parse().unwrap(),
+        ];
+        let mut domains = domain_ids
+            .clone()
+            .map(|domain_id| Domain::new(domain_id).build(&alice_id));
+        for account_id in [&alice_id, &bob_id] {
+            assert!(domains[2]
+                .add_account(Account::new(account_id.clone()).build(&alice_id))
+                .is_none());
+        }
+        let world = World::with(domains, UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let state_view = state.view();
+
+        // Domains added after the view was taken are not observed by it
+        {
+            let mut state_block = state.block();
+            let tea_party_id: DomainId = "tea_party".parse().unwrap();
+            let tea_party = Domain::new(tea_party_id.clone()).build(&alice_id);
+            state_block.world.domains.insert(tea_party_id, tea_party);
+            state_block.commit();
+        }
+
+        let iterated = state_view
+            .world
+            .domains_iter()
+            .map(|domain| domain.id().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(iterated, domain_ids);
+
+        let mut accounts = state_view
+            .world
+            .accounts_iter(&domain_ids[2])
+            .unwrap()
+            .map(|account| account.id().clone())
+            .collect::<Vec<_>>();
+        accounts.sort();
+        let mut expected = vec![alice_id, bob_id];
+        expected.sort();
+        assert_eq!(accounts, expected);
+        assert_eq!(
+            state_view
+                .world
+                .accounts_iter(&domain_ids[0])
+                .unwrap()
+                .len(),
+            0
+        );
+        assert!(state_view
+            .world
+            .accounts_iter(&"neverland".parse().unwrap())
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn empty_accounts() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let (carol_id, _carol_keypair) = gen_account_in("wonderland");
+        let asset_definition_id: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+
+        let mut domain = Domain::new("wonderland".parse().unwrap()).build(&alice_id);
+        let mut bob = Account::new(bob_id.clone()).build(&alice_id);
+        assert!(bob
+            .add_asset(Asset::new(
+                AssetId::new(asset_definition_id.clone(), bob_id.clone()),
+                Numeric::new(10, 0),
+            ))
+            .is_none());
+        assert!(domain
+            .add_asset_definition(AssetDefinition::numeric(asset_definition_id).build(&alice_id))
+            .is_none());
+        // alice holds no assets, but owns the domain
+        assert!(domain
+            .add_account(Account::new(alice_id.clone()).build(&alice_id))
+            .is_none());
+        assert!(domain.add_account(bob).is_none());
+        assert!(domain
+            .add_account(Account::new(carol_id.clone()).build(&alice_id))
+            .is_none());
+
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let state_view = state.view();
+
+        assert_eq!(
+            state_view.world.empty_accounts().collect::<Vec<_>>(),
+            [&carol_id]
+        );
+    }
+
+    #[tokio::test]
+    async fn migrate_asset_value_type() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let asset_definition_id: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let balances = [
+            (alice_id.clone(), Numeric::new(10, 0)),
+            (bob_id.clone(), Numeric::new(15, 1)),
+        ];
+
+        let mut domain = Domain::new("wonderland".parse().unwrap()).build(&alice_id);
+        assert!(domain
+            .add_asset_definition(
+                AssetDefinition::new(
+                    asset_definition_id.clone(),
+                    AssetValueType::Numeric(NumericSpec::fractional(2)),
+                )
+                .build(&alice_id)
+            )
+            .is_none());
+        for (account_id, balance) in &balances {
+            let mut account = Account::new(account_id.clone()).build(&alice_id);
+            assert!(account
+                .add_asset(Asset::new(
+                    AssetId::new(asset_definition_id.clone(), account_id.clone()),
+                    *balance,
+                ))
+                .is_none());
+            assert!(domain.add_account(account).is_none());
+        }
+
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let world = &mut state_transaction.world;
+
+        let wider = AssetValueType::Numeric(NumericSpec::fractional(4));
+        world
+            .migrate_asset_value_type(&asset_definition_id, wider)
+            .unwrap();
+        assert_eq!(
+            world
+                .asset_definition(&asset_definition_id)
+                .unwrap()
+                .value_type,
+            wider
+        );
+        for (account_id, balance) in &balances {
+            let asset = world
+                .asset(&AssetId::new(
+                    asset_definition_id.clone(),
+                    account_id.clone(),
+                ))
+                .unwrap();
+            assert_eq!(asset.value, AssetValue::Numeric(*balance));
+        }
+
+        // bob's `1.5` can't be represented as an integer
+        assert!(matches!(
+            world.migrate_asset_value_type(
+                &asset_definition_id,
+                AssetValueType::Numeric(NumericSpec::integer())
+            ),
+            Err(Error::Type(TypeError::NumericSpecViolation(value))) if value == Numeric::new(15, 1)
+        ));
+        assert_eq!(
+            world
+                .asset_definition(&asset_definition_id)
+                .unwrap()
+                .value_type,
+            wider
+        );
+    }
+
+    #[tokio::test]
+    async fn restore_domain_snapshot() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let (carol_id, _carol_keypair) = gen_account_in("garden");
+        let (dave_id, _dave_keypair) = gen_account_in("garden");
+        let wonderland_id: DomainId = "wonderland".parse().unwrap();
+        let garden_id: DomainId = "garden".parse().unwrap();
+        let rose_id: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let dave_rose_id = AssetId::new(rose_id.clone(), dave_id.clone());
+        let carol_rose_id = AssetId::new(rose_id, carol_id.clone());
+        let permission =
+            Permission::new("CanDoSomething".parse().unwrap(), serde_json::json!(null));
+
+        let mut wonderland = Domain::new(wonderland_id.clone()).build(&alice_id);
+        assert!(wonderland
+            .add_account(Account::new(alice_id.clone()).build(&alice_id))
+            .is_none());
+        let mut garden = Domain::new(garden_id.clone()).build(&alice_id);
+        let mut dave = Account::new(dave_id.clone()).build(&alice_id);
+        assert!(dave
+            .add_asset(Asset::new(dave_rose_id.clone(), 5_u32))
+            .is_none());
+        assert!(garden.add_account(dave).is_none());
+
+        let world = World::with([wonderland, garden], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let world = &mut state_transaction.world;
+
+        let snapshot = world.snapshot_domain(&wonderland_id).unwrap();
+
+        let bob = Account::new(bob_id.clone()).build(&alice_id);
+        assert!(world
+            .domain_mut(&wonderland_id)
+            .unwrap()
+            .add_account(bob)
+            .is_none());
+        let carol = Account::new(carol_id.clone()).build(&alice_id);
+        assert!(world
+            .domain_mut(&garden_id)
+            .unwrap()
+            .add_account(carol)
+            .is_none());
+        assert!(world.add_account_permission(&alice_id, permission.clone()));
+        assert!(world.add_account_permission(&carol_id, permission.clone()));
+        assert!(world
+            .account_mut(&dave_id)
+            .unwrap()
+            .add_asset(Asset::new(dave_rose_id.clone(), 10_u32))
+            .is_some());
+        assert!(world
+            .account_mut(&carol_id)
+            .unwrap()
+            .add_asset(Asset::new(carol_rose_id.clone(), 1_u32))
+            .is_none());
+
+        world.restore_domain(snapshot);
+
+        assert!(world.account(&alice_id).is_ok());
+        assert!(world.account(&bob_id).is_err());
+        assert!(world.account_permissions().get(&alice_id).is_none());
+        assert!(world.account(&carol_id).is_ok());
+        assert_eq!(
+            world.account_permissions().get(&carol_id),
+            Some(&BTreeSet::from([permission]))
+        );
+        assert_eq!(
+            world.asset(&dave_rose_id).unwrap().value,
+            AssetValue::Numeric(Numeric::new(5, 0))
+        );
+        assert!(world.asset(&carol_rose_id).is_err());
+    }
+
+    #[tokio::test]
+    async fn restore_world_snapshot() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let wonderland_id: DomainId = "wonderland".parse().unwrap();
+        let garden_id: DomainId = "garden".parse().unwrap();
+        let permission =
+            Permission::new("CanDoSomething".parse().unwrap(), serde_json::json!(null));
+
+        let mut wonderland = Domain::new(wonderland_id.clone()).build(&alice_id);
+        assert!(wonderland
+            .add_account(Account::new(alice_id.clone()).build(&alice_id))
+            .is_none());
+        let world = World::with([wonderland], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+
+        let snapshot = state.snapshot();
+
+        {
+            let mut state_block = state.block();
+            let mut state_transaction = state_block.transaction();
+            let world = &mut state_transaction.world;
+            let garden = Domain::new(garden_id.clone()).build(&alice_id);
+            world.domains.insert(garden_id.clone(), garden);
+            world
+                .domain_mut(&wonderland_id)
+                .unwrap()
+                .remove_account(&alice_id);
+            assert!(world.add_account_permission(&alice_id, permission));
+            state_transaction.apply();
+            state_block.commit();
+        }
+
+        state.restore(&snapshot);
+
+        let view = state.view();
+        assert!(view.world.domain(&garden_id).is_err());
+        assert!(view.world.account(&alice_id).is_ok());
+        assert!(view.world.account_permissions().get(&alice_id).is_none());
+    }
+
+    #[tokio::test]
+    async fn duplicate_signatories() {
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let alice_in_garden_id = AccountId::new(
+            "garden".parse().unwrap(),
+            alice_keypair.public_key().clone(),
+        );
+
+        let mut wonderland = Domain::new("wonderland".parse().unwrap()).build(&alice_id);
+        let mut garden = Domain::new("garden".parse().unwrap()).build(&alice_id);
+        assert!(wonderland
+            .add_account(Account::new(alice_id.clone()).build(&alice_id))
+            .is_none());
+        assert!(wonderland
+            .add_account(Account::new(bob_id).build(&alice_id))
+            .is_none());
+        assert!(garden
+            .add_account(Account::new(alice_in_garden_id.clone()).build(&alice_id))
+            .is_none());
+
+        let world = World::with([wonderland, garden], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let state_view = state.view();
+
+        assert_eq!(
+            state_view.world.duplicate_signatories(),
+            [(
+                alice_keypair.public_key().clone(),
+                vec![alice_in_garden_id, alice_id]
+            )]
+        );
+    }
+
     #[test]
     fn role_account_range() {
         let (account_id, _account_keypair) = gen_account_in("wonderland");
@@ -1844,4 +3284,242 @@ mod tests {
             assert_eq!(&role.account_id, &account_id);
         }
     }
+
+    #[tokio::test]
+    async fn scheduled_triggers() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(World::default(), kura, query_handle);
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+
+        let trigger_id: TriggerId = "scheduled".parse().unwrap();
+        let trigger = SpecializedTrigger::new(
+            trigger_id.clone(),
+            SpecializedAction::new(
+                Vec::<InstructionBox>::new(),
+                Repeats::Indefinitely,
+                alice_id,
+                PipelineEventFilterBox::Block(BlockEventFilter::new().for_height(3)),
+            ),
+        );
+        assert!(state_transaction
+            .world
+            .triggers
+            .add_pipeline_trigger(state_transaction.engine, trigger)
+            .unwrap());
+
+        let world = &state_transaction.world;
+        assert_eq!(world.scheduled_triggers(3), vec![trigger_id]);
+        assert!(world.scheduled_triggers(2).is_empty());
+        assert!(world.scheduled_triggers(4).is_empty());
+    }
+
+    #[tokio::test]
+    async fn accounts_by_asset_diversity() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let (carol_id, _carol_keypair) = gen_account_in("wonderland");
+        let asset_definition_ids: Vec<AssetDefinitionId> = ["rose", "tulip", "lily"]
+            .into_iter()
+            .map(|name| format!("{name}#wonderland").parse().unwrap())
+            .collect();
+
+        let mut domain = Domain::new("wonderland".parse().unwrap()).build(&alice_id);
+        for asset_definition_id in &asset_definition_ids {
+            assert!(domain
+                .add_asset_definition(
+                    AssetDefinition::numeric(asset_definition_id.clone()).build(&alice_id)
+                )
+                .is_none());
+        }
+        for (account_id, asset_types) in [(&alice_id, 1), (&bob_id, 3), (&carol_id, 0)] {
+            let mut account = Account::new(account_id.clone()).build(&alice_id);
+            for asset_definition_id in &asset_definition_ids[..asset_types] {
+                assert!(account
+                    .add_asset(Asset::new(
+                        AssetId::new(asset_definition_id.clone(), account_id.clone()),
+                        Numeric::new(1, 0),
+                    ))
+                    .is_none());
+            }
+            assert!(domain.add_account(account).is_none());
+        }
+
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let state_view = state.view();
+
+        assert_eq!(
+            state_view.world.accounts_by_asset_diversity(2),
+            vec![(bob_id, 3), (alice_id, 1)]
+        );
+    }
+
+    #[tokio::test]
+    async fn accounts_exceeding() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let (carol_id, _carol_keypair) = gen_account_in("wonderland");
+        let rose_id: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let tulip_id: AssetDefinitionId = "tulip#wonderland".parse().unwrap();
+
+        let mut domain = Domain::new("wonderland".parse().unwrap()).build(&alice_id);
+        for asset_definition_id in [&rose_id, &tulip_id] {
+            assert!(domain
+                .add_asset_definition(
+                    AssetDefinition::numeric(asset_definition_id.clone()).build(&alice_id)
+                )
+                .is_none());
+        }
+        for (account_id, asset_definition_id, quantity) in [
+            (&alice_id, &rose_id, 150),
+            (&bob_id, &rose_id, 50),
+            (&carol_id, &tulip_id, 500),
+        ] {
+            let mut account = Account::new(account_id.clone()).build(&alice_id);
+            assert!(account
+                .add_asset(Asset::new(
+                    AssetId::new(asset_definition_id.clone(), account_id.clone()),
+                    Numeric::new(quantity, 0),
+                ))
+                .is_none());
+            assert!(domain.add_account(account).is_none());
+        }
+
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let state_view = state.view();
+
+        assert_eq!(
+            state_view
+                .world
+                .accounts_exceeding(&rose_id, Numeric::new(100, 0)),
+            vec![(alice_id, AssetValue::Numeric(Numeric::new(150, 0)))]
+        );
+        assert!(state_view
+            .world
+            .accounts_exceeding(&rose_id, Numeric::new(150, 0))
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn asset_definitions_owned_by() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+
+        let domains = [("wonderland", "rose"), ("garden_of_live_flowers", "tulip")].map(
+            |(domain_id, asset_name)| {
+                let mut domain = Domain::new(domain_id.parse().unwrap()).build(&bob_id);
+                for (name, owner_id) in [(asset_name, &alice_id), ("lily", &bob_id)] {
+                    let asset_definition_id = format!("{name}#{domain_id}").parse().unwrap();
+                    assert!(domain
+                        .add_asset_definition(
+                            AssetDefinition::numeric(asset_definition_id).build(owner_id)
+                        )
+                        .is_none());
+                }
+                domain
+            },
+        );
+
+        let world = World::with(domains, UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let state_view = state.view();
+
+        let mut owned = state_view
+            .world
+            .asset_definitions_owned_by(&alice_id)
+            .map(|asset_definition| asset_definition.id.to_string())
+            .collect::<Vec<_>>();
+        owned.sort();
+        assert_eq!(owned, ["rose#wonderland", "tulip#garden_of_live_flowers"]);
+    }
+
+    #[tokio::test]
+    async fn grants_referencing() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let wonderland_id: DomainId = "wonderland".parse().unwrap();
+        let garden_id: DomainId = "garden".parse().unwrap();
+        let permission = Permission::new(
+            "CanUnregisterDomain".parse().unwrap(),
+            serde_json::json!({ "domain": wonderland_id }),
+        );
+
+        let mut wonderland = Domain::new(wonderland_id.clone()).build(&alice_id);
+        assert!(wonderland
+            .add_account(Account::new(alice_id.clone()).build(&alice_id))
+            .is_none());
+        let garden = Domain::new(garden_id.clone()).build(&alice_id);
+
+        let world = World::with([wonderland, garden], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let world = &mut state_transaction.world;
+        assert!(world.add_account_permission(&alice_id, permission.clone()));
+
+        assert_eq!(
+            world.grants_referencing(&IdBox::DomainId(wonderland_id)),
+            vec![(alice_id, permission)]
+        );
+        assert!(world
+            .grants_referencing(&IdBox::DomainId(garden_id))
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn validate_instructions_rolls_back_rejected_instructions() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let (mad_hatter_id, _mad_hatter_keypair) = gen_account_in("wonderland");
+        let asset_definition_id: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let alice_rose_id = AssetId::new(asset_definition_id.clone(), alice_id.clone());
+
+        let mut domain = Domain::new("wonderland".parse().unwrap()).build(&alice_id);
+        assert!(domain
+            .add_asset_definition(AssetDefinition::numeric(asset_definition_id).build(&alice_id))
+            .is_none());
+        let mut alice = Account::new(alice_id.clone()).build(&alice_id);
+        assert!(alice
+            .add_asset(Asset::new(alice_rose_id.clone(), 10_u32))
+            .is_none());
+        assert!(domain.add_account(alice).is_none());
+        assert!(domain
+            .add_account(Account::new(bob_id.clone()).build(&alice_id))
+            .is_none());
+
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+
+        let outcomes = state.validate_instructions(
+            &alice_id,
+            [
+                // Roses are debited before the missing destination account is noticed
+                Transfer::asset_numeric(alice_rose_id.clone(), 5_u32, mad_hatter_id).into(),
+                // Succeeds only if the debit of the rejected transfer is rolled back
+                Transfer::asset_numeric(alice_rose_id.clone(), 10_u32, bob_id).into(),
+            ],
+        );
+        assert!(matches!(
+            outcomes[0],
+            Err(ValidationFail::InstructionFailed(_))
+        ));
+        assert_eq!(outcomes[1], Ok(()));
+        assert_eq!(
+            state.view().world.asset(&alice_rose_id).unwrap().value,
+            AssetValue::Numeric(10_u32.into())
+        );
+    }
 }
@@ -6,6 +6,7 @@ use iroha_crypto::{PublicKey, SignatureOf};
 use iroha_data_model::{block::SignedBlock, prelude::PeerId};
 use iroha_logger::trace;
 use iroha_primitives::unique_vec::UniqueVec;
+use thiserror::Error;
 
 /// The ordering of the peers which defines their roles in the current round of consensus.
 ///
@@ -24,6 +25,15 @@ pub struct Topology {
     pub(crate) ordered_peers: UniqueVec<PeerId>,
 }
 
+/// Error creating [`Topology`] from a list of peers
+#[derive(Error, displaydoc::Display, Debug, Clone)]
+pub enum TopologyError {
+    /// Topology has to contain at least one peer
+    Empty,
+    /// Peer with public key `{0}` is listed with different addresses
+    DuplicatePeer(PublicKey),
+}
+
 /// Topology with at least one peer
 #[derive(Debug, Clone, PartialEq, Eq, derive_more::Deref)]
 pub struct NonEmptyTopology<'topology> {
@@ -44,6 +54,30 @@ impl Topology {
         }
     }
 
+    /// Create a topology from an unordered list of `peers`.
+    ///
+    /// Repeated entries of the same peer are merged and the peers are sorted by
+    /// their public keys, so every peer constructs the same topology from the same list.
+    ///
+    /// # Errors
+    /// - If `peers` is empty
+    /// - If the same public key is listed with different addresses
+    pub fn from_peers(mut peers: Vec<PeerId>) -> Result<Self, TopologyError> {
+        if peers.is_empty() {
+            return Err(TopologyError::Empty);
+        }
+
+        peers.sort();
+        if let Some(pair) = peers
+            .windows(2)
+            .find(|pair| pair[0] == pair[1] && pair[0].address != pair[1].address)
+        {
+            return Err(TopologyError::DuplicatePeer(pair[0].public_key.clone()));
+        }
+
+        Ok(Self::new(peers.into_iter().collect()))
+    }
+
     /// True, if the topology contains at least one peer and thus requires consensus
     pub fn is_non_empty(&self) -> Option<NonEmptyTopology> {
         (!self.ordered_peers.is_empty()).then_some(NonEmptyTopology { topology: self })
@@ -313,6 +347,40 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn from_peers_dedups_and_sorts() {
+        let peers = test_peers![0, 1, 2];
+        let mut listed = peers.iter().rev().cloned().collect::<Vec<_>>();
+        listed.push(peers[1].clone());
+
+        let topology = Topology::from_peers(listed).unwrap();
+
+        let mut expected = Vec::from(peers);
+        expected.sort();
+        assert_eq!(Vec::from(topology.ordered_peers), expected);
+    }
+
+    #[test]
+    fn from_peers_empty() {
+        assert!(matches!(
+            Topology::from_peers(Vec::new()),
+            Err(TopologyError::Empty)
+        ));
+    }
+
+    #[test]
+    fn from_peers_duplicate_public_key() {
+        let peers = test_peers![0, 1];
+        let mut moved = peers[1].clone();
+        moved.address = ([0, 0, 0, 0], 2).into();
+
+        let error =
+            Topology::from_peers(vec![peers[0].clone(), peers[1].clone(), moved]).unwrap_err();
+        assert!(
+            matches!(error, TopologyError::DuplicatePeer(public_key) if &public_key == peers[1].public_key())
+        );
+    }
+
     #[test]
     fn rotate_set_a() {
         let mut topology = topology();
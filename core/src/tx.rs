@@ -12,9 +12,9 @@ use eyre::Result;
 use iroha_crypto::SignatureVerificationFail;
 pub use iroha_data_model::prelude::*;
 use iroha_data_model::{
-    isi::error::Mismatch,
+    isi::error::{IndexedInstructionError, Mismatch},
     query::error::FindError,
-    transaction::{error::TransactionLimitError, TransactionLimits, TransactionPayload},
+    transaction::{TransactionLimits, TransactionPayload},
 };
 use iroha_genesis::GenesisTransaction;
 use iroha_logger::{debug, error};
@@ -34,7 +34,7 @@ pub struct AcceptedTransaction(pub(crate) SignedTransaction);
 #[derive(Debug, FromVariant, thiserror::Error, displaydoc::Display)]
 pub enum AcceptTransactionFail {
     /// Failure during limits check
-    TransactionLimit(#[source] TransactionLimitError),
+    TransactionLimit(#[source] TransactionLimitExceeded),
     /// Failure during signature verification
     SignatureVerification(#[source] SignatureVerificationFail<TransactionPayload>),
     /// The genesis account can only sign transactions in the genesis block
@@ -43,6 +43,25 @@ pub enum AcceptTransactionFail {
     ChainIdMismatch(Mismatch<ChainId>),
 }
 
+/// Transaction limit exceeded by a transaction being accepted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error, displaydoc::Display)]
+pub enum TransactionLimitExceeded {
+    /// Too many instructions in payload, max number is {max}, but got {actual}
+    InstructionNumber {
+        /// Number of instructions in the payload
+        actual: u64,
+        /// Maximum allowed number of instructions
+        max: u64,
+    },
+    /// Wasm binary too large, max size is {max}, but got {actual}
+    WasmSize {
+        /// Size of the wasm binary in bytes
+        actual: u64,
+        /// Maximum allowed size of the wasm binary in bytes
+        max: u64,
+    },
+}
+
 impl AcceptedTransaction {
     /// Accept genesis transaction. Transition from [`GenesisTransaction`] to [`AcceptedTransaction`].
     ///
@@ -79,6 +98,9 @@ impl AcceptedTransaction {
     ///
     /// # Errors
     ///
+    /// - if transaction chain id doesn't match
+    /// - if transaction is signed by the genesis account
+    /// - if signature doesn't correspond to the authority
     /// - if it does not adhere to limits
     pub fn accept(
         tx: SignedTransaction,
@@ -98,18 +120,21 @@ impl AcceptedTransaction {
             return Err(AcceptTransactionFail::UnexpectedGenesisAccountSignature);
         }
 
+        let signature = tx.signature();
+        if !tx.authority().signatory_matches(signature.public_key()) {
+            return Err(SignatureVerificationFail {
+                signature: signature.clone().into(),
+                reason: "Signature doesn't correspond to authority's public key".to_string(),
+            }
+            .into());
+        }
+
         match &tx.instructions() {
             Executable::Instructions(instructions) => {
-                let instruction_count = instructions.len();
-                if Self::len_u64(instruction_count) > limits.max_instruction_number {
-                    return Err(AcceptTransactionFail::TransactionLimit(
-                        TransactionLimitError {
-                            reason: format!(
-                                "Too many instructions in payload, max number is {}, but got {}",
-                                limits.max_instruction_number, instruction_count
-                            ),
-                        },
-                    ));
+                let actual = Self::len_u64(instructions.len());
+                let max = limits.max_instruction_number;
+                if actual > max {
+                    return Err(TransactionLimitExceeded::InstructionNumber { actual, max }.into());
                 }
             }
             // TODO: Can we check the number of instructions in wasm? Because we do this check
@@ -117,15 +142,10 @@ impl AcceptedTransaction {
             //
             // Should we allow infinite instructions in wasm? And deny only based on fuel and size
             Executable::Wasm(smart_contract) => {
-                let size_bytes = Self::len_u64(smart_contract.size_bytes());
-                let max_wasm_size_bytes = limits.max_wasm_size_bytes;
-
-                if size_bytes > max_wasm_size_bytes {
-                    return Err(AcceptTransactionFail::TransactionLimit(
-                        TransactionLimitError {
-                            reason: format!("Wasm binary too large, max size is {max_wasm_size_bytes}, but got {size_bytes}"),
-                        },
-                    ));
+                let actual = Self::len_u64(smart_contract.size_bytes());
+                let max = limits.max_wasm_size_bytes;
+                if actual > max {
+                    return Err(TransactionLimitExceeded::WasmSize { actual, max }.into());
                 }
             }
         }
@@ -238,12 +258,14 @@ impl TransactionExecutor {
         wasm::RuntimeBuilder::<wasm::state::SmartContract>::new()
             .build()
             .and_then(|mut wasm_runtime| {
-                wasm_runtime.validate(
+                let outcome = wasm_runtime.validate_with_fuel(
                     state_transaction,
                     authority,
                     wasm,
                     self.transaction_limits.max_instruction_number,
-                )
+                );
+                debug!(fuel_consumed = outcome.fuel_consumed, "Wasm validated");
+                outcome.result
             })
             .map_err(|error| WasmExecutionFail {
                 reason: format!("{:?}", eyre::Report::from(error)),
@@ -267,14 +289,91 @@ impl TransactionExecutor {
             .clone() // Cloning executor is a cheap operation
             .validate_transaction(state_transaction, &authority, tx)
             .map_err(|error| {
-                if let ValidationFail::InternalError(msg) = &error {
-                    error!(
-                        error = msg,
-                        "Internal error occurred during transaction validation, \
-                         is Runtime Executor correct?"
-                    )
+                let (index, error) = match error {
+                    ValidationFail::TransactionInstructionFailed(IndexedInstructionError {
+                        index,
+                        error,
+                    }) => (Some(index), *error),
+                    error => (None, error),
+                };
+
+                match (index, error) {
+                    (Some(index), ValidationFail::InstructionFailed(error)) => {
+                        // the changes are yet to be rolled back, so they can still be reported
+                        let rolled_back =
+                            state_transaction.world.transaction_data_events().collect();
+                        TransactionRejectionReason::InstructionFailed(FailedInstruction {
+                            index,
+                            error,
+                            rolled_back,
+                        })
+                    }
+                    (index, error) => {
+                        if let ValidationFail::InternalError(msg) = &error {
+                            error!(
+                                error = msg,
+                                "Internal error occurred during transaction validation, \
+                                 is Runtime Executor correct?"
+                            )
+                        }
+                        let error = match index {
+                            Some(index) => IndexedInstructionError {
+                                index,
+                                error: Box::new(error),
+                            }
+                            .into(),
+                            None => error,
+                        };
+                        error.into()
+                    }
                 }
-                error.into()
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iroha_crypto::KeyPair;
+    use iroha_data_model::Level;
+    use test_samples::gen_account_in;
+
+    use super::*;
+
+    fn log_instructions(count: usize) -> impl Iterator<Item = Log> {
+        core::iter::repeat(Log::new(Level::INFO, "accept".to_owned())).take(count)
+    }
+
+    #[test]
+    fn accept_rejects_signature_of_other_key() {
+        let chain_id = ChainId::from("0");
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let limits = TransactionLimits::new(1, 1024);
+
+        let tx = TransactionBuilder::new(chain_id.clone(), alice_id)
+            .with_instructions(log_instructions(1))
+            .sign(&KeyPair::random());
+
+        assert!(matches!(
+            AcceptedTransaction::accept(tx, &chain_id, &limits),
+            Err(AcceptTransactionFail::SignatureVerification(_))
+        ));
+    }
+
+    #[test]
+    fn accept_reports_exceeded_instruction_limit() {
+        let chain_id = ChainId::from("0");
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let limits = TransactionLimits::new(1, 1024);
+
+        let tx = TransactionBuilder::new(chain_id.clone(), alice_id)
+            .with_instructions(log_instructions(2))
+            .sign(&alice_keypair);
+
+        assert!(matches!(
+            AcceptedTransaction::accept(tx, &chain_id, &limits),
+            Err(AcceptTransactionFail::TransactionLimit(
+                TransactionLimitExceeded::InstructionNumber { actual: 2, max: 1 }
+            ))
+        ));
+    }
+}
@@ -7,14 +7,19 @@ use std::{collections::BTreeMap, ops::Deref, path::Path, sync::Arc, thread};
 use eyre::Result;
 use futures::{prelude::*, stream::FuturesUnordered};
 use iroha::{
-    client::{Client, QueryOutput},
+    client::{Client, QueryOutput, QueryResult, ResultSet},
     config::Config as ClientConfig,
     data_model::{isi::Instruction, peer::Peer as DataModelPeer, prelude::*, query::Query, Level},
 };
 use iroha_config::parameters::actual::{Root as Config, Sumeragi, TrustedPeers};
 pub use iroha_core::state::StateReadOnly;
 use iroha_crypto::{ExposedPrivateKey, KeyPair};
-use iroha_data_model::{query::QueryOutputBox, ChainId};
+use iroha_data_model::{
+    block::BlockHeader,
+    executor::ExecutorDataModel,
+    query::{QueryOutputBox, TransactionQueryOutput},
+    ChainId,
+};
 use iroha_genesis::{GenesisNetwork, RawGenesisBlockFile};
 use iroha_logger::{warn, InstrumentFutures};
 use iroha_primitives::{
@@ -23,6 +28,7 @@ use iroha_primitives::{
 };
 use irohad::{Iroha, ToriiStarted};
 use rand::{seq::IteratorRandom, thread_rng};
+use serde::Serialize;
 use serde_json::json;
 use tempfile::TempDir;
 use test_samples::{ALICE_ID, ALICE_KEYPAIR, PEER_KEYPAIR, SAMPLE_GENESIS_ACCOUNT_KEYPAIR};
@@ -374,6 +380,132 @@ pub fn wait_for_genesis_committed_with_max_retries(
     );
 }
 
+/// Query output which can be compared with an expected value by [`assert_query_eq`]
+pub trait ComparableQueryOutput {
+    /// Value to compare
+    type Value: Serialize;
+
+    /// Fetch the complete value of the query output
+    ///
+    /// # Errors
+    /// If fetching the rest of the output fails
+    fn into_value(self) -> QueryResult<Self::Value>;
+}
+
+impl<T: Serialize> ComparableQueryOutput for ResultSet<T>
+where
+    Self: Iterator<Item = QueryResult<T>>,
+{
+    type Value = Vec<T>;
+
+    fn into_value(self) -> QueryResult<Self::Value> {
+        self.collect()
+    }
+}
+
+macro_rules! impl_comparable_query_output {
+    ( $($ty:ty),+ $(,)? ) => { $(
+        impl ComparableQueryOutput for $ty {
+            type Value = Self;
+
+            fn into_value(self) -> QueryResult<Self::Value> {
+                Ok(self)
+            }
+        } )+
+    };
+}
+impl_comparable_query_output! {
+    Role,
+    Asset,
+    AssetDefinition,
+    Account,
+    Domain,
+    BlockHeader,
+    MetadataValueBox,
+    TransactionQueryOutput,
+    ExecutorDataModel,
+    Trigger,
+    Numeric,
+}
+
+/// Request `query` and compare its result with `expected`.
+///
+/// Values are compared by their JSON representation, so entities which are
+/// compared only by id (e.g. [`Parameter`]) are checked field by field.
+///
+/// # Errors
+/// - If the query fails
+/// - If the result doesn't match `expected`, the error lists every mismatching field
+pub fn assert_query_eq<R>(
+    client: &Client,
+    query: R,
+    expected: &<<R::Output as QueryOutput>::Target as ComparableQueryOutput>::Value,
+) -> Result<()>
+where
+    R: Query + Debug,
+    R::Output: QueryOutput,
+    <R::Output as QueryOutput>::Target: ComparableQueryOutput,
+    <R::Output as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
+{
+    let actual = client.request(query)?.into_value()?;
+
+    let mut diff = Vec::new();
+    json_diff(
+        "result",
+        &serde_json::to_value(expected)?,
+        &serde_json::to_value(&actual)?,
+        &mut diff,
+    );
+    if diff.is_empty() {
+        return Ok(());
+    }
+
+    Err(eyre::eyre!(
+        "Query result doesn't match the expected value:\n{}",
+        diff.join("\n")
+    ))
+}
+
+/// Collect paths of the fields which differ between `expected` and `actual`
+fn json_diff(
+    path: &str,
+    expected: &serde_json::Value,
+    actual: &serde_json::Value,
+    diff: &mut Vec<String>,
+) {
+    use serde_json::Value;
+
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            let keys = expected
+                .keys()
+                .chain(actual.keys().filter(|key| !expected.contains_key(*key)));
+            for key in keys {
+                json_diff(
+                    &format!("{path}.{key}"),
+                    expected.get(key).unwrap_or(&Value::Null),
+                    actual.get(key).unwrap_or(&Value::Null),
+                    diff,
+                );
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            for index in 0..expected.len().max(actual.len()) {
+                json_diff(
+                    &format!("{path}[{index}]"),
+                    expected.get(index).unwrap_or(&Value::Null),
+                    actual.get(index).unwrap_or(&Value::Null),
+                    diff,
+                );
+            }
+        }
+        (expected, actual) if expected != actual => {
+            diff.push(format!("{path}: expected `{expected}`, got `{actual}`"));
+        }
+        _ => {}
+    }
+}
+
 /// Peer structure
 pub struct Peer {
     /// The id of the peer
@@ -38,7 +38,7 @@ pub use hash::*;
 use iroha_macro::ffi_impl_opaque;
 use iroha_primitives::const_vec::ConstVec;
 use iroha_schema::{Declaration, IntoSchema, MetaMap, Metadata, NamedFieldsMeta, TypeId};
-pub use merkle::MerkleTree;
+pub use merkle::{MerkleProof, MerkleTree};
 #[cfg(not(feature = "ffi_import"))]
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize, Serializer};
@@ -219,6 +219,18 @@ impl KeyPair {
     }
 }
 
+#[cfg(any(test, feature = "test_utils"))]
+impl KeyPair {
+    /// Derive a stable key pair from `seed` using the default [`Algorithm`].
+    ///
+    /// **For tests and benchmarks only.** The same seed always yields the same key pair,
+    /// so keys derived from a guessable seed are not secret. Use [`KeyPair::random`] or
+    /// [`KeyPair::from_seed`] with a securely generated seed in production.
+    pub fn deterministic(seed: &[u8]) -> Self {
+        Self::from_seed(seed.to_vec(), Algorithm::default())
+    }
+}
+
 /// Derives full [`KeyPair`] from its [`PrivateKey`] only
 // TODO: consider whether to use or not a method `KeyPair::from_private_key` instead/in addition.
 impl From<PrivateKey> for KeyPair {
@@ -468,6 +480,58 @@ impl PublicKey {
     }
 }
 
+#[cfg(not(feature = "ffi_import"))]
+impl PublicKey {
+    /// Aggregate BLS public keys into a single key which verifies
+    /// signatures aggregated with [`Signature::aggregate`].
+    ///
+    /// Keys are weighted by coefficients derived from all of the aggregated keys,
+    /// so the result depends on the whole set of keys but not on their order.
+    ///
+    /// # Errors
+    ///
+    /// - If no public keys were given
+    /// - If public keys are of different algorithms or the algorithm doesn't support aggregation
+    pub fn aggregate<'key>(
+        public_keys: impl IntoIterator<Item = &'key PublicKey>,
+    ) -> Result<Self, Error> {
+        let public_keys = public_keys.into_iter().collect::<Vec<_>>();
+        let algorithm = public_keys
+            .first()
+            .ok_or_else(|| Error::Other("No public keys to aggregate".to_owned()))?
+            .algorithm();
+
+        macro_rules! aggregate {
+            ($alg:ident, $alg_impl:path) => {{
+                let keys = public_keys
+                    .iter()
+                    .map(|public_key| match public_key.0.as_ref() {
+                        PublicKeyInner::$alg(public_key) => Ok(public_key),
+                        _ => Err(Error::Other(
+                            "Public keys of different algorithms can't be aggregated".to_owned(),
+                        )),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let aggregated = <$alg_impl>::aggregate_public_keys(&keys)
+                    .expect("There is at least one public key");
+                PublicKeyInner::$alg(aggregated)
+            }};
+        }
+
+        let inner = match algorithm {
+            Algorithm::BlsNormal => aggregate!(BlsNormal, bls::BlsNormal),
+            Algorithm::BlsSmall => aggregate!(BlsSmall, bls::BlsSmall),
+            Algorithm::Ed25519 | Algorithm::Secp256k1 => {
+                return Err(Error::Other(format!(
+                    "{algorithm} public keys can't be aggregated"
+                )))
+            }
+        };
+
+        Ok(Self(Box::new(inner)))
+    }
+}
+
 #[cfg(not(feature = "ffi_import"))]
 impl core::hash::Hash for PublicKey {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
@@ -951,6 +1015,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deterministic_key_pair_depends_only_on_seed() {
+        assert_eq!(
+            KeyPair::deterministic(b"seed"),
+            KeyPair::deterministic(b"seed")
+        );
+        assert_ne!(
+            KeyPair::deterministic(b"seed"),
+            KeyPair::deterministic(b"other seed")
+        );
+    }
+
     #[test]
     #[cfg(feature = "rand")]
     fn key_pair_serialize_deserialize_consistent() {
@@ -15,6 +15,15 @@ use crate::{Hash, HashOf};
 #[repr(transparent)]
 pub struct MerkleTree<T>(Vec<Option<HashOf<T>>>);
 
+/// Proof that a leaf is contained in a [`MerkleTree`] with a particular root hash
+#[derive(Debug, Decode, Encode, Deserialize, Serialize)]
+pub struct MerkleProof<T> {
+    /// Index of the proven leaf
+    leaf_idx: u32,
+    /// Hashes of the sibling nodes on the path from the leaf up to the root
+    audit_path: Vec<Option<HashOf<T>>>,
+}
+
 /// Iterator over leaves of [`MerkleTree`]
 pub struct LeafHashIterator<T> {
     tree: MerkleTree<T>,
@@ -159,6 +168,29 @@ impl<T> MerkleTree<T> {
         None
     }
 
+    /// Get the proof that the `idx`-th leaf is contained in the tree.
+    pub fn get_proof(&self, idx: usize) -> Option<MerkleProof<T>> {
+        let leaf_idx = u32::try_from(idx).ok()?;
+        let mut node_idx = (2_usize.pow(self.height()) - 1).checked_add(idx)?;
+        self.get(node_idx)?.as_ref()?;
+
+        let mut audit_path = Vec::new();
+        while let Some(parent_idx) = self.parent(node_idx) {
+            let sibling_idx = if node_idx % 2 == 1 {
+                node_idx + 1
+            } else {
+                node_idx - 1
+            };
+            audit_path.push(self.get(sibling_idx).copied().flatten());
+            node_idx = parent_idx;
+        }
+
+        Some(MerkleProof {
+            leaf_idx,
+            audit_path,
+        })
+    }
+
     /// Add `hash` to the tail of the tree.
     pub fn add(&mut self, hash: HashOf<T>) {
         // If the tree is perfect, increment its height to double the leaf capacity.
@@ -225,6 +257,60 @@ impl<T> MerkleTree<T> {
     }
 }
 
+impl<T> Clone for MerkleProof<T> {
+    fn clone(&self) -> Self {
+        Self {
+            leaf_idx: self.leaf_idx,
+            audit_path: self.audit_path.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for MerkleProof<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.leaf_idx == other.leaf_idx && self.audit_path == other.audit_path
+    }
+}
+
+impl<T> Eq for MerkleProof<T> {}
+
+impl<T> MerkleProof<T> {
+    /// Index of the proven leaf
+    pub fn leaf_idx(&self) -> u32 {
+        self.leaf_idx
+    }
+
+    /// Check that `leaf` is contained in the tree with the given `root` hash at [`Self::leaf_idx`].
+    pub fn verify(&self, leaf: HashOf<T>, root: HashOf<MerkleTree<T>>) -> bool {
+        let Some(n_leaves) = u32::try_from(self.audit_path.len())
+            .ok()
+            .and_then(|height| 1_usize.checked_shl(height))
+        else {
+            return false;
+        };
+        let leaf_idx = self.leaf_idx as usize;
+        if leaf_idx >= n_leaves {
+            return false;
+        }
+
+        let mut node_idx = n_leaves - 1 + leaf_idx;
+        let mut node = Some(leaf);
+        for sibling in &self.audit_path {
+            node = if node_idx % 2 == 1 {
+                MerkleTree::nodes_pair_hash(node.as_ref(), sibling.as_ref())
+            } else if sibling.is_some() {
+                MerkleTree::nodes_pair_hash(sibling.as_ref(), node.as_ref())
+            } else {
+                // left siblings are always present in a complete binary tree
+                return false;
+            };
+            node_idx = (node_idx - 1) / 2;
+        }
+
+        node.map(HashOf::transmute) == Some(root)
+    }
+}
+
 impl<T> Iterator for LeafHashIterator<T> {
     type Item = HashOf<T>;
 
@@ -295,6 +381,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn proof() {
+        const N_LEAVES: u8 = 5;
+
+        let hashes = test_hashes(N_LEAVES);
+        let tree = hashes.clone().into_iter().collect::<MerkleTree<_>>();
+        let root = tree.hash().unwrap();
+
+        for (idx, leaf_hash) in hashes.iter().enumerate() {
+            let proof = tree.get_proof(idx).unwrap();
+            assert!(proof.verify(*leaf_hash, root));
+        }
+        assert!(tree.get_proof(N_LEAVES as usize).is_none());
+
+        let single = test_hashes(1).into_iter().collect::<MerkleTree<_>>();
+        let proof = single.get_proof(0).unwrap();
+        assert!(proof.verify(test_hashes(1)[0], single.hash().unwrap()));
+    }
+
+    #[test]
+    fn tampered_proof() {
+        let hashes = test_hashes(5);
+        let tree = hashes.clone().into_iter().collect::<MerkleTree<_>>();
+        let root = tree.hash().unwrap();
+        let proof = tree.get_proof(2).unwrap();
+
+        // wrong leaf
+        assert!(!proof.verify(hashes[3], root));
+
+        // wrong sibling
+        let mut tampered = proof.clone();
+        tampered.audit_path[0] = Some(hashes[0]);
+        assert!(!tampered.verify(hashes[2], root));
+
+        // missing sibling
+        let mut truncated = proof;
+        truncated.audit_path.pop();
+        assert!(!truncated.verify(hashes[2], root));
+    }
+
     #[test]
     fn reproduction() {
         const N_LEAVES: u8 = 5;
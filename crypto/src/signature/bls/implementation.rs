@@ -4,13 +4,14 @@ use core::marker::PhantomData;
 
 #[cfg(feature = "rand")]
 use rand_chacha::rand_core::OsRng;
-use sha2::Sha256;
+use sha2::{Digest as _, Sha256};
 // TODO: Better to use `SecretKey`, not `SecretKeyVT`, but it requires to implement
 // interior mutability
-use w3f_bls::{EngineBLS as _, PublicKey, SecretKeyVT as SecretKey, SerializableToBytes as _};
+use w3f_bls::{EngineBLS, PublicKey, SecretKeyVT as SecretKey, SerializableToBytes as _};
 use zeroize::Zeroize as _;
 
 pub(super) const MESSAGE_CONTEXT: &[u8; 20] = b"for signing messages";
+const AGGREGATION_CONTEXT: &[u8; 26] = b"for aggregating signatures";
 
 use crate::{Algorithm, Error, KeyGenOption, ParseError};
 
@@ -70,6 +71,80 @@ impl<C: BlsConfiguration + ?Sized> BlsImpl<C> {
         Ok(())
     }
 
+    /// Aggregate signatures of the same message into a single one.
+    ///
+    /// `public_keys` are the raw public keys of the signers given in the same order
+    /// as `signatures`, see [`Self::aggregate_public_keys`] for why they are needed.
+    ///
+    /// Returns `None` if there are no signatures to aggregate.
+    ///
+    /// # Errors
+    ///
+    /// - If the number of `signatures` and `public_keys` differs
+    /// - If any of the signatures can't be parsed
+    pub fn aggregate_signatures(
+        signatures: &[&[u8]],
+        public_keys: &[Vec<u8>],
+    ) -> Result<Option<Vec<u8>>, Error> {
+        if signatures.len() != public_keys.len() {
+            return Err(Error::Other(format!(
+                "Got {} signatures to aggregate, but {} public keys",
+                signatures.len(),
+                public_keys.len()
+            )));
+        }
+
+        let mut aggregated: Option<w3f_bls::Signature<C::Engine>> = None;
+
+        for (signature, coefficient) in signatures
+            .iter()
+            .zip(aggregation_coefficients::<C::Engine>(public_keys))
+        {
+            let mut signature = w3f_bls::Signature::<C::Engine>::from_bytes(signature)
+                .map_err(|_| ParseError("Failed to parse signature.".to_owned()))?;
+            signature.0 *= coefficient;
+
+            match &mut aggregated {
+                Some(aggregated) => aggregated.0 += &signature.0,
+                None => aggregated = Some(signature),
+            }
+        }
+
+        Ok(aggregated.map(|signature| signature.to_bytes()))
+    }
+
+    /// Aggregate public keys into a single one which verifies the aggregated signature.
+    ///
+    /// Every public key is multiplied by a coefficient derived from all of the aggregated keys.
+    /// Without it a signer could pick its public key to cancel out the keys of the others
+    /// and sign on behalf of all of them (rogue key attack).
+    /// See <https://eprint.iacr.org/2018/483> for details.
+    ///
+    /// Returns `None` if there are no public keys to aggregate.
+    pub fn aggregate_public_keys(
+        public_keys: &[&PublicKey<C::Engine>],
+    ) -> Option<PublicKey<C::Engine>> {
+        let coefficients = aggregation_coefficients::<C::Engine>(
+            &public_keys
+                .iter()
+                .map(|public_key| public_key.to_bytes())
+                .collect::<Vec<_>>(),
+        );
+
+        public_keys.iter().zip(coefficients).fold(
+            None,
+            |aggregated: Option<PublicKey<C::Engine>>, (public_key, coefficient)| {
+                let mut public_key = **public_key;
+                public_key.0 *= coefficient;
+
+                Some(aggregated.map_or(public_key, |mut aggregated| {
+                    aggregated.0 += &public_key.0;
+                    aggregated
+                }))
+            },
+        )
+    }
+
     pub fn parse_public_key(payload: &[u8]) -> Result<PublicKey<C::Engine>, ParseError> {
         PublicKey::from_bytes(payload).map_err(|err| ParseError(err.to_string()))
     }
@@ -78,3 +153,28 @@ impl<C: BlsConfiguration + ?Sized> BlsImpl<C> {
         SecretKey::from_bytes(payload).map_err(|err| ParseError(err.to_string()))
     }
 }
+
+/// Coefficients of the aggregated signers, in the same order as their raw `public_keys`.
+///
+/// Every coefficient depends on the whole set of public keys regardless of their order.
+fn aggregation_coefficients<E: EngineBLS>(public_keys: &[Vec<u8>]) -> Vec<E::Scalar> {
+    let mut sorted_public_keys = public_keys.iter().collect::<Vec<_>>();
+    sorted_public_keys.sort();
+
+    public_keys
+        .iter()
+        .map(|public_key| {
+            let mut hasher = Sha256::new();
+            hasher.update(AGGREGATION_CONTEXT);
+            hasher.update(public_key);
+            for public_key in &sorted_public_keys {
+                hasher.update(public_key);
+            }
+            let hash = hasher.finalize();
+
+            let coefficient = hash[..16].try_into().expect("Hash is 32 bytes long");
+            // Zero coefficient would drop the signer from the aggregate
+            E::Scalar::from(u128::from_le_bytes(coefficient) | 1)
+        })
+        .collect()
+}
@@ -48,6 +48,14 @@ fn test_signature_verification_different_keys<C: BlsConfiguration>() {
         .expect_err("Signature verification for wrong public key should fail");
 }
 
+fn test_aggregate_signatures_without_public_keys<C: BlsConfiguration>() {
+    let (pk, sk) = BlsImpl::<C>::keypair(KeyGenOption::Random);
+
+    let signature = BlsImpl::<C>::sign(MESSAGE_1, &sk);
+    BlsImpl::<C>::aggregate_signatures(&[&signature[..], &signature[..]], &[pk.to_bytes()])
+        .expect_err("Aggregation with a missing public key should fail");
+}
+
 mod normal {
     use super::*;
 
@@ -70,6 +78,11 @@ mod normal {
     fn signature_verification_different_keys() {
         test_signature_verification_different_keys::<NormalConfiguration>();
     }
+
+    #[test]
+    fn aggregate_signatures_without_public_keys() {
+        test_aggregate_signatures_without_public_keys::<NormalConfiguration>();
+    }
 }
 
 mod small {
@@ -94,4 +107,9 @@ mod small {
     fn signature_verification_different_keys() {
         test_signature_verification_different_keys::<SmallConfiguration>();
     }
+
+    #[test]
+    fn aggregate_signatures_without_public_keys() {
+        test_aggregate_signatures_without_public_keys::<SmallConfiguration>();
+    }
 }
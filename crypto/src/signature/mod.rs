@@ -128,6 +128,76 @@ impl Signature {
     }
 }
 
+/// Producer of [`Signature`]s which doesn't have to expose its private key,
+/// e.g. a handle to a key stored in a hardware security module.
+pub trait Signer {
+    /// Public key verifying the signatures produced by this signer
+    fn public_key(&self) -> &PublicKey;
+
+    /// Sign `payload` with the private key corresponding to [`Self::public_key`]
+    ///
+    /// # Errors
+    /// Fails if the signer can't produce a signature, e.g. when the external key storage is unreachable
+    fn sign(&self, payload: &[u8]) -> Result<Signature, Error>;
+}
+
+impl Signer for KeyPair {
+    fn public_key(&self) -> &PublicKey {
+        self.public_key()
+    }
+
+    fn sign(&self, payload: &[u8]) -> Result<Signature, Error> {
+        Ok(Signature::new(self, payload))
+    }
+}
+
+#[cfg(not(feature = "ffi_import"))]
+impl Signature {
+    /// Aggregate BLS signatures of the same payload into a single signature.
+    ///
+    /// Public key of the aggregated signature is the aggregation of signers' public keys
+    /// (see [`PublicKey::aggregate`]), so it's verified with [`Signature::verify`] as usual.
+    /// Signatures are weighted by coefficients derived from the signers' public keys,
+    /// so that a signer can't forge the aggregate by choosing its public key (rogue key attack).
+    ///
+    /// # Errors
+    ///
+    /// - If no signatures were given
+    /// - If signatures are of different algorithms or the algorithm doesn't support aggregation
+    pub fn aggregate<'sig>(
+        signatures: impl IntoIterator<Item = &'sig Signature>,
+    ) -> Result<Self, Error> {
+        let signatures = signatures.into_iter().collect::<Vec<_>>();
+        let public_key =
+            PublicKey::aggregate(signatures.iter().map(|signature| &signature.public_key))?;
+        let payloads = signatures
+            .iter()
+            .map(|signature| signature.payload())
+            .collect::<Vec<_>>();
+        let public_keys = signatures
+            .iter()
+            .map(|signature| signature.public_key.to_bytes().1)
+            .collect::<Vec<_>>();
+
+        let payload = match public_key.algorithm() {
+            crate::Algorithm::BlsNormal => {
+                bls::BlsNormal::aggregate_signatures(&payloads, &public_keys)?
+            }
+            crate::Algorithm::BlsSmall => {
+                bls::BlsSmall::aggregate_signatures(&payloads, &public_keys)?
+            }
+            algorithm @ (crate::Algorithm::Ed25519 | crate::Algorithm::Secp256k1) => {
+                return Err(Error::Other(format!(
+                    "{algorithm} signatures can't be aggregated"
+                )))
+            }
+        }
+        .expect("There is at least one signature");
+
+        Ok(Self::from_bytes(public_key, &payload))
+    }
+}
+
 // TODO: Enable in ffi_import
 #[cfg(not(feature = "ffi_import"))]
 impl From<Signature> for (PublicKey, Vec<u8>) {
@@ -260,6 +330,17 @@ impl<T: parity_scale_codec::Encode> SignatureOf<T> {
         Self::from_hash(key_pair, HashOf::new(value))
     }
 
+    /// Create [`SignatureOf`] by signing the hash of the given value with `signer`.
+    ///
+    /// # Errors
+    /// Fails if `signer` fails to sign the hash
+    #[inline]
+    pub fn with_signer(signer: &dyn Signer, value: &T) -> Result<Self, Error> {
+        signer
+            .sign(HashOf::new(value).as_ref())
+            .map(|signature| Self(signature, PhantomData))
+    }
+
     /// Verifies signature for this item
     ///
     /// # Errors
@@ -500,6 +581,15 @@ impl<T> SignaturesOf<T> {
     pub fn is_subset(&self, other: &Self) -> bool {
         self.signatures.is_subset(&other.signatures)
     }
+
+    /// Aggregate all signatures of the set into a single BLS signature.
+    ///
+    /// # Errors
+    /// Fails if signatures can't be aggregated, see [`Signature::aggregate`]
+    pub fn aggregate(&self) -> Result<SignatureOf<T>, Error> {
+        Signature::aggregate(self.iter().map(|signature| &signature.0))
+            .map(|signature| SignatureOf(signature, PhantomData))
+    }
 }
 
 #[cfg(not(feature = "ffi_import"))]
@@ -606,6 +696,51 @@ mod tests {
         signature.verify(message).unwrap();
     }
 
+    #[test]
+    #[cfg(all(feature = "rand", not(feature = "ffi_import")))]
+    fn aggregate_signatures_bls_normal() {
+        let key_pairs =
+            core::iter::repeat_with(|| KeyPair::random_with_algorithm(Algorithm::BlsNormal))
+                .take(3)
+                .collect::<Vec<_>>();
+        let message = b"Test message to sign.";
+        let signatures = key_pairs
+            .iter()
+            .map(|key_pair| Signature::new(key_pair, message))
+            .collect::<Vec<_>>();
+
+        let aggregated = Signature::aggregate(&signatures).unwrap();
+        let aggregated_public_key =
+            PublicKey::aggregate(key_pairs.iter().map(KeyPair::public_key)).unwrap();
+        assert_eq!(*aggregated.public_key(), aggregated_public_key);
+        assert_eq!(
+            PublicKey::aggregate(key_pairs.iter().rev().map(KeyPair::public_key)).unwrap(),
+            aggregated_public_key
+        );
+        aggregated.verify(message).unwrap();
+        aggregated
+            .verify(b"Another message.")
+            .expect_err("Aggregated signature of a different message should fail");
+
+        let partially_aggregated = Signature::aggregate(&signatures[..2]).unwrap();
+        assert_ne!(*partially_aggregated.public_key(), aggregated_public_key);
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", not(feature = "ffi_import")))]
+    fn aggregate_signatures_unsupported_algorithm() {
+        let message = b"Test message to sign.";
+        let ed25519 = Signature::new(&KeyPair::random_with_algorithm(Algorithm::Ed25519), message);
+        let bls = Signature::new(
+            &KeyPair::random_with_algorithm(Algorithm::BlsNormal),
+            message,
+        );
+
+        assert!(Signature::aggregate([&ed25519]).is_err());
+        assert!(Signature::aggregate([&bls, &ed25519]).is_err());
+        assert!(Signature::aggregate([]).is_err());
+    }
+
     #[test]
     #[cfg(all(feature = "rand", not(feature = "ffi_import")))]
     fn signatures_of_deduplication_by_public_key() {
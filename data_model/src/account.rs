@@ -113,6 +113,38 @@ mod model {
 }
 
 impl AccountId {
+    /// Parse [`AccountId`] from its `signatory` and `domain` parts given separately,
+    /// i.e. without formatting them into `signatory@domain` first.
+    ///
+    /// # Errors
+    ///
+    /// If either part is empty, contains the `@` separator or fails to parse
+    pub fn from_parts(signatory: &str, domain: &str) -> Result<Self, ParseError> {
+        if signatory.is_empty() {
+            return Err(ParseError {
+                reason: "Empty `signatory` part in `signatory@domain`",
+            });
+        }
+        if domain.is_empty() {
+            return Err(ParseError {
+                reason: "Empty `domain` part in `signatory@domain`",
+            });
+        }
+        if signatory.contains('@') || domain.contains('@') {
+            return Err(ParseError {
+                reason: "`signatory` and `domain` parts should not contain the `@` separator",
+            });
+        }
+
+        let signatory = signatory.parse().map_err(|_| ParseError {
+            reason: r#"Failed to parse `signatory` part in `signatory@domain`. `signatory` should have multihash format e.g. "ed0120...""#,
+        })?;
+        let domain_id = domain.parse().map_err(|_| ParseError {
+            reason: "Failed to parse `domain` part in `signatory@domain`",
+        })?;
+        Ok(Self::new(domain_id, signatory))
+    }
+
     /// Return `true` if the account signatory matches the given `public_key`.
     #[inline]
     #[cfg(feature = "transparent_api")]
@@ -216,20 +248,8 @@ impl FromStr for AccountId {
             None => Err(ParseError {
                 reason: "Account ID should have format `signatory@domain`",
             }),
-            Some(("", _)) => Err(ParseError {
-                reason: "Empty `signatory` part in `signatory@domain`",
-            }),
-            Some((_, "")) => Err(ParseError {
-                reason: "Empty `domain` part in `signatory@domain`",
-            }),
             Some((signatory_candidate, domain_id_candidate)) => {
-                let signatory = signatory_candidate.parse().map_err(|_| ParseError {
-                    reason: r#"Failed to parse `signatory` part in `signatory@domain`. `signatory` should have multihash format e.g. "ed0120...""#,
-                })?;
-                let domain_id = domain_id_candidate.parse().map_err(|_| ParseError {
-                    reason: "Failed to parse `domain` part in `signatory@domain`",
-                })?;
-                Ok(Self::new(domain_id, signatory))
+                Self::from_parts(signatory_candidate, domain_id_candidate)
             }
         }
     }
@@ -262,4 +282,22 @@ mod tests {
             .parse::<AccountId>()
             .expect_err("signatory#domain should not be valid");
     }
+
+    #[cfg(feature = "transparent_api")]
+    #[test]
+    fn account_id_from_parts() {
+        const SIGNATORY: &str =
+            "ed0120EDF6D7B52C7032D03AEC696F2068BD53101528F3C7B6081BFF05A1662D7FC245";
+        let id = AccountId::from_parts(SIGNATORY, "domain").expect("should be valid");
+        assert_eq!(id, format!("{SIGNATORY}@domain").parse().unwrap());
+
+        let _err_empty_signatory =
+            AccountId::from_parts("", "domain").expect_err("empty signatory should not be valid");
+        let _err_empty_domain =
+            AccountId::from_parts(SIGNATORY, "").expect_err("empty domain should not be valid");
+        let _err_separator_in_signatory = AccountId::from_parts(&format!("{SIGNATORY}@"), "domain")
+            .expect_err("signatory with `@` should not be valid");
+        let _err_separator_in_domain = AccountId::from_parts(SIGNATORY, "wonder@land")
+            .expect_err("domain with `@` should not be valid");
+    }
 }
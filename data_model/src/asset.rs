@@ -157,6 +157,10 @@ mod model {
         /// Asset's Quantity.
         #[getset(get = "pub")]
         pub value: AssetValue,
+        /// Parts of the numeric value locked by each account.
+        /// They can't be transferred or burned until unlocked by the account that locked them.
+        #[getset(get = "pub")]
+        pub locks: btree_map::BTreeMap<AccountId, Numeric>,
     }
 
     /// Builder which can be submitted in a transaction to create a new [`AssetDefinition`]
@@ -307,8 +311,23 @@ impl Asset {
         Self {
             id,
             value: value.into(),
+            locks: btree_map::BTreeMap::new(),
         }
     }
+
+    /// Total amount locked by all accounts
+    pub fn locked(&self) -> Numeric {
+        self.locks.values().fold(Numeric::ZERO, |total, amount| {
+            total
+                .checked_add(*amount)
+                .expect("Locked amounts can't exceed the asset quantity")
+        })
+    }
+
+    /// Whether any part of the asset is locked
+    pub fn is_locked(&self) -> bool {
+        !self.locked().is_zero()
+    }
 }
 
 impl NewAssetDefinition {
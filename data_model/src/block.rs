@@ -9,7 +9,7 @@ use alloc::{boxed::Box, format, string::String, vec::Vec};
 use core::{fmt::Display, time::Duration};
 
 use derive_more::Display;
-use iroha_crypto::{HashOf, MerkleTree, SignaturesOf};
+use iroha_crypto::{HashOf, MerkleProof, MerkleTree, SignaturesOf};
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_primitives::unique_vec::UniqueVec;
@@ -110,6 +110,11 @@ mod model {
         pub(super) signatures: SignaturesOf<BlockPayload>,
         /// Block payload
         pub(super) payload: BlockPayload,
+        /// Peers whose signatures were aggregated into the only signature of this block.
+        ///
+        /// Bit `i` is set if the `i`-th peer of the topology the block was committed with is a signer.
+        /// Empty if signatures of this block aren't aggregated.
+        pub(super) aggregated_signers: Vec<u8>,
     }
 }
 
@@ -141,6 +146,7 @@ impl SignedBlockV1 {
         SignedBlockV1 {
             signatures,
             payload,
+            aggregated_signers: Vec::new(),
         }
     }
 
@@ -187,12 +193,46 @@ impl SignedBlock {
         &block.signatures
     }
 
+    /// Peers whose signatures were aggregated into the only signature of this block.
+    ///
+    /// Bit `i` is set if the `i`-th peer of the topology the block was committed with is a signer.
+    /// Empty if signatures of this block aren't aggregated.
+    #[inline]
+    pub fn aggregated_signers(&self) -> &[u8] {
+        let SignedBlock::V1(block) = self;
+        &block.aggregated_signers
+    }
+
     /// Calculate block hash
     #[inline]
     pub fn hash(&self) -> HashOf<Self> {
         iroha_crypto::HashOf::new(self)
     }
 
+    /// Proof that the transaction with the given hash is contained in this block.
+    /// Returns `None` if the block doesn't contain such transaction
+    pub fn inclusion_proof(
+        &self,
+        transaction_hash: &HashOf<SignedTransaction>,
+    ) -> Option<InclusionProof> {
+        let transactions = self
+            .transactions()
+            .map(|tx| tx.as_ref().hash())
+            .collect::<Vec<_>>();
+        let idx = transactions
+            .iter()
+            .position(|hash| hash == transaction_hash)?;
+        let merkle_proof = transactions
+            .into_iter()
+            .collect::<MerkleTree<_>>()
+            .get_proof(idx)?;
+
+        Some(InclusionProof {
+            transaction_hash: *transaction_hash,
+            merkle_proof,
+        })
+    }
+
     /// Calculate block payload [`Hash`](`iroha_crypto::HashOf`).
     #[inline]
     #[cfg(feature = "std")]
@@ -244,6 +284,7 @@ impl SignedBlock {
 
         let SignedBlock::V1(block) = self;
         block.signatures = BTreeSet::new().into();
+        block.aggregated_signers = Vec::new();
 
         for signature in signatures {
             if self.add_signature(signature).is_err() {
@@ -253,6 +294,28 @@ impl SignedBlock {
 
         true
     }
+
+    /// Replace signatures of this block with a single BLS signature aggregated from them.
+    ///
+    /// `signers` are the peers whose signatures are aggregated, see [`Self::aggregated_signers`].
+    ///
+    /// # Errors
+    ///
+    /// If signatures can't be aggregated, e.g. not all of them are BLS signatures
+    #[cfg(feature = "transparent_api")]
+    pub fn aggregate_signatures(
+        &mut self,
+        signers: Vec<u8>,
+    ) -> Result<(), iroha_crypto::error::Error> {
+        let SignedBlock::V1(block) = self;
+        let aggregated = block.signatures.aggregate()?;
+        aggregated.verify(&block.payload)?;
+
+        block.signatures = aggregated.into();
+        block.aggregated_signers = signers;
+
+        Ok(())
+    }
 }
 
 mod candidate {
@@ -264,6 +327,7 @@ mod candidate {
     struct SignedBlockCandidate {
         signatures: SignaturesOf<BlockPayload>,
         payload: BlockPayload,
+        aggregated_signers: Vec<u8>,
     }
 
     impl SignedBlockCandidate {
@@ -278,6 +342,7 @@ mod candidate {
             Ok(SignedBlockV1 {
                 payload: self.payload,
                 signatures: self.signatures,
+                aggregated_signers: self.aggregated_signers,
             })
         }
 
@@ -301,6 +366,10 @@ mod candidate {
         }
 
         fn validate_signatures(&self) -> Result<(), &'static str> {
+            if !self.aggregated_signers.is_empty() && self.signatures.len() != 1 {
+                return Err("Block with aggregated signature contains several signatures");
+            }
+
             self.signatures
                 .verify(&self.payload)
                 .map_err(|_| "Transaction contains invalid signatures")
@@ -328,6 +397,27 @@ mod candidate {
     }
 }
 
+/// Compact proof that a transaction is contained in a block.
+///
+/// It is verified against [`BlockHeader::transactions_hash`], so the verifier
+/// only needs the block header and not the whole block.
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize)]
+pub struct InclusionProof {
+    /// Hash of the proven transaction
+    pub transaction_hash: HashOf<SignedTransaction>,
+    /// Path from the transaction up to the root of the block's transactions tree
+    pub merkle_proof: MerkleProof<SignedTransaction>,
+}
+
+/// Check that `proof` proves inclusion of its transaction into the block with the given `header`
+pub fn verify_inclusion(proof: &InclusionProof, header: &BlockHeader) -> bool {
+    header.transactions_hash.map_or(false, |transactions_hash| {
+        proof
+            .merkle_proof
+            .verify(proof.transaction_hash, transactions_hash)
+    })
+}
+
 impl Display for SignedBlock {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let SignedBlock::V1(block) = self;
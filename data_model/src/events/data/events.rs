@@ -139,6 +139,8 @@ mod asset {
             MetadataRemoved(AssetDefinitionMetadataChanged),
             #[has_origin(total_quantity_changed => &total_quantity_changed.asset_definition_id)]
             TotalQuantityChanged(AssetDefinitionTotalQuantityChanged),
+            /// Asset definition wasn't registered since it already exists.
+            AlreadyRegistered(AssetDefinitionId),
         }
     }
 
@@ -317,6 +319,8 @@ mod account {
             MetadataInserted(AccountMetadataChanged),
             #[has_origin(metadata_changed => &metadata_changed.target_id)]
             MetadataRemoved(AccountMetadataChanged),
+            /// Account wasn't registered since it already exists.
+            AlreadyRegistered(AccountId),
         }
     }
 
@@ -403,6 +407,8 @@ mod domain {
             MetadataRemoved(DomainMetadataChanged),
             #[has_origin(owner_changed => &owner_changed.domain_id)]
             OwnerChanged(DomainOwnerChanged),
+            /// Domain wasn't registered since it already exists.
+            AlreadyRegistered(DomainId),
         }
     }
 
@@ -489,16 +495,64 @@ mod trigger {
 }
 
 mod config {
+    use iroha_data_model_derive::model;
+
+    pub use self::model::*;
     use super::*;
+    use crate::parameter::ParameterValueBox;
 
     data_event! {
         #[has_origin(origin = Parameter)]
         pub enum ConfigurationEvent {
-            Changed(ParameterId),
+            #[has_origin(parameter_changed => &parameter_changed.parameter_id)]
+            Changed(ParameterChanged),
             Created(ParameterId),
             Deleted(ParameterId),
         }
     }
+
+    #[model]
+    mod model {
+        use super::*;
+
+        /// [`Self`] represents the value of a [`Parameter`] being changed.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct ParameterChanged {
+            pub parameter_id: ParameterId,
+            // NOTE: Getters are implemented manually because `ParameterValueBox` is local to FFI
+            #[getset(skip)]
+            pub old_value: ParameterValueBox,
+            #[getset(skip)]
+            pub new_value: ParameterValueBox,
+        }
+    }
+
+    impl ParameterChanged {
+        /// Value of the parameter before the change
+        pub fn old_value(&self) -> &ParameterValueBox {
+            &self.old_value
+        }
+
+        /// Value of the parameter after the change
+        pub fn new_value(&self) -> &ParameterValueBox {
+            &self.new_value
+        }
+    }
 }
 
 mod executor {
@@ -512,12 +566,13 @@ mod executor {
     #[model]
     mod model {
 
+        use iroha_crypto::HashOf;
         use iroha_data_model_derive::EventSet;
 
         // this is used in no_std
         #[allow(unused)]
         use super::*;
-        use crate::executor::ExecutorDataModel;
+        use crate::executor::{Executor, ExecutorDataModel};
 
         #[derive(
             Debug,
@@ -541,7 +596,7 @@ mod executor {
             Upgraded(ExecutorUpgrade),
         }
 
-        /// Information about the updated executor data model.
+        /// Information about the upgraded executor and its data model.
         #[derive(
             Debug,
             Clone,
@@ -557,9 +612,10 @@ mod executor {
             Getters,
         )]
         #[ffi_type]
-        #[repr(transparent)]
         #[getset(get = "pub")]
         pub struct ExecutorUpgrade {
+            /// Hash of the new executor
+            pub new_executor_hash: HashOf<Executor>,
             /// Updated data model
             pub new_data_model: ExecutorDataModel,
         }
@@ -611,7 +667,7 @@ pub mod prelude {
             AssetDefinitionOwnerChanged, AssetDefinitionTotalQuantityChanged, AssetEvent,
             AssetEventSet,
         },
-        config::{ConfigurationEvent, ConfigurationEventSet},
+        config::{ConfigurationEvent, ConfigurationEventSet, ParameterChanged},
         domain::{DomainEvent, DomainEventSet, DomainOwnerChanged},
         executor::{ExecutorEvent, ExecutorEventSet, ExecutorUpgrade},
         peer::{PeerEvent, PeerEventSet},
@@ -137,6 +137,8 @@ mod model {
     pub struct AssetEventFilter {
         /// If specified matches only events originating from this asset
         pub(super) id_matcher: Option<super::AssetId>,
+        /// If specified matches only events originating from assets of this definition
+        pub(super) definition_matcher: Option<super::AssetDefinitionId>,
         /// Matches only event from this set
         pub(super) event_set: AssetEventSet,
     }
@@ -403,6 +405,7 @@ impl AssetEventFilter {
     pub const fn new() -> Self {
         Self {
             id_matcher: None,
+            definition_matcher: None,
             event_set: AssetEventSet::all(),
         }
     }
@@ -414,6 +417,13 @@ impl AssetEventFilter {
         self
     }
 
+    /// Modifies a [`AssetEventFilter`] to accept only [`AssetEvent`]s originating from assets of the `definition_matcher` definition, held by any account.
+    #[must_use]
+    pub fn for_asset_definition(mut self, definition_matcher: AssetDefinitionId) -> Self {
+        self.definition_matcher = Some(definition_matcher);
+        self
+    }
+
     /// Modifies a [`AssetEventFilter`] to accept only [`AssetEvent`]s of types contained in `event_set`.
     #[must_use]
     pub const fn for_events(mut self, event_set: AssetEventSet) -> Self {
@@ -439,6 +449,12 @@ impl super::EventFilter for AssetEventFilter {
             }
         }
 
+        if let Some(definition_matcher) = &self.definition_matcher {
+            if definition_matcher != event.origin_id().definition_id() {
+                return false;
+            }
+        }
+
         if !self.event_set.matches(event) {
             return false;
         }
@@ -794,5 +810,16 @@ mod tests {
         assert!(!asset_filter.matches(&domain_created));
         assert!(!asset_filter.matches(&account_created));
         assert!(asset_filter.matches(&asset_created));
+
+        // asset definition filter matches asset events of any holder of the definition
+        let definition_filter = DataEventFilter::Asset(
+            AssetEventFilter::new().for_asset_definition("rose#wonderland".parse().unwrap()),
+        );
+        let other_definition_filter = DataEventFilter::Asset(
+            AssetEventFilter::new().for_asset_definition("tulip#wonderland".parse().unwrap()),
+        );
+        assert!(!definition_filter.matches(&account_created));
+        assert!(definition_filter.matches(&asset_created));
+        assert!(!other_definition_filter.matches(&asset_created));
     }
 }
@@ -7,7 +7,7 @@ use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_schema::IntoSchema;
 use parity_scale_codec::{Decode, Encode};
-use pipeline::{BlockEvent, TransactionEvent};
+use pipeline::{BlockCommittedEvent, BlockEvent, TransactionEvent};
 use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
@@ -139,6 +139,12 @@ impl From<BlockEvent> for EventBox {
     }
 }
 
+impl From<BlockCommittedEvent> for EventBox {
+    fn from(source: BlockCommittedEvent) -> Self {
+        Self::Pipeline(source.into())
+    }
+}
+
 impl TryFrom<EventBox> for TransactionEvent {
     type Error = iroha_macro::error::ErrorTryFromEnum<EventBox, Self>;
 
@@ -171,6 +177,22 @@ impl TryFrom<EventBox> for BlockEvent {
     }
 }
 
+impl TryFrom<EventBox> for BlockCommittedEvent {
+    type Error = iroha_macro::error::ErrorTryFromEnum<EventBox, Self>;
+
+    fn try_from(event: EventBox) -> Result<Self, Self::Error> {
+        use iroha_macro::error::ErrorTryFromEnum;
+
+        let EventBox::Pipeline(pipeline_event) = event else {
+            return Err(ErrorTryFromEnum::default());
+        };
+
+        pipeline_event
+            .try_into()
+            .map_err(|_| ErrorTryFromEnum::default())
+    }
+}
+
 /// Trait for filters
 #[cfg(feature = "transparent_api")]
 pub trait EventFilter {
@@ -260,7 +282,7 @@ impl EventFilter for TriggeringEventFilterBox {
 
 mod conversions {
     use super::{
-        pipeline::{BlockEventFilter, TransactionEventFilter},
+        pipeline::{BlockCommittedEventFilter, BlockEventFilter, TransactionEventFilter},
         prelude::*,
     };
 
@@ -310,11 +332,13 @@ mod conversions {
         ConfigurationEventFilter    => DataEventFilter => TriggeringEventFilterBox,
         ExecutorEventFilter         => DataEventFilter => TriggeringEventFilterBox,
 
-        TransactionEventFilter => PipelineEventFilterBox => TriggeringEventFilterBox,
-        BlockEventFilter       => PipelineEventFilterBox => TriggeringEventFilterBox,
+        TransactionEventFilter    => PipelineEventFilterBox => TriggeringEventFilterBox,
+        BlockEventFilter          => PipelineEventFilterBox => TriggeringEventFilterBox,
+        BlockCommittedEventFilter => PipelineEventFilterBox => TriggeringEventFilterBox,
 
-        TransactionEventFilter => PipelineEventFilterBox => EventFilterBox,
-        BlockEventFilter       => PipelineEventFilterBox => EventFilterBox,
+        TransactionEventFilter    => PipelineEventFilterBox => EventFilterBox,
+        BlockEventFilter          => PipelineEventFilterBox => EventFilterBox,
+        BlockCommittedEventFilter => PipelineEventFilterBox => EventFilterBox,
     }
 }
 
@@ -40,6 +40,7 @@ mod model {
     pub enum PipelineEventBox {
         Transaction(TransactionEvent),
         Block(BlockEvent),
+        BlockCommitted(BlockCommittedEvent),
     }
 
     #[derive(
@@ -64,6 +65,61 @@ mod model {
         pub status: BlockStatus,
     }
 
+    /// Summary of a committed block, emitted once per block after [`BlockStatus::Committed`]
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        CopyGetters,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub struct BlockCommittedEvent {
+        /// Height of the committed block
+        #[getset(get_copy = "pub")]
+        pub height: u64,
+        /// Hash of the committed block
+        #[getset(get = "pub")]
+        pub hash: HashOf<SignedBlock>,
+        /// Summaries of the block's transactions, in the order they appear in the block
+        #[getset(get = "pub")]
+        pub transactions: Vec<TransactionSummary>,
+    }
+
+    /// Outcome of a transaction in a committed block
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        CopyGetters,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub struct TransactionSummary {
+        /// Hash of the transaction
+        #[getset(get = "pub")]
+        pub hash: HashOf<SignedTransaction>,
+        /// Whether the transaction was rejected
+        #[getset(get_copy = "pub")]
+        pub is_rejected: bool,
+    }
+
     #[derive(
         Debug,
         Clone,
@@ -160,6 +216,28 @@ mod model {
     pub enum PipelineEventFilterBox {
         Transaction(TransactionEventFilter),
         Block(BlockEventFilter),
+        BlockCommitted(BlockCommittedEventFilter),
+    }
+
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Default,
+        CopyGetters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub struct BlockCommittedEventFilter {
+        #[getset(get_copy = "pub")]
+        pub height: Option<u64>,
     }
 
     #[derive(
@@ -236,6 +314,28 @@ impl BlockEventFilter {
     }
 }
 
+impl BlockCommittedEvent {
+    /// Number of transactions in the block
+    pub fn transaction_count(&self) -> usize {
+        self.transactions.len()
+    }
+}
+
+impl BlockCommittedEventFilter {
+    /// Construct new instance
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { height: None }
+    }
+
+    /// Match only the summary of the block with the given height
+    #[must_use]
+    pub fn for_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
+}
+
 impl TransactionEventFilter {
     /// Construct new instance
     #[must_use]
@@ -325,6 +425,12 @@ impl super::EventFilter for PipelineEventFilterBox {
             ]
             .into_iter()
             .all(core::convert::identity),
+            (
+                Self::BlockCommitted(block_committed_filter),
+                PipelineEventBox::BlockCommitted(block_committed_event),
+            ) => block_committed_filter
+                .height
+                .map_or(true, |height| height == block_committed_event.height),
             _ => false,
         }
     }
@@ -333,8 +439,8 @@ impl super::EventFilter for PipelineEventFilterBox {
 /// Exports common structs and enums from this module.
 pub mod prelude {
     pub use super::{
-        BlockEvent, BlockStatus, PipelineEventBox, PipelineEventFilterBox, TransactionEvent,
-        TransactionStatus,
+        BlockCommittedEvent, BlockCommittedEventFilter, BlockEvent, BlockStatus, PipelineEventBox,
+        PipelineEventFilterBox, TransactionEvent, TransactionStatus, TransactionSummary,
     };
 }
 
@@ -1,8 +1,10 @@
 //! This library contains basic Iroha Special Instructions.
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{collections::btree_map, format, string::String, vec::Vec};
 use core::fmt::{Debug, Display};
+#[cfg(feature = "std")]
+use std::collections::btree_map;
 
 use derive_more::{Constructor, DebugCustom, Display};
 use iroha_data_model_derive::{model, EnumRef};
@@ -119,6 +121,28 @@ mod model {
 
         #[debug(fmt = "{_0:?}")]
         Fail(Fail),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        Lock(LockBox),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        Unlock(UnlockBox),
+        #[debug(fmt = "{_0:?}")]
+        SetParameterIf(SetParameterIf),
+        #[debug(fmt = "{_0:?}")]
+        MintBatch(MintBatch),
+        #[debug(fmt = "{_0:?}")]
+        SetParameters(SetParameters),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        RegisterMany(RegisterManyBox),
+        #[debug(fmt = "{_0:?}")]
+        SetAccountMetadata(SetAccountMetadata),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        RegisterIfAbsent(RegisterIfAbsentBox),
+        #[debug(fmt = "{_0:?}")]
+        MigrateAssetValueType(MigrateAssetValueType),
     }
 }
 
@@ -150,6 +174,12 @@ impl_instruction! {
     Register<Asset>,
     Register<Role>,
     Register<Trigger>,
+    RegisterMany<Domain>,
+    RegisterMany<Account>,
+    RegisterMany<AssetDefinition>,
+    RegisterIfAbsent<Domain>,
+    RegisterIfAbsent<Account>,
+    RegisterIfAbsent<AssetDefinition>,
     Unregister<Peer>,
     Unregister<Domain>,
     Unregister<Account>,
@@ -161,6 +191,8 @@ impl_instruction! {
     Mint<u32, Trigger>,
     Burn<Numeric, Asset>,
     Burn<u32, Trigger>,
+    Lock<Numeric, Asset>,
+    Unlock<Numeric, Asset>,
     Transfer<Account, DomainId, Account>,
     Transfer<Account, AssetDefinitionId, Account>,
     Transfer<Asset, Numeric, Account>,
@@ -172,6 +204,11 @@ impl_instruction! {
     Revoke<RoleId, Account>,
     Revoke<Permission, Role>,
     SetParameter,
+    SetParameterIf,
+    SetParameters,
+    MintBatch,
+    SetAccountMetadata,
+    MigrateAssetValueType,
     NewParameter,
     Upgrade,
     ExecuteTrigger,
@@ -187,7 +224,9 @@ impl Instruction for InstructionBox {
 
 mod transparent {
     use super::*;
-    use crate::{account::NewAccount, domain::NewDomain, metadata::Metadata};
+    use crate::{
+        account::NewAccount, domain::NewDomain, metadata::Metadata, parameter::ParameterValueBox,
+    };
 
     macro_rules! isi {
         ($($meta:meta)* $item:item) => {
@@ -258,6 +297,42 @@ mod transparent {
         }
     }
 
+    isi! {
+        /// Instruction for setting a chain-wide config parameter only if its
+        /// current value equals `expected_current`.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "SET `{parameter}` IF `{expected_current}`")]
+        pub struct SetParameterIf {
+            /// The configuration parameter being changed.
+            pub parameter: Parameter,
+            /// Value the parameter must currently hold for the change to apply.
+            pub expected_current: ParameterValueBox,
+        }
+    }
+
+    isi! {
+        /// Instruction for setting several chain-wide config parameters at once.
+        ///
+        /// Either every parameter is set or none of them are.
+        #[derive(Display)]
+        #[display(fmt = "SET PARAMETERS")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct SetParameters {
+            /// The configuration parameters being changed.
+            pub parameters: Vec<Parameter>,
+        }
+    }
+
+    impl SetParameters {
+        /// Constructs a new [`SetParameters`] changing the given parameters.
+        pub fn new(parameters: impl IntoIterator<Item = Parameter>) -> Self {
+            Self {
+                parameters: parameters.into_iter().collect(),
+            }
+        }
+    }
+
     isi! {
         /// Sized structure for all possible on-chain configuration parameters when they are first created.
         /// Generic instruction for setting a chain-wide config parameter.
@@ -285,6 +360,50 @@ mod transparent {
         }
     }
 
+    isi! {
+        /// Instruction for setting several metadata entries of an [`Account`] at once.
+        ///
+        /// Either every entry is set or none of them are. Entries are merged into the
+        /// existing metadata, unless [`Self::replace`] is set, in which case keys that are
+        /// not among the new entries are removed.
+        #[derive(Display)]
+        #[display(fmt = "SET METADATA OF `{account_id}`")]
+        pub struct SetAccountMetadata {
+            /// Account whose metadata is being set.
+            pub account_id: AccountId,
+            /// Entries being set.
+            pub metadata: UnlimitedMetadata,
+            /// Whether existing entries missing from [`Self::metadata`] are removed.
+            pub replace: bool,
+        }
+    }
+
+    impl SetAccountMetadata {
+        /// Constructs a new [`SetAccountMetadata`] merging `metadata` into the existing metadata.
+        pub fn merge(
+            account_id: AccountId,
+            metadata: impl IntoIterator<Item = (Name, MetadataValueBox)>,
+        ) -> Self {
+            Self {
+                account_id,
+                metadata: metadata.into_iter().collect(),
+                replace: false,
+            }
+        }
+
+        /// Constructs a new [`SetAccountMetadata`] replacing the existing metadata with `metadata`.
+        pub fn replace(
+            account_id: AccountId,
+            metadata: impl IntoIterator<Item = (Name, MetadataValueBox)>,
+        ) -> Self {
+            Self {
+                account_id,
+                metadata: metadata.into_iter().collect(),
+                replace: true,
+            }
+        }
+    }
+
     impl SetKeyValue<Domain> {
         /// Constructs a new [`SetKeyValue`] for a [`Domain`] with the given `key` and `value`.
         pub fn domain(domain_id: DomainId, key: Name, value: impl Into<MetadataValueBox>) -> Self {
@@ -540,6 +659,115 @@ mod transparent {
     => RegisterBoxRef<'a> => InstructionBoxRef<'a>[Register]
     }
 
+    isi! {
+        /// Generic instruction for a registration of several objects of the same type at once.
+        ///
+        /// Either every object is registered or none of them are.
+        #[schema(bounds = "O: Registered, O::With: IntoSchema")]
+        #[serde(transparent)]
+        pub struct RegisterMany<O: Registered> {
+            /// The objects that should be registered, each should be uniquely identifiable by its id.
+            pub objects: Vec<O::With>,
+        }
+    }
+
+    impl RegisterMany<Domain> {
+        /// Constructs a new [`RegisterMany`] for [`Domain`]s.
+        pub fn domains(new_domains: impl IntoIterator<Item = NewDomain>) -> Self {
+            Self {
+                objects: new_domains.into_iter().collect(),
+            }
+        }
+    }
+
+    impl RegisterMany<Account> {
+        /// Constructs a new [`RegisterMany`] for [`Account`]s.
+        pub fn accounts(new_accounts: impl IntoIterator<Item = NewAccount>) -> Self {
+            Self {
+                objects: new_accounts.into_iter().collect(),
+            }
+        }
+    }
+
+    impl RegisterMany<AssetDefinition> {
+        /// Constructs a new [`RegisterMany`] for [`AssetDefinition`]s.
+        pub fn asset_definitions(
+            new_asset_definitions: impl IntoIterator<Item = NewAssetDefinition>,
+        ) -> Self {
+            Self {
+                objects: new_asset_definitions.into_iter().collect(),
+            }
+        }
+    }
+
+    impl<O: Registered> Display for RegisterMany<O> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "REGISTER {} OBJECTS", self.objects.len())
+        }
+    }
+
+    impl_into_box! {
+        RegisterMany<Domain> |
+        RegisterMany<Account> |
+        RegisterMany<AssetDefinition>
+    => RegisterManyBox => InstructionBox[RegisterMany],
+    => RegisterManyBoxRef<'a> => InstructionBoxRef<'a>[RegisterMany]
+    }
+
+    isi! {
+        /// Generic instruction for a registration of an object which succeeds
+        /// without changing anything if an object with the same id is already registered.
+        #[schema(bounds = "O: Registered, O::With: IntoSchema")]
+        #[serde(transparent)]
+        pub struct RegisterIfAbsent<O: Registered> {
+            /// The object that should be registered unless its id is already taken.
+            pub object: O::With,
+        }
+    }
+
+    impl RegisterIfAbsent<Domain> {
+        /// Constructs a new [`RegisterIfAbsent`] for a [`Domain`].
+        pub fn domain(new_domain: NewDomain) -> Self {
+            Self { object: new_domain }
+        }
+    }
+
+    impl RegisterIfAbsent<Account> {
+        /// Constructs a new [`RegisterIfAbsent`] for an [`Account`].
+        pub fn account(new_account: NewAccount) -> Self {
+            Self {
+                object: new_account,
+            }
+        }
+    }
+
+    impl RegisterIfAbsent<AssetDefinition> {
+        /// Constructs a new [`RegisterIfAbsent`] for an [`AssetDefinition`].
+        pub fn asset_definition(new_asset_definition: NewAssetDefinition) -> Self {
+            Self {
+                object: new_asset_definition,
+            }
+        }
+    }
+
+    impl_display! {
+        RegisterIfAbsent<O>
+        where
+            O: Registered,
+            O::With: Display,
+        =>
+        "REGISTER IF ABSENT `{}`",
+        object,
+    }
+
+    impl_into_box! {
+        RegisterIfAbsent<Domain> |
+        RegisterIfAbsent<Account> |
+        RegisterIfAbsent<AssetDefinition>
+    => RegisterIfAbsentBox => InstructionBox[RegisterIfAbsent],
+    => RegisterIfAbsentBoxRef<'a> => InstructionBoxRef<'a>[RegisterIfAbsent]
+    }
+
     isi! {
         /// Generic instruction for an unregistration of an object from the identifiable destination.
         #[schema(bounds = "O: Identifiable, O::Id: IntoSchema")]
@@ -680,6 +908,60 @@ mod transparent {
     => MintBoxRef<'a> => InstructionBoxRef<'a>[Mint]
     }
 
+    isi! {
+        /// Instruction for minting a [`Numeric`] asset of a single definition to several accounts at once.
+        ///
+        /// Either every mint in the batch is applied or none of them are.
+        #[derive(Display)]
+        #[display(fmt = "MINT BATCH OF `{asset_definition_id}`")]
+        pub struct MintBatch {
+            /// Definition of the asset being minted.
+            pub asset_definition_id: AssetDefinitionId,
+            /// Accounts receiving the asset mapped to the amount each of them receives.
+            pub mints: btree_map::BTreeMap<AccountId, Numeric>,
+        }
+    }
+
+    impl MintBatch {
+        /// Constructs a new [`MintBatch`] of the given asset definition.
+        ///
+        /// If an account is listed more than once, only its last amount is kept.
+        pub fn new(
+            asset_definition_id: AssetDefinitionId,
+            mints: impl IntoIterator<Item = (AccountId, Numeric)>,
+        ) -> Self {
+            Self {
+                asset_definition_id,
+                mints: mints.into_iter().collect(),
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction for changing the value type of an [`AssetDefinition`] to another numeric one.
+        ///
+        /// Balances keep their values, so the instruction fails without changing anything
+        /// if some of them can't be represented with the new value type.
+        #[derive(Display)]
+        #[display(fmt = "MIGRATE VALUE TYPE OF `{asset_definition_id}`")]
+        pub struct MigrateAssetValueType {
+            /// Asset definition whose value type is changed.
+            pub asset_definition_id: AssetDefinitionId,
+            /// New value type of the asset definition.
+            pub value_type: AssetValueType,
+        }
+    }
+
+    impl MigrateAssetValueType {
+        /// Constructs a new [`MigrateAssetValueType`] of the given asset definition.
+        pub fn new(asset_definition_id: AssetDefinitionId, value_type: AssetValueType) -> Self {
+            Self {
+                asset_definition_id,
+                value_type,
+            }
+        }
+    }
+
     isi! {
         /// Generic instruction for a burn of an object to the identifiable destination.
         #[schema(bounds = "O: IntoSchema, D: Identifiable, D::Id: IntoSchema")]
@@ -730,6 +1012,84 @@ mod transparent {
     => BurnBoxRef<'a> => InstructionBoxRef<'a>[Burn]
     }
 
+    isi! {
+        /// Generic instruction for locking a part of an object so that it can't be spent until unlocked.
+        #[schema(bounds = "O: IntoSchema, D: Identifiable, D::Id: IntoSchema")]
+        pub struct Lock<O, D: Identifiable> {
+            /// Object which should be locked.
+            pub object: O,
+            /// Destination object [`Identifiable::Id`].
+            pub destination_id: D::Id,
+        }
+    }
+
+    impl Lock<Numeric, Asset> {
+        /// Constructs a new [`Lock`] for an [`Asset`] of [`Numeric`] type.
+        pub fn asset_numeric(object: impl Into<Numeric>, asset_id: AssetId) -> Self {
+            Self {
+                object: object.into(),
+                destination_id: asset_id,
+            }
+        }
+    }
+
+    impl_display! {
+        Lock<O, D>
+        where
+            O: Display,
+            D: Identifiable,
+            D::Id: Display,
+        =>
+        "LOCK `{}` IN `{}`",
+        object,
+        destination_id,
+    }
+
+    impl_into_box! {
+        Lock<Numeric, Asset>
+    => LockBox => InstructionBox[Lock],
+    => LockBoxRef<'a> => InstructionBoxRef<'a>[Lock]
+    }
+
+    isi! {
+        /// Generic instruction for releasing a part of an object previously locked by the same authority.
+        #[schema(bounds = "O: IntoSchema, D: Identifiable, D::Id: IntoSchema")]
+        pub struct Unlock<O, D: Identifiable> {
+            /// Object which should be unlocked.
+            pub object: O,
+            /// Destination object [`Identifiable::Id`].
+            pub destination_id: D::Id,
+        }
+    }
+
+    impl Unlock<Numeric, Asset> {
+        /// Constructs a new [`Unlock`] for an [`Asset`] of [`Numeric`] type.
+        pub fn asset_numeric(object: impl Into<Numeric>, asset_id: AssetId) -> Self {
+            Self {
+                object: object.into(),
+                destination_id: asset_id,
+            }
+        }
+    }
+
+    impl_display! {
+        Unlock<O, D>
+        where
+            O: Display,
+            D: Identifiable,
+            D::Id: Display,
+        =>
+        "UNLOCK `{}` IN `{}`",
+        object,
+        destination_id,
+    }
+
+    impl_into_box! {
+        Unlock<Numeric, Asset>
+    => UnlockBox => InstructionBox[Unlock],
+    => UnlockBoxRef<'a> => InstructionBoxRef<'a>[Unlock]
+    }
+
     isi! {
         /// Generic instruction for a transfer of an object from the identifiable source to the identifiable destination.
         #[schema(bounds = "S: Identifiable, S::Id: IntoSchema, \
@@ -1146,6 +1506,66 @@ isi_box! {
     }
 }
 
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(RegisterManyType),
+        derive(Encode),
+    )]
+    /// Enum with all supported [`RegisterMany`] instructions.
+    pub enum RegisterManyBox {
+        /// Register several [`Domain`]s.
+        Domain(RegisterMany<Domain>),
+        /// Register several [`Account`]s.
+        Account(RegisterMany<Account>),
+        /// Register several [`AssetDefinition`]s.
+        AssetDefinition(RegisterMany<AssetDefinition>),
+    }
+}
+
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(RegisterIfAbsentType),
+        derive(Encode),
+    )]
+    /// Enum with all supported [`RegisterIfAbsent`] instructions.
+    pub enum RegisterIfAbsentBox {
+        /// Register [`Domain`] if absent.
+        Domain(RegisterIfAbsent<Domain>),
+        /// Register [`Account`] if absent.
+        Account(RegisterIfAbsent<Account>),
+        /// Register [`AssetDefinition`] if absent.
+        AssetDefinition(RegisterIfAbsent<AssetDefinition>),
+    }
+}
+
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(LockType),
+        derive(Encode),
+    )]
+    /// Enum with all supported [`Lock`] instructions.
+    pub enum LockBox {
+        /// Lock [`Asset`].
+        Asset(Lock<Numeric, Asset>),
+    }
+}
+
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(UnlockType),
+        derive(Encode),
+    )]
+    /// Enum with all supported [`Unlock`] instructions.
+    pub enum UnlockBox {
+        /// Unlock [`Asset`].
+        Asset(Unlock<Numeric, Asset>),
+    }
+}
+
 isi_box! {
     #[strum_discriminants(
         vis(pub(crate)),
@@ -1217,12 +1637,13 @@ pub mod error {
     //! Module containing errors that can occur during instruction evaluation
 
     #[cfg(not(feature = "std"))]
-    use alloc::{format, string::String, vec::Vec};
+    use alloc::{boxed::Box, format, string::String, vec::Vec};
     use core::fmt::Debug;
 
     use derive_more::Display;
     use iroha_data_model_derive::model;
     use iroha_macro::FromVariant;
+    use iroha_primitives::numeric::Numeric;
     use iroha_schema::IntoSchema;
     use parity_scale_codec::{Decode, Encode};
 
@@ -1231,6 +1652,8 @@ pub mod error {
     use crate::{
         asset::AssetValueType,
         metadata,
+        name::Name,
+        parameter::ParameterId,
         query::error::{FindError, QueryExecutionFail},
         IdBox,
     };
@@ -1298,6 +1721,8 @@ pub mod error {
                 #[skip_try_from]
                 String,
             ),
+            /// Batch item failed
+            Batch(#[cfg_attr(feature = "std", source)] BatchItemError),
         }
 
         /// Evaluation error. This error indicates instruction is not a valid Iroha DSL
@@ -1386,6 +1811,12 @@ pub mod error {
                 #[skip_try_from]
                 AssetValueType,
             ),
+            /// Value {0} can't be represented with the requested numeric spec
+            NumericSpecViolation(
+                #[skip_from]
+                #[skip_try_from]
+                Numeric,
+            ),
         }
 
         /// Math error, which occurs during instruction execution
@@ -1481,6 +1912,111 @@ pub mod error {
             NameLength,
             /// Attempt to register a time-trigger with `start` point in the past
             TimeTriggerInThePast,
+            /// Invalid parameter value
+            ParameterValue(#[cfg_attr(feature = "std", source)] ParameterValueError),
+            /// Name `{0}` doesn't conform to the naming policy
+            NamingPolicy(Name),
+        }
+
+        /// Value of parameter `{parameter_id}` is rejected: it {constraint}
+        #[derive(
+            Debug,
+            displaydoc::Display,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Deserialize,
+            Serialize,
+            Decode,
+            Encode,
+            IntoSchema,
+        )]
+        #[cfg_attr(feature = "std", derive(thiserror::Error))]
+        #[ffi_type]
+        pub struct ParameterValueError {
+            /// Id of the parameter whose value is rejected
+            pub parameter_id: ParameterId,
+            /// Constraint violated by the value
+            pub constraint: ParameterConstraint,
+        }
+
+        /// Constraint on the value of a chain-wide parameter
+        #[derive(
+            Debug,
+            displaydoc::Display,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Deserialize,
+            Serialize,
+            Decode,
+            Encode,
+            IntoSchema,
+        )]
+        #[ffi_type(opaque)]
+        pub enum ParameterConstraint {
+            /// must be a positive whole number of milliseconds
+            PositiveMilliseconds,
+            /// must not be greater than the value of `{0}`
+            NotGreaterThan(ParameterId),
+            /// must not be less than the value of `{0}`
+            NotLessThan(ParameterId),
+            /// must be a valid regular expression
+            Regex,
+        }
+
+        /// Item #{index} of the batch failed
+        #[derive(
+            Debug,
+            displaydoc::Display,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Deserialize,
+            Serialize,
+            Decode,
+            Encode,
+            IntoSchema,
+        )]
+        #[cfg_attr(feature = "std", derive(thiserror::Error))]
+        #[ffi_type(opaque)]
+        pub struct BatchItemError {
+            /// Position of the failed item in the batch
+            pub index: u32,
+            /// Reason the item failed
+            #[cfg_attr(feature = "std", source)]
+            pub error: Box<InstructionExecutionError>,
+        }
+
+        /// Instruction #{index} of the transaction failed
+        #[derive(
+            Debug,
+            displaydoc::Display,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Deserialize,
+            Serialize,
+            Decode,
+            Encode,
+            IntoSchema,
+        )]
+        #[cfg_attr(feature = "std", derive(thiserror::Error))]
+        #[ffi_type(opaque)]
+        pub struct IndexedInstructionError {
+            /// Position of the failed instruction in the transaction
+            pub index: u32,
+            /// Reason the instruction failed or was rejected
+            #[cfg_attr(feature = "std", source)]
+            pub error: Box<crate::ValidationFail>,
         }
 
         /// Repetition of of `{instruction_type}` for id `{id}`
@@ -1508,6 +2044,38 @@ pub mod error {
         }
     }
 
+    impl BatchItemError {
+        /// Construct [`BatchItemError`] for the item at `index` failed with `error`
+        ///
+        /// # Panics
+        ///
+        /// If `index` doesn't fit into [`u32`]
+        pub fn new(index: usize, error: impl Into<InstructionExecutionError>) -> Self {
+            Self {
+                index: index
+                    .try_into()
+                    .expect("Batch can't hold more than `u32::MAX` items"),
+                error: Box::new(error.into()),
+            }
+        }
+    }
+
+    impl IndexedInstructionError {
+        /// Construct [`IndexedInstructionError`] for the instruction at `index` failed with `error`
+        ///
+        /// # Panics
+        ///
+        /// If `index` doesn't fit into [`u32`]
+        pub fn new(index: usize, error: impl Into<crate::ValidationFail>) -> Self {
+            Self {
+                index: index
+                    .try_into()
+                    .expect("Transaction can't hold more than `u32::MAX` instructions"),
+                error: Box::new(error.into()),
+            }
+        }
+    }
+
     impl From<TypeError> for InstructionExecutionError {
         fn from(err: TypeError) -> Self {
             Self::Evaluate(InstructionEvaluationError::Type(err))
@@ -1519,8 +2087,10 @@ pub mod error {
 pub mod prelude {
     pub use super::{
         AssetTransferBox, Burn, BurnBox, ExecuteTrigger, Fail, Grant, GrantBox, InstructionBox,
-        Log, Mint, MintBox, NewParameter, Register, RegisterBox, RemoveKeyValue, RemoveKeyValueBox,
-        Revoke, RevokeBox, SetKeyValue, SetKeyValueBox, SetParameter, Transfer, TransferBox,
-        Unregister, UnregisterBox, Upgrade,
+        Lock, LockBox, Log, MigrateAssetValueType, Mint, MintBatch, MintBox, NewParameter,
+        Register, RegisterBox, RegisterIfAbsent, RegisterIfAbsentBox, RegisterMany,
+        RegisterManyBox, RemoveKeyValue, RemoveKeyValueBox, Revoke, RevokeBox, SetAccountMetadata,
+        SetKeyValue, SetKeyValueBox, SetParameter, SetParameterIf, SetParameters, Transfer,
+        TransferBox, Unlock, UnlockBox, Unregister, UnregisterBox, Upgrade,
     };
 }
@@ -89,6 +89,14 @@ mod seal {
         Register<Role>,
         Register<Trigger>,
 
+        RegisterMany<Domain>,
+        RegisterMany<Account>,
+        RegisterMany<AssetDefinition>,
+
+        RegisterIfAbsent<Domain>,
+        RegisterIfAbsent<Account>,
+        RegisterIfAbsent<AssetDefinition>,
+
         Unregister<Peer>,
         Unregister<Domain>,
         Unregister<Account>,
@@ -103,6 +111,9 @@ mod seal {
         Burn<Numeric, Asset>,
         Burn<u32, Trigger>,
 
+        Lock<Numeric, Asset>,
+        Unlock<Numeric, Asset>,
+
         Transfer<Account, DomainId, Account>,
         Transfer<Account, AssetDefinitionId, Account>,
         Transfer<Asset, Numeric, Account>,
@@ -117,6 +128,11 @@ mod seal {
         Revoke<Permission, Role>,
 
         SetParameter,
+        SetParameterIf,
+        SetParameters,
+        MintBatch,
+        SetAccountMetadata,
+        MigrateAssetValueType,
         NewParameter,
         Upgrade,
         ExecuteTrigger,
@@ -141,15 +157,19 @@ mod seal {
         FindAssetsByDomainIdAndAssetDefinitionId,
         FindAssetQuantityById,
         FindTotalAssetQuantityByAssetDefinitionId,
+        FindTotalAssetQuantityByDomainIdAndAssetDefinitionId,
         FindAssetKeyValueByIdAndKey,
         FindAssetDefinitionKeyValueByIdAndKey,
         FindAllDomains,
         FindDomainById,
         FindDomainKeyValueByIdAndKey,
         FindAllPeers,
+        FindTopology,
         FindAllBlocks,
         FindAllBlockHeaders,
         FindBlockHeaderByHash,
+        FindBlockByHeight,
+        FindBlockByHash,
         FindAllTransactions,
         FindTransactionsByAccountId,
         FindTransactionByHash,
@@ -164,6 +184,7 @@ mod seal {
         FindRoleByRoleId,
         FindRolesByAccountId,
         FindAllParameters,
+        FindParametersBySubsystem,
     }
 }
 
@@ -235,10 +256,13 @@ pub mod parameter {
         pub const WSV_ASSET_METADATA_LIMITS: &str = "WSVAssetMetadataLimits";
         pub const WSV_TRIGGER_METADATA_LIMITS: &str = "WSVTriggerMetadataLimits";
         pub const WSV_IDENT_LENGTH_LIMITS: &str = "WSVIdentLengthLimits";
+        pub const WSV_IDENT_NAMING_POLICY: &str = "WSVIdentNamingPolicy";
         pub const EXECUTOR_FUEL_LIMIT: &str = "ExecutorFuelLimit";
         pub const EXECUTOR_MAX_MEMORY: &str = "ExecutorMaxMemory";
         pub const WASM_FUEL_LIMIT: &str = "WASMFuelLimit";
         pub const WASM_MAX_MEMORY: &str = "WASMMaxMemory";
+        pub const MAX_BLOCK_FUEL: &str = "MaxBlockFuel";
+        pub const MAX_CLOCK_SKEW: &str = "MaxClockSkew";
     }
 
     #[model]
@@ -269,6 +293,33 @@ pub mod parameter {
                 #[skip_try_from]
                 Numeric,
             ),
+            IdentNamingPolicy(IdentNamingPolicy),
+        }
+
+        /// Regular expression which names of registered domains and asset definitions must match as a whole.
+        #[derive(
+            Debug,
+            Display,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Constructor,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[display(fmt = "{pattern}_NP")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        // SAFETY: `IdentNamingPolicy` has no trap representation in `String`
+        #[ffi_type(unsafe {robust})]
+        pub struct IdentNamingPolicy {
+            /// Pattern of the regular expression
+            pub pattern: String,
         }
 
         /// Identification of a [`Parameter`].
@@ -320,6 +371,37 @@ pub mod parameter {
             /// Current value of the [`Parameter`].
             pub val: ParameterValueBox,
         }
+
+        /// Subsystem of Iroha configured by a group of [`Parameter`]s.
+        #[derive(
+            Debug,
+            Display,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[ffi_type]
+        #[repr(u8)]
+        pub enum ParameterSubsystem {
+            /// Consensus: block time, commit time, block size and block fuel.
+            Sumeragi,
+            /// Limits on transactions.
+            Transaction,
+            /// Metadata limits, identifier length limits and naming policy of the world state.
+            World,
+            /// Runtime limits of the executor.
+            Executor,
+            /// Runtime limits of smart contracts and triggers.
+            SmartContract,
+        }
     }
 
     // TODO: Maybe derive
@@ -330,6 +412,7 @@ pub mod parameter {
                 Self::TransactionLimits(v) => core::fmt::Display::fmt(&v, f),
                 Self::LengthLimits(v) => core::fmt::Display::fmt(&v, f),
                 Self::Numeric(v) => core::fmt::Display::fmt(&v, f),
+                Self::IdentNamingPolicy(v) => core::fmt::Display::fmt(&v, f),
             }
         }
     }
@@ -375,6 +458,31 @@ pub mod parameter {
         }
     }
 
+    impl ParameterSubsystem {
+        /// Subsystem configured by the parameter with the given `id`.
+        /// Returns `None` for the parameters not used by Iroha itself.
+        pub fn of(id: &ParameterId) -> Option<Self> {
+            use default::*;
+
+            match Borrow::<str>::borrow(id) {
+                MAX_TRANSACTIONS_IN_BLOCK | BLOCK_TIME | COMMIT_TIME_LIMIT | MAX_BLOCK_FUEL => {
+                    Some(Self::Sumeragi)
+                }
+                TRANSACTION_LIMITS | MAX_CLOCK_SKEW => Some(Self::Transaction),
+                WSV_DOMAIN_METADATA_LIMITS
+                | WSV_ASSET_DEFINITION_METADATA_LIMITS
+                | WSV_ACCOUNT_METADATA_LIMITS
+                | WSV_ASSET_METADATA_LIMITS
+                | WSV_TRIGGER_METADATA_LIMITS
+                | WSV_IDENT_LENGTH_LIMITS
+                | WSV_IDENT_NAMING_POLICY => Some(Self::World),
+                EXECUTOR_FUEL_LIMIT | EXECUTOR_MAX_MEMORY => Some(Self::Executor),
+                WASM_FUEL_LIMIT | WASM_MAX_MEMORY => Some(Self::SmartContract),
+                _ => None,
+            }
+        }
+    }
+
     impl Borrow<str> for ParameterId {
         fn borrow(&self) -> &str {
             self.name.borrow()
@@ -391,7 +499,13 @@ pub mod parameter {
         type Err = ParseError;
 
         fn from_str(string: &str) -> Result<Self, Self::Err> {
-            if let Some((parameter_id_candidate, val_candidate)) = string.rsplit_once('=') {
+            // Naming policy patterns may contain `=` themselves
+            let split = if string.ends_with("_NP") {
+                string.split_once('=')
+            } else {
+                string.rsplit_once('=')
+            };
+            if let Some((parameter_id_candidate, val_candidate)) = split {
                 if let Some(parameter_id_candidate) = parameter_id_candidate.strip_prefix('?') {
                     let param_id: ParameterId =
                         parameter_id_candidate.parse().map_err(|_| ParseError {
@@ -450,6 +564,8 @@ pub mod parameter {
                                 })?;
                                 metadata::Limits::new(lower, upper).into()
                             }
+                            // Shorthand for `IdentNamingPolicy`
+                            "NP" => IdentNamingPolicy::new(val.to_owned()).into(),
                             _ => return Err(ParseError {
                                 reason:
                                     "Unsupported type provided for the `val` part of the `Parameter`.",
@@ -539,7 +655,7 @@ pub mod parameter {
     pub mod prelude {
         //! Prelude: re-export of most commonly used traits, structs and macros in this crate.
 
-        pub use super::{Parameter, ParameterId};
+        pub use super::{Parameter, ParameterId, ParameterSubsystem};
     }
 
     #[cfg(test)]
@@ -813,6 +929,12 @@ mod model {
             #[skip_try_from]
             String,
         ),
+        /// Instruction of the transaction failed or was rejected
+        ///
+        /// Unlike [`Self::InstructionFailed`] it tells the position of the offending instruction in the transaction
+        TransactionInstructionFailed(
+            #[cfg_attr(feature = "std", source)] isi::error::IndexedInstructionError,
+        ),
     }
 
     /// Log level for reading from environment and (de)serializing
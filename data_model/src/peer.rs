@@ -55,6 +55,30 @@ mod model {
         /// Peer Identification.
         pub id: PeerId,
     }
+
+    /// Ordering of the peers which defines their roles in the current round of consensus.
+    ///
+    /// The first peer is the leader.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{peers:?}")]
+    #[ffi_type]
+    pub struct Topology {
+        /// Peers in the consensus order.
+        pub peers: Vec<PeerId>,
+    }
 }
 
 impl PeerId {
@@ -109,6 +133,26 @@ impl Borrow<PublicKey> for PeerId {
     }
 }
 
+impl Topology {
+    /// Construct [`Topology`] given `peers` in the consensus order.
+    #[inline]
+    pub const fn new(peers: Vec<PeerId>) -> Self {
+        Self { peers }
+    }
+
+    /// Peers in the consensus order.
+    #[inline]
+    pub fn peers(&self) -> &[PeerId] {
+        &self.peers
+    }
+
+    /// Leader of the current round, [`None`] if the topology is empty.
+    #[inline]
+    pub fn leader(&self) -> Option<&PeerId> {
+        self.peers.first()
+    }
+}
+
 impl Registered for Peer {
     type With = Self;
 }
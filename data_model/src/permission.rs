@@ -10,7 +10,13 @@ use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
-use crate::name::Name;
+use crate::{
+    account::AccountId,
+    asset::{AssetDefinitionId, AssetId},
+    domain::DomainId,
+    name::Name,
+    trigger::TriggerId,
+};
 
 /// Collection of [`Token`]s
 pub type Permissions = BTreeSet<Permission>;
@@ -97,7 +103,363 @@ impl Permission {
     }
 }
 
+/// Declares constructors of [`Permission`]s known to the default executor.
+///
+/// The payload of each permission mirrors the serialized form of the executor's token type,
+/// i.e. `null` for a token without fields and an object with a single field otherwise.
+macro_rules! default_permissions {
+    ($(
+        $(#[$meta:meta])*
+        $constructor:ident => $permission:ident $(($field:ident: $ty:ty))?
+    ),+ $(,)?) => {
+        impl Permission { $(
+            $(#[$meta])*
+            pub fn $constructor($($field: $ty)?) -> Self {
+                Self::new(
+                    stringify!($permission)
+                        .parse()
+                        .expect("Permission name is a valid `Name`"),
+                    default_permissions!(@payload $($field)?),
+                )
+            }
+        )+ }
+    };
+    (@payload) => {
+        serde_json::Value::Null
+    };
+    (@payload $field:ident) => {
+        serde_json::json!({ stringify!($field): $field })
+    };
+}
+
+default_permissions! {
+    /// Permission to unregister any peer.
+    can_unregister_any_peer => CanUnregisterAnyPeer,
+
+    /// Permission to unregister the domain.
+    can_unregister_domain => CanUnregisterDomain(domain_id: DomainId),
+    /// Permission to set metadata of the domain.
+    can_set_key_value_in_domain => CanSetKeyValueInDomain(domain_id: DomainId),
+    /// Permission to remove metadata of the domain.
+    can_remove_key_value_in_domain => CanRemoveKeyValueInDomain(domain_id: DomainId),
+    /// Permission to register accounts in the domain.
+    can_register_account_in_domain => CanRegisterAccountInDomain(domain_id: DomainId),
+    /// Permission to register asset definitions in the domain.
+    can_register_asset_definition_in_domain => CanRegisterAssetDefinitionInDomain(domain_id: DomainId),
+
+    /// Permission to unregister the account.
+    can_unregister_account => CanUnregisterAccount(account_id: AccountId),
+    /// Permission to add public keys to the account.
+    can_mint_user_public_keys => CanMintUserPublicKeys(account_id: AccountId),
+    /// Permission to remove public keys from the account.
+    can_burn_user_public_keys => CanBurnUserPublicKeys(account_id: AccountId),
+    /// Permission to change signature check conditions of the account.
+    can_mint_user_signature_check_conditions => CanMintUserSignatureCheckConditions(account_id: AccountId),
+    /// Permission to set metadata of the account.
+    can_set_key_value_in_account => CanSetKeyValueInAccount(account_id: AccountId),
+    /// Permission to remove metadata of the account.
+    can_remove_key_value_in_account => CanRemoveKeyValueInAccount(account_id: AccountId),
+
+    /// Permission to unregister the asset definition.
+    can_unregister_asset_definition => CanUnregisterAssetDefinition(asset_definition_id: AssetDefinitionId),
+    /// Permission to set metadata of the asset definition.
+    can_set_key_value_in_asset_definition => CanSetKeyValueInAssetDefinition(asset_definition_id: AssetDefinitionId),
+    /// Permission to remove metadata of the asset definition.
+    can_remove_key_value_in_asset_definition => CanRemoveKeyValueInAssetDefinition(asset_definition_id: AssetDefinitionId),
+
+    /// Permission to register assets of the asset definition.
+    can_register_asset_with_definition => CanRegisterAssetWithDefinition(asset_definition_id: AssetDefinitionId),
+    /// Permission to unregister assets of the asset definition.
+    can_unregister_asset_with_definition => CanUnregisterAssetWithDefinition(asset_definition_id: AssetDefinitionId),
+    /// Permission to unregister the asset.
+    can_unregister_user_asset => CanUnregisterUserAsset(asset_id: AssetId),
+    /// Permission to burn assets of the asset definition.
+    can_burn_asset_with_definition => CanBurnAssetWithDefinition(asset_definition_id: AssetDefinitionId),
+    /// Permission to mint assets of the asset definition.
+    can_mint_asset_with_definition => CanMintAssetWithDefinition(asset_definition_id: AssetDefinitionId),
+    /// Permission to mint the asset.
+    can_mint_user_asset => CanMintUserAsset(asset_id: AssetId),
+    /// Permission to burn the asset.
+    can_burn_user_asset => CanBurnUserAsset(asset_id: AssetId),
+    /// Permission to transfer assets of the asset definition.
+    can_transfer_asset_with_definition => CanTransferAssetWithDefinition(asset_definition_id: AssetDefinitionId),
+    /// Permission to transfer the asset.
+    can_transfer_user_asset => CanTransferUserAsset(asset_id: AssetId),
+    /// Permission to set metadata of the asset.
+    can_set_key_value_in_user_asset => CanSetKeyValueInUserAsset(asset_id: AssetId),
+    /// Permission to remove metadata of the asset.
+    can_remove_key_value_in_user_asset => CanRemoveKeyValueInUserAsset(asset_id: AssetId),
+
+    /// Permission to grant [`Self::can_create_parameters`].
+    can_grant_permission_to_create_parameters => CanGrantPermissionToCreateParameters,
+    /// Permission to revoke [`Self::can_create_parameters`].
+    can_revoke_permission_to_create_parameters => CanRevokePermissionToCreateParameters,
+    /// Permission to create new configuration parameters.
+    can_create_parameters => CanCreateParameters,
+    /// Permission to grant [`Self::can_set_parameters`].
+    can_grant_permission_to_set_parameters => CanGrantPermissionToSetParameters,
+    /// Permission to revoke [`Self::can_set_parameters`].
+    can_revoke_permission_to_set_parameters => CanRevokePermissionToSetParameters,
+    /// Permission to set configuration parameters.
+    can_set_parameters => CanSetParameters,
+
+    /// Permission to unregister any role.
+    can_unregister_any_role => CanUnregisterAnyRole,
+
+    /// Permission to register triggers on behalf of the account.
+    can_register_user_trigger => CanRegisterUserTrigger(account_id: AccountId),
+    /// Permission to execute the trigger.
+    can_execute_user_trigger => CanExecuteUserTrigger(trigger_id: TriggerId),
+    /// Permission to unregister triggers of the account.
+    can_unregister_user_trigger => CanUnregisterUserTrigger(account_id: AccountId),
+    /// Permission to increase repetitions of the trigger.
+    can_mint_user_trigger => CanMintUserTrigger(trigger_id: TriggerId),
+    /// Permission to decrease repetitions of the trigger.
+    can_burn_user_trigger => CanBurnUserTrigger(trigger_id: TriggerId),
+    /// Permission to set metadata of the trigger.
+    can_set_key_value_in_trigger => CanSetKeyValueInTrigger(trigger_id: TriggerId),
+    /// Permission to remove metadata of the trigger.
+    can_remove_key_value_in_trigger => CanRemoveKeyValueInTrigger(trigger_id: TriggerId),
+
+    /// Permission to upgrade the executor.
+    can_upgrade_executor => CanUpgradeExecutor,
+}
+
 pub mod prelude {
     //! The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub use super::{Permission, PermissionId};
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn default_permissions_match_executor_payload() {
+        let domain_id: DomainId = "wonderland".parse().unwrap();
+        let account_id: AccountId =
+            "ed0120EDF6D7B52C7032D03AEC696F2068BD53101528F3C7B6081BFF05A1662D7FC245@wonderland"
+                .parse()
+                .unwrap();
+        let asset_definition_id: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let asset_id = AssetId::new(asset_definition_id.clone(), account_id.clone());
+        let trigger_id: TriggerId = "mint_rose".parse().unwrap();
+
+        let domain: &str = &format!(r#"{{"domain_id":"{domain_id}"}}"#);
+        let account: &str = &format!(r#"{{"account_id":"{account_id}"}}"#);
+        let asset_definition: &str =
+            &format!(r#"{{"asset_definition_id":"{asset_definition_id}"}}"#);
+        let asset: &str = &format!(r#"{{"asset_id":"{asset_id}"}}"#);
+        let trigger: &str = &format!(r#"{{"trigger_id":"{trigger_id}"}}"#);
+
+        let cases = [
+            (
+                Permission::can_unregister_any_peer(),
+                "CanUnregisterAnyPeer",
+                "null",
+            ),
+            (
+                Permission::can_unregister_domain(domain_id.clone()),
+                "CanUnregisterDomain",
+                domain,
+            ),
+            (
+                Permission::can_set_key_value_in_domain(domain_id.clone()),
+                "CanSetKeyValueInDomain",
+                domain,
+            ),
+            (
+                Permission::can_remove_key_value_in_domain(domain_id.clone()),
+                "CanRemoveKeyValueInDomain",
+                domain,
+            ),
+            (
+                Permission::can_register_account_in_domain(domain_id.clone()),
+                "CanRegisterAccountInDomain",
+                domain,
+            ),
+            (
+                Permission::can_register_asset_definition_in_domain(domain_id),
+                "CanRegisterAssetDefinitionInDomain",
+                domain,
+            ),
+            (
+                Permission::can_unregister_account(account_id.clone()),
+                "CanUnregisterAccount",
+                account,
+            ),
+            (
+                Permission::can_mint_user_public_keys(account_id.clone()),
+                "CanMintUserPublicKeys",
+                account,
+            ),
+            (
+                Permission::can_burn_user_public_keys(account_id.clone()),
+                "CanBurnUserPublicKeys",
+                account,
+            ),
+            (
+                Permission::can_mint_user_signature_check_conditions(account_id.clone()),
+                "CanMintUserSignatureCheckConditions",
+                account,
+            ),
+            (
+                Permission::can_set_key_value_in_account(account_id.clone()),
+                "CanSetKeyValueInAccount",
+                account,
+            ),
+            (
+                Permission::can_remove_key_value_in_account(account_id.clone()),
+                "CanRemoveKeyValueInAccount",
+                account,
+            ),
+            (
+                Permission::can_unregister_asset_definition(asset_definition_id.clone()),
+                "CanUnregisterAssetDefinition",
+                asset_definition,
+            ),
+            (
+                Permission::can_set_key_value_in_asset_definition(asset_definition_id.clone()),
+                "CanSetKeyValueInAssetDefinition",
+                asset_definition,
+            ),
+            (
+                Permission::can_remove_key_value_in_asset_definition(asset_definition_id.clone()),
+                "CanRemoveKeyValueInAssetDefinition",
+                asset_definition,
+            ),
+            (
+                Permission::can_register_asset_with_definition(asset_definition_id.clone()),
+                "CanRegisterAssetWithDefinition",
+                asset_definition,
+            ),
+            (
+                Permission::can_unregister_asset_with_definition(asset_definition_id.clone()),
+                "CanUnregisterAssetWithDefinition",
+                asset_definition,
+            ),
+            (
+                Permission::can_unregister_user_asset(asset_id.clone()),
+                "CanUnregisterUserAsset",
+                asset,
+            ),
+            (
+                Permission::can_burn_asset_with_definition(asset_definition_id.clone()),
+                "CanBurnAssetWithDefinition",
+                asset_definition,
+            ),
+            (
+                Permission::can_mint_asset_with_definition(asset_definition_id.clone()),
+                "CanMintAssetWithDefinition",
+                asset_definition,
+            ),
+            (
+                Permission::can_mint_user_asset(asset_id.clone()),
+                "CanMintUserAsset",
+                asset,
+            ),
+            (
+                Permission::can_burn_user_asset(asset_id.clone()),
+                "CanBurnUserAsset",
+                asset,
+            ),
+            (
+                Permission::can_transfer_asset_with_definition(asset_definition_id),
+                "CanTransferAssetWithDefinition",
+                asset_definition,
+            ),
+            (
+                Permission::can_transfer_user_asset(asset_id.clone()),
+                "CanTransferUserAsset",
+                asset,
+            ),
+            (
+                Permission::can_set_key_value_in_user_asset(asset_id.clone()),
+                "CanSetKeyValueInUserAsset",
+                asset,
+            ),
+            (
+                Permission::can_remove_key_value_in_user_asset(asset_id),
+                "CanRemoveKeyValueInUserAsset",
+                asset,
+            ),
+            (
+                Permission::can_grant_permission_to_create_parameters(),
+                "CanGrantPermissionToCreateParameters",
+                "null",
+            ),
+            (
+                Permission::can_revoke_permission_to_create_parameters(),
+                "CanRevokePermissionToCreateParameters",
+                "null",
+            ),
+            (
+                Permission::can_create_parameters(),
+                "CanCreateParameters",
+                "null",
+            ),
+            (
+                Permission::can_grant_permission_to_set_parameters(),
+                "CanGrantPermissionToSetParameters",
+                "null",
+            ),
+            (
+                Permission::can_revoke_permission_to_set_parameters(),
+                "CanRevokePermissionToSetParameters",
+                "null",
+            ),
+            (Permission::can_set_parameters(), "CanSetParameters", "null"),
+            (
+                Permission::can_unregister_any_role(),
+                "CanUnregisterAnyRole",
+                "null",
+            ),
+            (
+                Permission::can_register_user_trigger(account_id.clone()),
+                "CanRegisterUserTrigger",
+                account,
+            ),
+            (
+                Permission::can_execute_user_trigger(trigger_id.clone()),
+                "CanExecuteUserTrigger",
+                trigger,
+            ),
+            (
+                Permission::can_unregister_user_trigger(account_id),
+                "CanUnregisterUserTrigger",
+                account,
+            ),
+            (
+                Permission::can_mint_user_trigger(trigger_id.clone()),
+                "CanMintUserTrigger",
+                trigger,
+            ),
+            (
+                Permission::can_burn_user_trigger(trigger_id.clone()),
+                "CanBurnUserTrigger",
+                trigger,
+            ),
+            (
+                Permission::can_set_key_value_in_trigger(trigger_id.clone()),
+                "CanSetKeyValueInTrigger",
+                trigger,
+            ),
+            (
+                Permission::can_remove_key_value_in_trigger(trigger_id),
+                "CanRemoveKeyValueInTrigger",
+                trigger,
+            ),
+            (
+                Permission::can_upgrade_executor(),
+                "CanUpgradeExecutor",
+                "null",
+            ),
+        ];
+
+        for (permission, name, payload) in cases {
+            assert_eq!(permission.id().name().as_ref(), name);
+            assert_eq!(permission.payload().to_string(), payload, "{name}");
+        }
+    }
+}
@@ -207,6 +207,13 @@ mod model {
         FindRoleByRoleId(FindRoleByRoleId),
         FindRolesByAccountId(FindRolesByAccountId),
         FindAllParameters(FindAllParameters),
+        FindParametersBySubsystem(FindParametersBySubsystem),
+        FindBlockByHeight(FindBlockByHeight),
+        FindBlockByHash(FindBlockByHash),
+        FindTopology(FindTopology),
+        FindTotalAssetQuantityByDomainIdAndAssetDefinitionId(
+            FindTotalAssetQuantityByDomainIdAndAssetDefinitionId,
+        ),
     }
 
     /// Sized container for all possible [`Query::Output`]s
@@ -235,6 +242,7 @@ mod model {
         BlockHeader(BlockHeader),
         Block(crate::block::SignedBlock),
         ExecutorDataModel(crate::executor::ExecutorDataModel),
+        Topology(crate::peer::Topology),
 
         Vec(
             #[skip_from]
@@ -414,13 +422,16 @@ impl_queries! {
     FindAssetsByDomainIdAndAssetDefinitionId => Vec<crate::asset::Asset>,
     FindAssetQuantityById => Numeric,
     FindTotalAssetQuantityByAssetDefinitionId => Numeric,
+    FindTotalAssetQuantityByDomainIdAndAssetDefinitionId => Numeric,
     FindAssetKeyValueByIdAndKey => MetadataValueBox,
     FindAssetDefinitionKeyValueByIdAndKey => MetadataValueBox,
     FindAllDomains => Vec<crate::domain::Domain>,
     FindDomainById => crate::domain::Domain,
     FindDomainKeyValueByIdAndKey => MetadataValueBox,
     FindAllPeers => Vec<crate::peer::Peer>,
+    FindTopology => crate::peer::Topology,
     FindAllParameters => Vec<crate::parameter::Parameter>,
+    FindParametersBySubsystem => Vec<crate::parameter::Parameter>,
     FindAllActiveTriggerIds => Vec<crate::trigger::TriggerId>,
     FindTriggerById => crate::trigger::Trigger,
     FindTriggerKeyValueByIdAndKey => MetadataValueBox,
@@ -431,6 +442,8 @@ impl_queries! {
     FindAllBlocks => Vec<SignedBlock>,
     FindAllBlockHeaders => Vec<crate::block::BlockHeader>,
     FindBlockHeaderByHash => crate::block::BlockHeader,
+    FindBlockByHeight => SignedBlock,
+    FindBlockByHash => SignedBlock,
     FindExecutorDataModel => crate::executor::ExecutorDataModel
 }
 
@@ -455,6 +468,7 @@ impl core::fmt::Display for QueryOutputBox {
             QueryOutputBox::Numeric(v) => core::fmt::Display::fmt(&v, f),
             QueryOutputBox::LimitedMetadata(v) => core::fmt::Display::fmt(&v, f),
             QueryOutputBox::ExecutorDataModel(v) => core::fmt::Display::fmt(&v, f),
+            QueryOutputBox::Topology(v) => core::fmt::Display::fmt(&v, f),
 
             QueryOutputBox::Vec(v) => {
                 // TODO: Remove so we can derive.
@@ -895,6 +909,19 @@ pub mod asset {
             pub id: AssetDefinitionId,
         }
 
+        /// [`FindTotalAssetQuantityByDomainIdAndAssetDefinitionId`] Iroha Query gets [`DomainId`] and [`AssetDefinitionId`]
+        /// as inputs and finds the sum of [`Asset::quantity`] over all accounts of the [`Domain`].
+        /// Returns zero if no account of the [`Domain`] holds the asset.
+        #[derive(Display)]
+        #[display(fmt = "Find total quantity of the `{asset_definition_id}` asset under the `{domain_id}` domain")]
+        #[ffi_type]
+        pub struct FindTotalAssetQuantityByDomainIdAndAssetDefinitionId {
+            /// `Id` of the domain whose accounts are summed over.
+            pub domain_id: DomainId,
+            /// [`AssetDefinitionId`] of the asset to find total quantity of.
+            pub asset_definition_id: AssetDefinitionId,
+        }
+
         /// [`FindAssetKeyValueByIdAndKey`] Iroha Query gets [`AssetId`] and key as input and finds [`MetadataValue`]
         /// of the key-value pair stored in this asset.
         #[derive(Display)]
@@ -928,6 +955,7 @@ pub mod asset {
             FindAssetQuantityById, FindAssetsByAccountId, FindAssetsByAssetDefinitionId,
             FindAssetsByDomainId, FindAssetsByDomainIdAndAssetDefinitionId, FindAssetsByName,
             FindTotalAssetQuantityByAssetDefinitionId,
+            FindTotalAssetQuantityByDomainIdAndAssetDefinitionId,
         };
     }
 }
@@ -1000,11 +1028,17 @@ pub mod peer {
         #[display(fmt = "Find all peers")]
         #[ffi_type]
         pub struct FindAllPeers;
+
+        /// [`FindTopology`] Iroha Query finds the ordering of peers in the current round of consensus.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find topology")]
+        #[ffi_type]
+        pub struct FindTopology;
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::FindAllPeers;
+        pub use super::{FindAllPeers, FindTopology};
     }
 }
 
@@ -1016,6 +1050,8 @@ pub mod executor {
 
     use derive_more::Display;
 
+    use crate::parameter::ParameterSubsystem;
+
     queries! {
         /// [`FindExecutorDataModel`] Iroha Query finds the data model of the current executor.
         #[derive(Copy, Display)]
@@ -1028,11 +1064,22 @@ pub mod executor {
         #[display(fmt = "Find all peers parameters")]
         #[ffi_type]
         pub struct FindAllParameters;
+
+        /// [`FindParametersBySubsystem`] Iroha Query finds the configuration parameters of the given [`ParameterSubsystem`].
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find `{subsystem}` parameters")]
+        #[repr(transparent)]
+        // SAFETY: `FindParametersBySubsystem` has no trap representation in `ParameterSubsystem`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindParametersBySubsystem {
+            /// [`ParameterSubsystem`] configured by the parameters.
+            pub subsystem: ParameterSubsystem,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAllParameters, FindExecutorDataModel};
+        pub use super::{FindAllParameters, FindExecutorDataModel, FindParametersBySubsystem};
     }
 }
 
@@ -1198,11 +1245,36 @@ pub mod block {
             /// Block hash.
             pub hash: HashOf<SignedBlock>,
         }
+
+        /// [`FindBlockByHeight`] Iroha Query finds committed block by its height
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find block with height `{height}`")]
+        #[repr(transparent)]
+        // SAFETY: `FindBlockByHeight` has no trap representation in `u64`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindBlockByHeight {
+            /// Block height.
+            pub height: u64,
+        }
+
+        /// [`FindBlockByHash`] Iroha Query finds committed block by its hash
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find block with `{hash}` hash")]
+        #[repr(transparent)]
+        // SAFETY: `FindBlockByHash` has no trap representation in `HashOf<SignedBlock>`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindBlockByHash {
+            /// Block hash.
+            pub hash: HashOf<SignedBlock>,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAllBlockHeaders, FindAllBlocks, FindBlockHeaderByHash};
+        pub use super::{
+            FindAllBlockHeaders, FindAllBlocks, FindBlockByHash, FindBlockByHeight,
+            FindBlockHeaderByHash,
+        };
     }
 }
 
@@ -1422,6 +1494,23 @@ pub mod http {
             }
             .into()
         }
+
+        /// Consumes self and returns a signed [`ClientQueryBuilder`]
+        /// signed with provided [`Signer`](iroha_crypto::Signer).
+        ///
+        /// # Errors
+        /// Fails if `signer` fails to produce a signature
+        #[inline]
+        pub fn sign_with(
+            self,
+            signer: &dyn iroha_crypto::Signer,
+        ) -> Result<SignedQuery, iroha_crypto::Error> {
+            Ok(SignedQueryV1 {
+                signature: SignatureOf::with_signer(signer, &self.payload)?,
+                payload: self.payload,
+            }
+            .into())
+        }
     }
 
     pub mod prelude {
@@ -1488,6 +1577,8 @@ pub mod error {
             FetchSizeTooBig,
             /// Some of the specified parameters (filter/pagination/fetch_size/sorting) are not applicable to singular queries
             InvalidSingularParameters,
+            /// Numeric overflow occurred while computing the query result
+            Overflow,
         }
 
         /// Type assertion error
@@ -1535,6 +1626,8 @@ pub mod error {
             Parameter(ParameterId),
             /// Failed to find public key: `{0}`
             PublicKey(PublicKey),
+            /// Block with height `{0}` not found
+            BlockHeight(u64),
         }
     }
 }
@@ -195,6 +195,82 @@ impl TransactionLimits {
             max_wasm_size_bytes,
         }
     }
+
+    /// Limits which accept transactions of any size.
+    ///
+    /// Intended for tests and benchmarks only, a network running with these
+    /// limits can be flooded with arbitrarily large transactions.
+    pub const fn unlimited() -> Self {
+        Self::new(u64::MAX, u64::MAX)
+    }
+
+    /// Limits a production network runs with unless configured otherwise:
+    /// up to 4096 instructions or a wasm binary of up to 4 MiB per transaction.
+    pub const fn mainnet_defaults() -> Self {
+        Self::new(2_u64.pow(12), 4 * 2_u64.pow(20))
+    }
+
+    /// Construct [`TransactionLimitsBuilder`] starting from [`Self::mainnet_defaults`]
+    pub const fn builder() -> TransactionLimitsBuilder {
+        TransactionLimitsBuilder::new()
+    }
+}
+
+/// Convenience tool for constructing [`TransactionLimits`]
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct TransactionLimitsBuilder {
+    limits: TransactionLimits,
+}
+
+/// Error associated with [`TransactionLimitsBuilder`]
+#[derive(Debug, Display, Copy, Clone, PartialEq, Eq)]
+pub enum TransactionLimitsBuilderError {
+    /// Maximum number of instructions must be positive, otherwise no transaction would be accepted
+    #[display(fmt = "Maximum number of instructions must be positive")]
+    ZeroInstructionNumber,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionLimitsBuilderError {}
+
+impl Default for TransactionLimitsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransactionLimitsBuilder {
+    /// Construct [`Self`] starting from [`TransactionLimits::mainnet_defaults`]
+    pub const fn new() -> Self {
+        Self {
+            limits: TransactionLimits::mainnet_defaults(),
+        }
+    }
+
+    /// Set maximum number of instructions per transaction
+    pub const fn max_instruction_number(mut self, max_instruction_number: u64) -> Self {
+        self.limits.max_instruction_number = max_instruction_number;
+        self
+    }
+
+    /// Set maximum size of wasm binary in bytes
+    pub const fn max_wasm_size_bytes(mut self, max_wasm_size_bytes: u64) -> Self {
+        self.limits.max_wasm_size_bytes = max_wasm_size_bytes;
+        self
+    }
+
+    /// Construct [`TransactionLimits`]
+    ///
+    /// # Errors
+    /// - maximum number of instructions is zero
+    pub const fn build(self) -> Result<TransactionLimits, TransactionLimitsBuilderError> {
+        if self.limits.max_instruction_number == 0 {
+            return Err(TransactionLimitsBuilderError::ZeroInstructionNumber);
+        }
+
+        Ok(self.limits)
+    }
 }
 
 impl<A: Instruction> FromIterator<A> for Executable {
@@ -318,6 +394,25 @@ impl SignedTransaction {
         }
         .into()
     }
+
+    /// Sign transaction with provided [`Signer`](iroha_crypto::Signer).
+    ///
+    /// # Errors
+    /// Fails if `signer` fails to produce a signature
+    pub fn sign_with(
+        self,
+        signer: &dyn iroha_crypto::Signer,
+    ) -> Result<SignedTransaction, iroha_crypto::Error> {
+        let SignedTransaction::V1(mut tx) = self;
+        let signature = iroha_crypto::SignatureOf::with_signer(signer, &tx.payload)?;
+        tx.signature = signature;
+
+        Ok(SignedTransactionV1 {
+            payload: tx.payload,
+            signature: tx.signature,
+        }
+        .into())
+    }
 }
 
 #[cfg(feature = "transparent_api")]
@@ -495,6 +590,62 @@ pub mod error {
             pub reason: String,
         }
 
+        /// Instruction #{index} of the transaction failed
+        ///
+        /// Transactions are atomic, so the changes made before the failure
+        /// were rolled back together with the whole transaction.
+        #[derive(
+            Debug,
+            displaydoc::Display,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[ignore_extra_doc_attributes]
+        #[cfg_attr(feature = "std", derive(thiserror::Error))]
+        #[ffi_type]
+        pub struct FailedInstruction {
+            /// Position of the failed instruction in the transaction
+            pub index: u32,
+            /// Error which happened during execution
+            #[cfg_attr(feature = "std", source)]
+            pub error: crate::isi::error::InstructionExecutionError,
+            /// Events emitted by the changes applied before the failure, i.e. what was rolled back
+            pub rolled_back: Vec<crate::events::data::DataEvent>,
+        }
+
+        /// Transaction creation time differs from the block time by {skew_ms} ms, but the max clock skew is {max_clock_skew_ms} ms
+        #[derive(
+            Debug,
+            displaydoc::Display,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[cfg_attr(feature = "std", derive(thiserror::Error))]
+        #[ffi_type]
+        pub struct ClockSkewError {
+            /// Difference between the transaction creation time and the block time in milliseconds
+            pub skew_ms: u64,
+            /// Max allowed difference in milliseconds
+            pub max_clock_skew_ms: u64,
+        }
+
         /// Transaction was rejected because execution of `WebAssembly` binary failed
         #[derive(
             Debug,
@@ -561,6 +712,10 @@ pub mod error {
             InstructionExecution(#[cfg_attr(feature = "std", source)] InstructionExecutionFail),
             /// Failure in WebAssembly execution
             WasmExecution(#[cfg_attr(feature = "std", source)] WasmExecutionFail),
+            /// Execution of one of the transaction instructions failed
+            InstructionFailed(#[cfg_attr(feature = "std", source)] FailedInstruction),
+            /// Transaction creation time is too far from the block time
+            ClockSkew(#[cfg_attr(feature = "std", source)] ClockSkewError),
         }
     }
 
@@ -571,7 +726,11 @@ pub mod error {
                 Burn(_) => "burn",
                 Fail(_) => "fail",
                 Mint(_) => "mint",
+                Lock(_) => "lock",
+                Unlock(_) => "unlock",
                 Register(_) => "register",
+                RegisterMany(_) => "register many",
+                RegisterIfAbsent(_) => "register if absent",
                 Transfer(_) => "transfer",
                 Unregister(_) => "un-register",
                 SetKeyValue(_) => "set key-value pair",
@@ -580,6 +739,11 @@ pub mod error {
                 Revoke(_) => "revoke",
                 ExecuteTrigger(_) => "execute trigger",
                 SetParameter(_) => "set parameter",
+                SetParameterIf(_) => "set parameter if",
+                SetParameters(_) => "set parameters",
+                MintBatch(_) => "mint batch",
+                SetAccountMetadata(_) => "set account metadata",
+                MigrateAssetValueType(_) => "migrate asset value type",
                 NewParameter(_) => "new parameter",
                 Upgrade(_) => "upgrade",
                 Log(_) => "log",
@@ -604,7 +768,10 @@ pub mod error {
     pub mod prelude {
         //! The prelude re-exports most commonly used traits, structs and macros from this module.
 
-        pub use super::{InstructionExecutionFail, TransactionRejectionReason, WasmExecutionFail};
+        pub use super::{
+            ClockSkewError, FailedInstruction, InstructionExecutionFail,
+            TransactionRejectionReason, WasmExecutionFail,
+        };
     }
 }
 
@@ -751,6 +918,24 @@ mod http {
             }
             .into()
         }
+
+        /// Sign transaction with provided [`Signer`](iroha_crypto::Signer),
+        /// e.g. the one delegating to an external key storage.
+        ///
+        /// # Errors
+        /// Fails if `signer` fails to produce a signature
+        pub fn sign_with(
+            self,
+            signer: &dyn iroha_crypto::Signer,
+        ) -> Result<SignedTransaction, iroha_crypto::Error> {
+            let signature = SignatureOf::with_signer(signer, &self.payload)?;
+
+            Ok(SignedTransactionV1 {
+                payload: self.payload,
+                signature,
+            }
+            .into())
+        }
     }
 }
 
@@ -775,4 +960,37 @@ mod tests {
         let contract = WasmSmartContract::from_compiled(vec![0, 1, 2, 3, 4]);
         assert_eq!(format!("{contract:?}"), "WASM binary(len = 5)");
     }
+
+    #[test]
+    fn transaction_limits_presets() {
+        assert_eq!(
+            TransactionLimits::unlimited(),
+            TransactionLimits::new(u64::MAX, u64::MAX)
+        );
+        assert_eq!(
+            TransactionLimits::mainnet_defaults(),
+            TransactionLimits::new(4096, 4_194_304)
+        );
+        assert_eq!(
+            TransactionLimits::builder().build(),
+            Ok(TransactionLimits::mainnet_defaults())
+        );
+    }
+
+    #[test]
+    fn transaction_limits_builder() {
+        assert_eq!(
+            TransactionLimits::builder()
+                .max_instruction_number(1)
+                .max_wasm_size_bytes(0)
+                .build(),
+            Ok(TransactionLimits::new(1, 0))
+        );
+        assert_eq!(
+            TransactionLimits::builder()
+                .max_instruction_number(0)
+                .build(),
+            Err(TransactionLimitsBuilderError::ZeroInstructionNumber)
+        );
+    }
 }
@@ -30,18 +30,27 @@ pub trait Visit {
         visit_burn(&BurnBox),
         visit_fail(&Fail),
         visit_grant(&GrantBox),
+        visit_lock(&LockBox),
         visit_mint(&MintBox),
         visit_register(&RegisterBox),
+        visit_register_many(&RegisterManyBox),
+        visit_register_if_absent(&RegisterIfAbsentBox),
         visit_remove_key_value(&RemoveKeyValueBox),
         visit_revoke(&RevokeBox),
         visit_set_key_value(&SetKeyValueBox),
         visit_transfer(&TransferBox),
+        visit_unlock(&UnlockBox),
         visit_unregister(&UnregisterBox),
         visit_upgrade(&Upgrade),
 
         visit_execute_trigger(&ExecuteTrigger),
         visit_new_parameter(&NewParameter),
         visit_set_parameter(&SetParameter),
+        visit_set_parameter_if(&SetParameterIf),
+        visit_set_parameters(&SetParameters),
+        visit_mint_batch(&MintBatch),
+        visit_set_account_metadata(&SetAccountMetadata),
+        visit_migrate_asset_value_type(&MigrateAssetValueType),
         visit_log(&Log),
 
         // Visit QueryBox
@@ -57,7 +66,9 @@ pub trait Visit {
         visit_find_all_blocks(&FindAllBlocks),
         visit_find_all_domains(&FindAllDomains),
         visit_find_all_parameters(&FindAllParameters),
+        visit_find_parameters_by_subsystem(&FindParametersBySubsystem),
         visit_find_all_peers(&FindAllPeers),
+        visit_find_topology(&FindTopology),
         visit_find_executor_data_model(&FindExecutorDataModel),
         visit_find_all_role_ids(&FindAllRoleIds),
         visit_find_all_roles(&FindAllRoles),
@@ -73,12 +84,15 @@ pub trait Visit {
         visit_find_assets_by_domain_id_and_asset_definition_id(&FindAssetsByDomainIdAndAssetDefinitionId),
         visit_find_assets_by_name(&FindAssetsByName),
         visit_find_block_header_by_hash(&FindBlockHeaderByHash),
+        visit_find_block_by_height(&FindBlockByHeight),
+        visit_find_block_by_hash(&FindBlockByHash),
         visit_find_domain_by_id(&FindDomainById),
         visit_find_domain_key_value_by_id_and_key(&FindDomainKeyValueByIdAndKey),
         visit_find_permissions_by_account_id(&FindPermissionsByAccountId),
         visit_find_role_by_role_id(&FindRoleByRoleId),
         visit_find_roles_by_account_id(&FindRolesByAccountId),
         visit_find_total_asset_quantity_by_asset_definition_id(&FindTotalAssetQuantityByAssetDefinitionId),
+        visit_find_total_asset_quantity_by_domain_id_and_asset_definition_id(&FindTotalAssetQuantityByDomainIdAndAssetDefinitionId),
         visit_find_transaction_by_hash(&FindTransactionByHash),
         visit_find_transactions_by_account_id(&FindTransactionsByAccountId),
         visit_find_trigger_by_id(&FindTriggerById),
@@ -94,6 +108,16 @@ pub trait Visit {
         visit_register_role(&Register<Role>),
         visit_register_trigger(&Register<Trigger>),
 
+        // Visit RegisterManyBox
+        visit_register_many_domains(&RegisterMany<Domain>),
+        visit_register_many_accounts(&RegisterMany<Account>),
+        visit_register_many_asset_definitions(&RegisterMany<AssetDefinition>),
+
+        // Visit RegisterIfAbsentBox
+        visit_register_domain_if_absent(&RegisterIfAbsent<Domain>),
+        visit_register_account_if_absent(&RegisterIfAbsent<Account>),
+        visit_register_asset_definition_if_absent(&RegisterIfAbsent<AssetDefinition>),
+
         // Visit UnregisterBox
         visit_unregister_peer(&Unregister<Peer>),
         visit_unregister_domain(&Unregister<Domain>),
@@ -112,6 +136,12 @@ pub trait Visit {
         visit_burn_asset_numeric(&Burn<Numeric, Asset>),
         visit_burn_trigger_repetitions(&Burn<u32, Trigger>),
 
+        // Visit LockBox
+        visit_lock_asset_numeric(&Lock<Numeric, Asset>),
+
+        // Visit UnlockBox
+        visit_unlock_asset_numeric(&Unlock<Numeric, Asset>),
+
         // Visit TransferBox
         visit_transfer_asset_definition(&Transfer<Account, AssetDefinitionId, Account>),
         visit_transfer_asset_numeric(&Transfer<Asset, Numeric, Account>),
@@ -182,7 +212,9 @@ pub fn visit_query<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, qu
         visit_find_all_blocks(FindAllBlocks),
         visit_find_all_domains(FindAllDomains),
         visit_find_all_parameters(FindAllParameters),
+        visit_find_parameters_by_subsystem(FindParametersBySubsystem),
         visit_find_all_peers(FindAllPeers),
+        visit_find_topology(FindTopology),
         visit_find_executor_data_model(FindExecutorDataModel),
         visit_find_all_role_ids(FindAllRoleIds),
         visit_find_all_roles(FindAllRoles),
@@ -198,12 +230,15 @@ pub fn visit_query<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, qu
         visit_find_assets_by_domain_id_and_asset_definition_id(FindAssetsByDomainIdAndAssetDefinitionId),
         visit_find_assets_by_name(FindAssetsByName),
         visit_find_block_header_by_hash(FindBlockHeaderByHash),
+        visit_find_block_by_height(FindBlockByHeight),
+        visit_find_block_by_hash(FindBlockByHash),
         visit_find_domain_by_id(FindDomainById),
         visit_find_domain_key_value_by_id_and_key(FindDomainKeyValueByIdAndKey),
         visit_find_permissions_by_account_id(FindPermissionsByAccountId),
         visit_find_role_by_role_id(FindRoleByRoleId),
         visit_find_roles_by_account_id(FindRolesByAccountId),
         visit_find_total_asset_quantity_by_asset_definition_id(FindTotalAssetQuantityByAssetDefinitionId),
+        visit_find_total_asset_quantity_by_domain_id_and_asset_definition_id(FindTotalAssetQuantityByDomainIdAndAssetDefinitionId),
         visit_find_transaction_by_hash(FindTransactionByHash),
         visit_find_transactions_by_account_id(FindTransactionsByAccountId),
         visit_find_trigger_by_id(FindTriggerById),
@@ -236,6 +271,21 @@ pub fn visit_instruction<V: Visit + ?Sized>(
         InstructionBox::SetParameter(variant_value) => {
             visitor.visit_set_parameter(authority, variant_value)
         }
+        InstructionBox::SetParameterIf(variant_value) => {
+            visitor.visit_set_parameter_if(authority, variant_value)
+        }
+        InstructionBox::SetParameters(variant_value) => {
+            visitor.visit_set_parameters(authority, variant_value)
+        }
+        InstructionBox::MintBatch(variant_value) => {
+            visitor.visit_mint_batch(authority, variant_value)
+        }
+        InstructionBox::SetAccountMetadata(variant_value) => {
+            visitor.visit_set_account_metadata(authority, variant_value)
+        }
+        InstructionBox::MigrateAssetValueType(variant_value) => {
+            visitor.visit_migrate_asset_value_type(authority, variant_value)
+        }
         InstructionBox::ExecuteTrigger(variant_value) => {
             visitor.visit_execute_trigger(authority, variant_value)
         }
@@ -243,8 +293,15 @@ pub fn visit_instruction<V: Visit + ?Sized>(
         InstructionBox::Burn(variant_value) => visitor.visit_burn(authority, variant_value),
         InstructionBox::Fail(variant_value) => visitor.visit_fail(authority, variant_value),
         InstructionBox::Grant(variant_value) => visitor.visit_grant(authority, variant_value),
+        InstructionBox::Lock(variant_value) => visitor.visit_lock(authority, variant_value),
         InstructionBox::Mint(variant_value) => visitor.visit_mint(authority, variant_value),
         InstructionBox::Register(variant_value) => visitor.visit_register(authority, variant_value),
+        InstructionBox::RegisterMany(variant_value) => {
+            visitor.visit_register_many(authority, variant_value)
+        }
+        InstructionBox::RegisterIfAbsent(variant_value) => {
+            visitor.visit_register_if_absent(authority, variant_value)
+        }
         InstructionBox::RemoveKeyValue(variant_value) => {
             visitor.visit_remove_key_value(authority, variant_value)
         }
@@ -253,6 +310,7 @@ pub fn visit_instruction<V: Visit + ?Sized>(
             visitor.visit_set_key_value(authority, variant_value)
         }
         InstructionBox::Transfer(variant_value) => visitor.visit_transfer(authority, variant_value),
+        InstructionBox::Unlock(variant_value) => visitor.visit_unlock(authority, variant_value),
         InstructionBox::Unregister(variant_value) => {
             visitor.visit_unregister(authority, variant_value)
         }
@@ -278,6 +336,36 @@ pub fn visit_register<V: Visit + ?Sized>(
     }
 }
 
+pub fn visit_register_many<V: Visit + ?Sized>(
+    visitor: &mut V,
+    authority: &AccountId,
+    isi: &RegisterManyBox,
+) {
+    match isi {
+        RegisterManyBox::Domain(obj) => visitor.visit_register_many_domains(authority, obj),
+        RegisterManyBox::Account(obj) => visitor.visit_register_many_accounts(authority, obj),
+        RegisterManyBox::AssetDefinition(obj) => {
+            visitor.visit_register_many_asset_definitions(authority, obj)
+        }
+    }
+}
+
+pub fn visit_register_if_absent<V: Visit + ?Sized>(
+    visitor: &mut V,
+    authority: &AccountId,
+    isi: &RegisterIfAbsentBox,
+) {
+    match isi {
+        RegisterIfAbsentBox::Domain(obj) => visitor.visit_register_domain_if_absent(authority, obj),
+        RegisterIfAbsentBox::Account(obj) => {
+            visitor.visit_register_account_if_absent(authority, obj)
+        }
+        RegisterIfAbsentBox::AssetDefinition(obj) => {
+            visitor.visit_register_asset_definition_if_absent(authority, obj)
+        }
+    }
+}
+
 pub fn visit_unregister<V: Visit + ?Sized>(
     visitor: &mut V,
     authority: &AccountId,
@@ -310,6 +398,18 @@ pub fn visit_burn<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, isi
     }
 }
 
+pub fn visit_lock<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, isi: &LockBox) {
+    match isi {
+        LockBox::Asset(obj) => visitor.visit_lock_asset_numeric(authority, obj),
+    }
+}
+
+pub fn visit_unlock<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, isi: &UnlockBox) {
+    match isi {
+        UnlockBox::Asset(obj) => visitor.visit_unlock_asset_numeric(authority, obj),
+    }
+}
+
 pub fn visit_transfer<V: Visit + ?Sized>(
     visitor: &mut V,
     authority: &AccountId,
@@ -386,6 +486,8 @@ macro_rules! leaf_visitors {
 leaf_visitors! {
     // Instruction visitors
     visit_register_account(&Register<Account>),
+    visit_register_many_accounts(&RegisterMany<Account>),
+    visit_register_account_if_absent(&RegisterIfAbsent<Account>),
     visit_unregister_account(&Unregister<Account>),
     visit_set_account_key_value(&SetKeyValue<Account>),
     visit_remove_account_key_value(&RemoveKeyValue<Account>),
@@ -393,6 +495,8 @@ leaf_visitors! {
     visit_unregister_asset(&Unregister<Asset>),
     visit_mint_asset_numeric(&Mint<Numeric, Asset>),
     visit_burn_asset_numeric(&Burn<Numeric, Asset>),
+    visit_lock_asset_numeric(&Lock<Numeric, Asset>),
+    visit_unlock_asset_numeric(&Unlock<Numeric, Asset>),
     visit_transfer_asset_numeric(&Transfer<Asset, Numeric, Account>),
     visit_transfer_asset_store(&Transfer<Asset, Metadata, Account>),
     visit_set_asset_key_value(&SetKeyValue<Asset>),
@@ -400,11 +504,15 @@ leaf_visitors! {
     visit_set_trigger_key_value(&SetKeyValue<Trigger>),
     visit_remove_trigger_key_value(&RemoveKeyValue<Trigger>),
     visit_register_asset_definition(&Register<AssetDefinition>),
+    visit_register_many_asset_definitions(&RegisterMany<AssetDefinition>),
+    visit_register_asset_definition_if_absent(&RegisterIfAbsent<AssetDefinition>),
     visit_unregister_asset_definition(&Unregister<AssetDefinition>),
     visit_transfer_asset_definition(&Transfer<Account, AssetDefinitionId, Account>),
     visit_set_asset_definition_key_value(&SetKeyValue<AssetDefinition>),
     visit_remove_asset_definition_key_value(&RemoveKeyValue<AssetDefinition>),
     visit_register_domain(&Register<Domain>),
+    visit_register_many_domains(&RegisterMany<Domain>),
+    visit_register_domain_if_absent(&RegisterIfAbsent<Domain>),
     visit_unregister_domain(&Unregister<Domain>),
     visit_transfer_domain(&Transfer<Account, DomainId, Account>),
     visit_set_domain_key_value(&SetKeyValue<Domain>),
@@ -426,6 +534,11 @@ leaf_visitors! {
     visit_upgrade(&Upgrade),
     visit_new_parameter(&NewParameter),
     visit_set_parameter(&SetParameter),
+    visit_set_parameter_if(&SetParameterIf),
+    visit_set_parameters(&SetParameters),
+    visit_mint_batch(&MintBatch),
+    visit_set_account_metadata(&SetAccountMetadata),
+    visit_migrate_asset_value_type(&MigrateAssetValueType),
     visit_execute_trigger(&ExecuteTrigger),
     visit_fail(&Fail),
     visit_log(&Log),
@@ -443,7 +556,9 @@ leaf_visitors! {
     visit_find_all_blocks(&FindAllBlocks),
     visit_find_all_domains(&FindAllDomains),
     visit_find_all_parameters(&FindAllParameters),
+    visit_find_parameters_by_subsystem(&FindParametersBySubsystem),
     visit_find_all_peers(&FindAllPeers),
+    visit_find_topology(&FindTopology),
     visit_find_executor_data_model(&FindExecutorDataModel),
     visit_find_all_role_ids(&FindAllRoleIds),
     visit_find_all_roles(&FindAllRoles),
@@ -459,12 +574,15 @@ leaf_visitors! {
     visit_find_assets_by_domain_id_and_asset_definition_id(&FindAssetsByDomainIdAndAssetDefinitionId),
     visit_find_assets_by_name(&FindAssetsByName),
     visit_find_block_header_by_hash(&FindBlockHeaderByHash),
+    visit_find_block_by_height(&FindBlockByHeight),
+    visit_find_block_by_hash(&FindBlockByHash),
     visit_find_domain_by_id(&FindDomainById),
     visit_find_domain_key_value_by_id_and_key(&FindDomainKeyValueByIdAndKey),
     visit_find_permissions_by_account_id(&FindPermissionsByAccountId),
     visit_find_role_by_role_id(&FindRoleByRoleId),
     visit_find_roles_by_account_id(&FindRolesByAccountId),
     visit_find_total_asset_quantity_by_asset_definition_id(&FindTotalAssetQuantityByAssetDefinitionId),
+    visit_find_total_asset_quantity_by_domain_id_and_asset_definition_id(&FindTotalAssetQuantityByDomainIdAndAssetDefinitionId),
     visit_find_transaction_by_hash(&FindTransactionByHash),
     visit_find_transactions_by_account_id(&FindTransactionsByAccountId),
     visit_find_trigger_by_id(&FindTriggerById),
@@ -151,18 +151,46 @@ mod test {
     #[test]
     fn derive_getset() {
         assert_derive_ok!(
-            #[derive(Getters, Setters, MutGetters, CopyGetters)],
+            #[derive(Getters, Setters, MutGetters, CopyGetters, CowGetters)],
             DeriveAttrs {
                 derives: vec![
                     GetSetDerive::Getters,
                     GetSetDerive::Setters,
                     GetSetDerive::MutGetters,
                     GetSetDerive::CopyGetters,
+                    GetSetDerive::CowGetters,
                 ].into_iter().map(Derive::GetSet).collect(),
             }
         );
     }
 
+    #[test]
+    fn derive_getset_qualified() {
+        assert_derive_ok!(
+            #[derive(getset::Getters, iroha_ffi::getset::Setters, ::getset::MutGetters)],
+            DeriveAttrs {
+                derives: vec![
+                    GetSetDerive::Getters,
+                    GetSetDerive::Setters,
+                    GetSetDerive::MutGetters,
+                ].into_iter().map(Derive::GetSet).collect(),
+            }
+        );
+    }
+
+    #[test]
+    fn derive_getset_other_module() {
+        assert_derive_ok!(
+            #[derive(other::Getters, getset::other::Setters)],
+            DeriveAttrs {
+                derives: vec![
+                    "other :: Getters".to_string(),
+                    "getset :: other :: Setters".to_string(),
+                ].into_iter().map(Derive::Other).collect(),
+            }
+        );
+    }
+
     #[test]
     fn derive_unknown() {
         assert_derive_ok!(
@@ -16,25 +16,22 @@ pub enum GetSetDerive {
     Getters,
     MutGetters,
     CopyGetters,
+    DerefGetters,
+    CowGetters,
 }
 
 impl GetSetDerive {
     pub fn try_from_path(path: &syn::Path) -> Option<Self> {
         // try to be smart and handle two cases:
         // - bare attribute name (like `Getters`, when it's imported)
-        // - fully qualified path (like `getset::Getters`, when it's not imported)
-        let ident = if let Some(i) = path.get_ident() {
-            i.clone()
-        } else {
-            let mut segments = path.segments.iter();
-            if segments.len() == 2
-                && segments.next().unwrap().ident.to_string().as_str() == "getset"
-            {
-                segments.next().unwrap().ident.clone()
-            } else {
+        // - qualified path (like `getset::Getters` or re-exported `iroha_ffi::getset::Getters`, when it's not imported)
+        let mut segments = path.segments.iter().rev();
+        let ident = &segments.next()?.ident;
+        if let Some(module) = segments.next() {
+            if module.ident != "getset" {
                 return None;
             }
-        };
+        }
 
         ident.to_string().parse().ok()
     }
@@ -45,14 +42,139 @@ impl GetSetDerive {
             Self::Getters => GetSetGenMode::Get,
             Self::MutGetters => GetSetGenMode::GetMut,
             Self::CopyGetters => GetSetGenMode::GetCopy,
+            Self::DerefGetters => GetSetGenMode::GetDeref,
+            Self::CowGetters => GetSetGenMode::GetCow,
         }
     }
+
+    /// Whether the derive is only a marker understood by `iroha_ffi_derive`, with no derive macro behind it
+    pub fn is_ffi_marker(self) -> bool {
+        matches!(self, Self::DerefGetters | Self::CowGetters)
+    }
+}
+
+/// Flavour of a getter, requested with the `pinned` or `raw_ptr` option
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum GetterKind {
+    /// Getter generated by the `getset` crate
+    #[default]
+    Plain,
+    /// Getter taking `Pin<&Self>` and returning `Pin<&Field>`, requested with `pinned`
+    Pinned,
+    /// `unsafe` getter returning `NonNull<Field>`, requested with `raw_ptr`
+    RawPtr,
+}
+
+/// Flavour of a setter, requested with the `try` option
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetterKind {
+    /// Setter generated by the `getset` crate
+    #[default]
+    Plain,
+    /// Setter returning `Result<(), E>` of the `validate` function, requested with `try`
+    Try,
 }
 
 #[derive(Default, Debug, Eq, PartialEq, Clone)]
 pub struct GetSetOptions {
     pub visibility: Option<syn::Visibility>,
     pub with_prefix: bool,
+    /// Documentation of the generated accessor, given as `doc="..."`
+    pub doc: Option<String>,
+    pub getter: GetterKind,
+    pub setter: SetterKind,
+    /// Function validating the new value of a fallible setter, given as `validate=path::to::fn`
+    pub validate: Option<syn::Path>,
+    /// Error type returned by a fallible setter, given as `error=path::to::Error`
+    pub error: Option<syn::Type>,
+    /// Whether the accessor is marked `#[inline]`. Enabled for all accessors by the struct-level `inline`,
+    /// which can be opted out of with `no_inline`
+    pub inline: Option<bool>,
+}
+
+impl GetSetOptions {
+    /// Parse a single space-separated `part` of the options string, reporting errors at `span`
+    fn parse_part(&mut self, part: &str, span: Span) -> syn::Result<()> {
+        if part == "with_prefix" {
+            self.with_prefix = true;
+        } else if part == "try" {
+            self.setter = SetterKind::Try;
+        } else if part == "no_inline" {
+            self.inline = Some(false);
+        } else if part == "pinned" || part == "raw_ptr" {
+            let getter = if part == "pinned" {
+                GetterKind::Pinned
+            } else {
+                GetterKind::RawPtr
+            };
+            if self.getter != GetterKind::Plain && self.getter != getter {
+                return Err(syn::Error::new(
+                    span,
+                    "`raw_ptr` can't be combined with `pinned`",
+                ));
+            }
+            self.getter = getter;
+        } else if let Some(path) = part.strip_prefix("validate=") {
+            let Ok(path) = syn::parse_str::<syn::Path>(path) else {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "Failed to parse getset options at `{part}`: expected `validate=path::to::fn`"
+                    ),
+                ));
+            };
+            if self.validate.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    format!("Failed to parse getset options at {part}: duplicate validate"),
+                ));
+            }
+            self.validate = Some(path);
+        } else if let Some(ty) = part.strip_prefix("error=") {
+            let Ok(ty) = syn::parse_str::<syn::Type>(ty) else {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "Failed to parse getset options at `{part}`: expected `error=path::to::Error`"
+                    ),
+                ));
+            };
+            if self.error.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    format!("Failed to parse getset options at {part}: duplicate error"),
+                ));
+            }
+            self.error = Some(ty);
+        } else if let Some(doc) = part.strip_prefix("doc=") {
+            let Some(doc) = doc.strip_prefix('"').and_then(|doc| doc.strip_suffix('"')) else {
+                return Err(syn::Error::new(
+                    span,
+                    format!("Failed to parse getset options at `{part}`: expected `doc=\"...\"`"),
+                ));
+            };
+            if self.doc.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    format!("Failed to parse getset options at {part}: duplicate doc"),
+                ));
+            }
+            self.doc = Some(doc.to_owned());
+        } else if let Ok(vis) = syn::parse_str::<syn::Visibility>(part) {
+            if self.visibility.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    format!("Failed to parse getset options at {part}: duplicate visibility"),
+                ));
+            }
+            self.visibility = Some(vis);
+        } else {
+            return Err(syn::Error::new(span, format!("Failed to parse getset options at `{part}`: expected visibility, `with_prefix`, `try`, `validate=...`, `error=...`, `no_inline`, `pinned`, `raw_ptr` or `doc=\"...\"`")));
+        }
+
+        Ok(())
+    }
 }
 
 struct SpannedGetSetOptions {
@@ -69,20 +191,16 @@ impl syn::parse::Parse for SpannedGetSetOptions {
         let mut errors = Vec::new();
 
         let lit = input.parse::<syn::LitStr>()?;
-        for part in lit.value().split(' ') {
-            if part == "with_prefix" {
-                result.with_prefix = true;
-            } else if let Ok(vis) = syn::parse_str::<syn::Visibility>(part) {
-                if result.visibility.is_none() {
-                    result.visibility = Some(vis);
-                } else {
-                    errors.push(syn::Error::new(
-                        lit.span(),
-                        format!("Failed to parse getset options at {part}: duplicate visibility",),
-                    ));
-                }
-            } else {
-                errors.push(syn::Error::new(lit.span(), format!("Failed to parse getset options at `{part}`: expected visibility or `with_prefix`")));
+        let value = lit.value();
+        let parts = split_options(&value).map_err(|part| {
+            syn::Error::new(
+                part_span(&lit, &value, part),
+                format!("Failed to parse getset options at `{part}`: unterminated quote"),
+            )
+        })?;
+        for part in parts {
+            if let Err(error) = result.parse_part(part, part_span(&lit, &value, part)) {
+                errors.push(error);
             }
         }
 
@@ -104,18 +222,112 @@ impl syn::parse::Parse for SpannedGetSetOptions {
     }
 }
 
+/// Split the getset options string on spaces, keeping quoted substrings (e.g. `doc="..."`)
+/// and parenthesized ones (e.g. `pub(in some::path)`) intact. Empty fragments (e.g. from a double space) are skipped.
+///
+/// On unterminated quote the offending part is returned as an error
+fn split_options(value: &str) -> Result<Vec<&str>, &str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut depth = 0_usize;
+
+    for (idx, c) in value.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '(' if !in_quotes => depth += 1,
+            ')' if !in_quotes => depth = depth.saturating_sub(1),
+            ' ' if !in_quotes && depth == 0 => {
+                parts.push(&value[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if in_quotes {
+        return Err(&value[start..]);
+    }
+    parts.push(&value[start..]);
+    parts.retain(|part| !part.is_empty());
+
+    Ok(parts)
+}
+
+/// Span of the `part` of the options string `value` parsed from `lit`.
+///
+/// Falls back to the span of the whole literal if the compiler doesn't support sub-spans
+/// or the literal is written in a form whose offsets can't be mapped (raw strings, unicode escapes etc.)
+fn part_span(lit: &syn::LitStr, value: &str, part: &str) -> Span {
+    let start = part.as_ptr() as usize - value.as_ptr() as usize;
+    let token = lit.token();
+    let repr = token.to_string();
+
+    literal_source_offset(&repr, start)
+        .zip(literal_source_offset(&repr, start + part.len()))
+        .and_then(|(start, end)| token.subspan(start..end))
+        .unwrap_or_else(|| lit.span())
+}
+
+/// Offset in the source `repr` of a string literal corresponding to the `offset` in its value
+fn literal_source_offset(repr: &str, offset: usize) -> Option<usize> {
+    let source = repr.strip_prefix('"')?.as_bytes();
+    let (mut source_idx, mut value_idx) = (0, 0);
+
+    while value_idx < offset {
+        match source.get(source_idx)? {
+            b'\\' => match source.get(source_idx + 1)? {
+                b'"' | b'\\' | b'\'' | b'n' | b'r' | b't' | b'0' => source_idx += 2,
+                _ => return None,
+            },
+            _ => source_idx += 1,
+        }
+        value_idx += 1;
+    }
+
+    // skip the opening quote
+    Some(source_idx + 1)
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Display, EnumString)]
 #[strum(serialize_all = "snake_case")]
 pub enum GetSetGenMode {
     Get,
     GetCopy,
+    /// Getter returning a reference to the [`Deref::Target`](core::ops::Deref::Target) of the field.
+    /// It is generated by `iroha_ffi_derive` itself, `DerefGetters` only marks the structure requesting it
+    GetDeref,
+    /// Getter returning a [`Cow`](std::borrow::Cow) borrowing the field, leaving it to the caller to take ownership.
+    /// Like [`Self::GetDeref`] it is generated by `iroha_ffi_derive` itself, `CowGetters` only marks the structure requesting it
+    GetCow,
     Set,
     GetMut,
+    /// Getter returning the discriminant of a fieldless enum by value.
+    /// Unlike other modes it is generated by `iroha_ffi_derive` itself, not by the `getset` crate
+    GetDiscriminant,
+    /// Getter returning an array field as a slice.
+    /// Like [`Self::GetDiscriminant`] it is generated by `iroha_ffi_derive` itself
+    GetSlice,
+}
+
+impl GetSetGenMode {
+    /// Whether the accessor is generated by `iroha_ffi_derive` rather than by the `getset` crate
+    pub fn is_ffi_only(self) -> bool {
+        matches!(self, Self::GetDiscriminant | Self::GetSlice)
+    }
 }
 
 enum GetSetAttrToken {
     Skip,
+    /// Skip only the listed accessors, e.g. `skip(set)`, leaving the inherited ones intact
+    SkipModes(Vec<GetSetGenMode>),
     Gen(GetSetGenMode, GetSetOptions),
+    /// Shorthand for requesting both `get` and `get_mut` with the same options
+    GetBoth(GetSetOptions),
+    /// Request a borrowed view struct of all the fields exposed through `get`
+    View,
+    /// Mark all the accessors of the struct `#[inline]`
+    Inline,
 }
 
 struct SpannedGetSetAttrToken {
@@ -128,14 +340,42 @@ impl syn::parse::Parse for SpannedGetSetAttrToken {
         let ident = input.parse::<syn::Ident>()?;
 
         match ident.to_string().as_str() {
+            "skip" if input.peek(syn::token::Paren) => {
+                let content;
+                let parens = syn::parenthesized!(content in input);
+                let mut modes = Vec::new();
+                for mode in Punctuated::<syn::Ident, Token![,]>::parse_terminated(&content)? {
+                    match mode.to_string().as_str() {
+                        "get_both" => modes.extend([GetSetGenMode::Get, GetSetGenMode::GetMut]),
+                        name => modes.push(name.parse().map_err(|_| {
+                            syn::Error::new(
+                                mode.span(),
+                                format!("unknown accessor `{name}` in `skip(...)`"),
+                            )
+                        })?),
+                    }
+                }
+
+                Ok(SpannedGetSetAttrToken {
+                    span: ident.span().join(parens.span.join()).unwrap_or_else(|| ident.span()),
+                    token: GetSetAttrToken::SkipModes(modes),
+                })
+            }
             "skip" => Ok(SpannedGetSetAttrToken {
                 span: ident.span(),
                 token: GetSetAttrToken::Skip,
             }),
-            s @ ("get" | "get_copy" | "set" | "get_mut") => {
-                let mode = s.parse().unwrap();
-
-                if input.peek(Token![=]) {
+            "view" => Ok(SpannedGetSetAttrToken {
+                span: ident.span(),
+                token: GetSetAttrToken::View,
+            }),
+            "inline" => Ok(SpannedGetSetAttrToken {
+                span: ident.span(),
+                token: GetSetAttrToken::Inline,
+            }),
+            s @ ("get" | "get_copy" | "get_deref" | "get_cow" | "set" | "get_mut" | "get_both"
+            | "get_discriminant" | "get_slice") => {
+                let (span, options) = if input.peek(Token![=]) {
                     input.parse::<Token![=]>()?;
                     let options = input.parse::<SpannedGetSetOptions>()?;
                     let span = ident
@@ -143,20 +383,22 @@ impl syn::parse::Parse for SpannedGetSetAttrToken {
                         .join(options.span)
                         .unwrap_or_else(|| ident.span());
 
-                    Ok(SpannedGetSetAttrToken {
-                        span,
-                        token: GetSetAttrToken::Gen(mode, options.options),
-                    })
+                    (span, options.options)
                 } else {
-                    Ok(SpannedGetSetAttrToken {
-                        span: ident.span(),
-                        token: GetSetAttrToken::Gen(mode, GetSetOptions::default()),
-                    })
-                }
+                    (ident.span(), GetSetOptions::default())
+                };
+
+                let token = if s == "get_both" {
+                    GetSetAttrToken::GetBoth(options)
+                } else {
+                    GetSetAttrToken::Gen(s.parse().unwrap(), options)
+                };
+
+                Ok(SpannedGetSetAttrToken { span, token })
             }
             _ => Err(syn::Error::new(
                 ident.span(),
-                "expected one of `get`, `get_copy`, `get_mut`, `set`, `skip`",
+                "expected one of `get`, `get_both`, `get_copy`, `get_cow`, `get_deref`, `get_discriminant`, `get_mut`, `get_slice`, `inline`, `set`, `skip`, `view`",
             )),
         }
     }
@@ -172,13 +414,48 @@ fn insert_gen_request(
     mode: GetSetGenMode,
     options: GetSetOptions,
 ) {
-    if options.with_prefix && mode == GetSetGenMode::Set {
+    if options.with_prefix && (mode == GetSetGenMode::Set || mode.is_ffi_only()) {
+        accumulator.push(
+            darling::Error::custom(format!("`with_prefix` is not supported for `{mode}`"))
+                .with_span(&span),
+        );
+    }
+
+    if options.getter != GetterKind::Plain && mode != GetSetGenMode::Get {
+        accumulator.push(
+            darling::Error::custom(format!(
+                "`{}` is not supported for `{mode}`",
+                options.getter
+            ))
+            .with_span(&span),
+        );
+    }
+    let try_set = options.setter == SetterKind::Try;
+    if (try_set || options.validate.is_some() || options.error.is_some())
+        && mode != GetSetGenMode::Set
+    {
+        accumulator.push(
+            darling::Error::custom(format!(
+                "`try`, `validate` and `error` are not supported for `{mode}`"
+            ))
+            .with_span(&span),
+        );
+    } else if try_set != options.validate.is_some() || try_set != options.error.is_some() {
         accumulator.push(
-            darling::Error::custom("`with_prefix` is not supported for `set`").with_span(&span),
+            darling::Error::custom(
+                "`try`, `validate=...` and `error=...` have to be used together",
+            )
+            .with_span(&span),
         );
     }
 
     match gen_map.entry(mode) {
+        Entry::Occupied(o) if o.get().setter != options.setter => accumulator.push(
+            darling::Error::custom(
+                "both fallible (`try`) and plain setters are requested for the same field",
+            )
+            .with_span(&span),
+        ),
         Entry::Occupied(_) => accumulator.push(
             darling::Error::custom(format!("duplicate `getset({mode})` attribute"))
                 .with_span(&span),
@@ -191,15 +468,32 @@ fn insert_gen_request(
 
 struct GetSetRawFieldAttr {
     pub skip: bool,
+    pub skip_modes: FxHashSet<GetSetGenMode>,
+    pub view: bool,
+    pub inline: bool,
     pub gen: RequestedAccessors,
 }
 
+/// Spans of the tokens which are checked against each other once all the attributes are parsed
+#[derive(Default)]
+struct GetSetAttrSpans {
+    skip: Option<Span>,
+    skip_modes: Option<Span>,
+    pinned: Option<Span>,
+    raw_ptr: Option<Span>,
+    try_set: Option<Span>,
+    cow: Option<Span>,
+}
+
 impl GetSetRawFieldAttr {
     fn from_attributes(attrs: &[Attribute], allow_skip: bool) -> darling::Result<Self> {
         let mut accumulator = darling::error::Accumulator::default();
-        let mut skip_span = None;
+        let mut spans = GetSetAttrSpans::default();
         let mut result = GetSetRawFieldAttr {
             skip: false,
+            skip_modes: FxHashSet::default(),
+            view: false,
+            inline: false,
             gen: FxHashMap::default(),
         };
         for attr in attrs {
@@ -221,25 +515,7 @@ impl GetSetRawFieldAttr {
                 };
 
                 for token in tokens {
-                    match token.token {
-                        GetSetAttrToken::Skip if allow_skip => {
-                            result.skip = true;
-                            skip_span = Some(token.span);
-                        }
-                        GetSetAttrToken::Skip => {
-                            accumulator.push(
-                                darling::Error::custom("`skip` is not valid on a struct")
-                                    .with_span(&token.span),
-                            );
-                        }
-                        GetSetAttrToken::Gen(mode, options) => insert_gen_request(
-                            &mut accumulator,
-                            &mut result.gen,
-                            token.span,
-                            mode,
-                            options,
-                        ),
-                    }
+                    result.insert_token(&mut accumulator, &mut spans, token, allow_skip);
                 }
             } else if attr
                 .path()
@@ -256,22 +532,171 @@ impl GetSetRawFieldAttr {
             }
         }
 
-        if result.skip && !result.gen.is_empty() {
+        result.check_field_only_options(&mut accumulator, &spans, allow_skip);
+        result.check_conflicts(&mut accumulator, &spans);
+
+        accumulator.finish_with(result)
+    }
+
+    fn insert_token(
+        &mut self,
+        accumulator: &mut darling::error::Accumulator,
+        spans: &mut GetSetAttrSpans,
+        token: SpannedGetSetAttrToken,
+        allow_skip: bool,
+    ) {
+        match token.token {
+            GetSetAttrToken::Skip if allow_skip => {
+                self.skip = true;
+                spans.skip = Some(token.span);
+            }
+            GetSetAttrToken::SkipModes(modes) if allow_skip => {
+                self.skip_modes.extend(modes);
+                spans.skip_modes = Some(token.span);
+            }
+            GetSetAttrToken::Skip | GetSetAttrToken::SkipModes(_) => {
+                accumulator.push(
+                    darling::Error::custom("`skip` is not valid on a struct")
+                        .with_span(&token.span),
+                );
+            }
+            GetSetAttrToken::View if !allow_skip => {
+                self.view = true;
+            }
+            GetSetAttrToken::View => {
+                accumulator.push(
+                    darling::Error::custom("`view` is only valid on a struct")
+                        .with_span(&token.span),
+                );
+            }
+            GetSetAttrToken::Inline if !allow_skip => {
+                self.inline = true;
+            }
+            GetSetAttrToken::Inline => {
+                accumulator.push(
+                    darling::Error::custom(
+                        "`inline` is only valid on a struct, use `no_inline` option to opt out of it",
+                    )
+                    .with_span(&token.span),
+                );
+            }
+            GetSetAttrToken::Gen(mode, _) if mode.is_ffi_only() && !allow_skip => {
+                accumulator.push(
+                    darling::Error::custom(format!("`{mode}` is not valid on a struct"))
+                        .with_span(&token.span),
+                );
+            }
+            GetSetAttrToken::Gen(mode, options) => {
+                if mode == GetSetGenMode::GetCow {
+                    spans.cow = Some(token.span);
+                }
+                match options.getter {
+                    GetterKind::Plain => {}
+                    GetterKind::Pinned => spans.pinned = Some(token.span),
+                    GetterKind::RawPtr => spans.raw_ptr = Some(token.span),
+                }
+                if options.setter == SetterKind::Try {
+                    spans.try_set = Some(token.span);
+                }
+                insert_gen_request(accumulator, &mut self.gen, token.span, mode, options);
+            }
+            GetSetAttrToken::GetBoth(options) => {
+                for mode in [GetSetGenMode::Get, GetSetGenMode::GetMut] {
+                    insert_gen_request(
+                        accumulator,
+                        &mut self.gen,
+                        token.span,
+                        mode,
+                        options.clone(),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Reject `pinned`, `raw_ptr` and `try` on a struct, and `pinned` getters of fields which can be moved out of
+    fn check_field_only_options(
+        &self,
+        accumulator: &mut darling::error::Accumulator,
+        spans: &GetSetAttrSpans,
+        allow_skip: bool,
+    ) {
+        if !allow_skip {
+            for (option, span) in [
+                ("pinned", spans.pinned),
+                ("raw_ptr", spans.raw_ptr),
+                ("try", spans.try_set),
+            ] {
+                if let Some(span) = span {
+                    accumulator.push(
+                        darling::Error::custom(format!("`{option}` is only valid on a field"))
+                            .with_span(&span),
+                    );
+                }
+            }
+        } else if let Some(pinned_span) = spans.pinned {
+            if self.gen.contains_key(&GetSetGenMode::Set)
+                || self.gen.contains_key(&GetSetGenMode::GetMut)
+            {
+                accumulator.push(
+                    darling::Error::custom(
+                        "`pinned` getter can't be combined with `set` or `get_mut`, they allow moving the field",
+                    )
+                    .with_span(&pinned_span),
+                );
+            }
+        }
+    }
+
+    /// Reject accessors generating the same method and accessors which are both requested and skipped
+    fn check_conflicts(
+        &self,
+        accumulator: &mut darling::error::Accumulator,
+        spans: &GetSetAttrSpans,
+    ) {
+        if let Some(cow_span) = spans.cow {
+            for mode in [GetSetGenMode::Get, GetSetGenMode::GetCopy] {
+                if self.gen.contains_key(&mode) {
+                    accumulator.push(
+                        darling::Error::custom(format!(
+                            "`get_cow` can't be combined with `{mode}`, they generate the same method"
+                        ))
+                        .with_span(&cow_span),
+                    );
+                }
+            }
+        }
+        if self.skip && !self.gen.is_empty() {
             accumulator.push(
                 darling::Error::custom(
                     "`skip` is used, but attributes requesting a getter or setter are also present",
                 )
-                .with_span(&skip_span.unwrap()),
+                .with_span(&spans.skip.unwrap()),
+            );
+        }
+        let mut skipped_and_requested = self
+            .skip_modes
+            .iter()
+            .filter(|mode| self.gen.contains_key(mode))
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        skipped_and_requested.sort();
+        for mode in skipped_and_requested {
+            accumulator.push(
+                darling::Error::custom(format!(
+                    "`getset({mode})` is requested, but also skipped with `skip({mode})`"
+                ))
+                .with_span(&spans.skip_modes.unwrap()),
             );
         }
-
-        accumulator.finish_with(result)
     }
 }
 
 #[derive(Default, Debug, Eq, PartialEq, Clone)]
 pub struct GetSetFieldAttrs {
     pub skip: bool,
+    /// Accessors inherited from the struct which are not generated for this field
+    pub skip_modes: FxHashSet<GetSetGenMode>,
     pub gen: RequestedAccessors,
 }
 
@@ -279,6 +704,7 @@ impl darling::FromAttributes for GetSetFieldAttrs {
     fn from_attributes(attrs: &[Attribute]) -> darling::Result<Self> {
         GetSetRawFieldAttr::from_attributes(attrs, true).map(|raw| GetSetFieldAttrs {
             skip: raw.skip,
+            skip_modes: raw.skip_modes,
             gen: raw.gen,
         })
     }
@@ -287,12 +713,19 @@ impl darling::FromAttributes for GetSetFieldAttrs {
 #[derive(Default, Debug, Eq, PartialEq, Clone)]
 pub struct GetSetStructAttrs {
     pub gen: FxHashMap<GetSetGenMode, GetSetOptions>,
+    /// Generate a `{Struct}View<'a>` struct borrowing all the fields exposed through `get`
+    pub view: bool,
+    /// Mark all the accessors `#[inline]` unless opted out of with `no_inline`
+    pub inline: bool,
 }
 
 impl darling::FromAttributes for GetSetStructAttrs {
     fn from_attributes(attrs: &[Attribute]) -> darling::Result<Self> {
-        GetSetRawFieldAttr::from_attributes(attrs, false)
-            .map(|raw| GetSetStructAttrs { gen: raw.gen })
+        GetSetRawFieldAttr::from_attributes(attrs, false).map(|raw| GetSetStructAttrs {
+            gen: raw.gen,
+            view: raw.view,
+            inline: raw.inline,
+        })
     }
 }
 
@@ -314,6 +747,18 @@ impl GetSetFieldAttrs {
                     // visibility is overwritten, while the "with_prefix" is merged
                     o.visibility.clone_from(&options.visibility);
                     o.with_prefix |= options.with_prefix;
+                    // doc is only overwritten if the field provides its own
+                    if options.doc.is_some() {
+                        o.doc.clone_from(&options.doc);
+                    }
+                    if options.inline.is_some() {
+                        o.inline = options.inline;
+                    }
+                    // `pinned`, `raw_ptr` and `try` are only valid on a field, so they are always taken from there
+                    o.getter = options.getter;
+                    o.setter = options.setter;
+                    o.validate.clone_from(&options.validate);
+                    o.error.clone_from(&options.error);
                 }
                 Entry::Vacant(v) => {
                     v.insert(options.clone());
@@ -321,7 +766,15 @@ impl GetSetFieldAttrs {
             }
         }
 
+        result.retain(|mode, _| !self.skip_modes.contains(mode));
+        if struct_attr.inline {
+            for options in result.values_mut() {
+                options.inline.get_or_insert(true);
+            }
+        }
+
         // filter out the modes that are not requested by the `#[derive(...)]` attribute
+        // (`get_both` was already expanded into `get` and `get_mut`, so it needs both `Getters` and `MutGetters`)
         let derived_modes = derives
             .derives
             .iter()
@@ -330,16 +783,412 @@ impl GetSetFieldAttrs {
                 _ => None,
             })
             .collect::<FxHashSet<_>>();
-        result.retain(|&mode, _| derived_modes.contains(&mode));
+        // `get_discriminant` and `get_slice` are not backed by any derive, they are generated by `ffi_export` itself
+        result.retain(|&mode, _| mode.is_ffi_only() || derived_modes.contains(&mode));
+
+        // `get_cow` generates the same method as `get` and `get_copy`, so the one requested on the field wins over the inherited ones
+        // (`get_cow` of a field is passed to the `getset` crate as `skip(get, get_copy)`, even if `CowGetters` is not derived)
+        if self.gen.contains_key(&GetSetGenMode::GetCow) {
+            result.remove(&GetSetGenMode::Get);
+            result.remove(&GetSetGenMode::GetCopy);
+        } else if result.contains_key(&GetSetGenMode::Get)
+            || result.contains_key(&GetSetGenMode::GetCopy)
+        {
+            result.remove(&GetSetGenMode::GetCow);
+        }
 
         result
     }
+
+    /// Reject `get_discriminant` on fields whose type is known not to be an enum
+    /// and `get_slice` on fields which are not arrays.
+    ///
+    /// Only types that can be recognized syntactically (primitives, references, arrays, tuples etc.) are rejected
+    pub fn check_field_type(&self, ty: &syn::Type) -> darling::Result<()> {
+        const NON_ENUM_TYPES: &[&str] = &[
+            "bool", "char", "str", "String", "u8", "u16", "u32", "u64", "u128", "usize", "i8",
+            "i16", "i32", "i64", "i128", "isize", "f32", "f64",
+        ];
+
+        if self.gen.contains_key(&GetSetGenMode::GetSlice) && array_elem_type(ty).is_none() {
+            return Err(darling::Error::custom(
+                "`get_slice` is only applicable to fields of array type",
+            )
+            .with_span(ty));
+        }
+        if !self.gen.contains_key(&GetSetGenMode::GetDiscriminant) {
+            return Ok(());
+        }
+
+        let is_enum_candidate = match ty {
+            syn::Type::Path(path) => {
+                path.qself.is_none()
+                    && path.path.get_ident().map_or(true, |ident| {
+                        !NON_ENUM_TYPES.contains(&ident.to_string().as_str())
+                    })
+            }
+            syn::Type::Group(group) => return self.check_field_type(&group.elem),
+            syn::Type::Paren(paren) => return self.check_field_type(&paren.elem),
+            _ => false,
+        };
+
+        if is_enum_candidate {
+            Ok(())
+        } else {
+            Err(darling::Error::custom(
+                "`get_discriminant` is only applicable to fields of enum type",
+            )
+            .with_span(ty))
+        }
+    }
+}
+
+/// Type of the elements of an array type, e.g. `T` of `[T; N]`
+pub fn array_elem_type(ty: &syn::Type) -> Option<&syn::Type> {
+    match ty {
+        syn::Type::Array(array) => Some(&array.elem),
+        syn::Type::Group(group) => array_elem_type(&group.elem),
+        syn::Type::Paren(paren) => array_elem_type(&paren.elem),
+        _ => None,
+    }
+}
+
+/// Remove the given token (e.g. `get_discriminant` or `view`) from `#[getset(...)]` attributes
+/// since the `getset` crate rejects unknown tokens
+pub fn strip_getset_token(attrs: &mut Vec<Attribute>, token: &str) {
+    attrs.retain_mut(|attr| {
+        if !attr.path().is_ident("getset") {
+            return true;
+        }
+        let Ok(metas) = attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+        else {
+            // leave malformed attributes for the `getset` crate to report
+            return true;
+        };
+        if !metas.iter().any(|meta| meta.path().is_ident(token)) {
+            return true;
+        }
+
+        let metas = metas
+            .into_iter()
+            .filter(|meta| !meta.path().is_ident(token))
+            .collect::<Punctuated<_, Token![,]>>();
+        if metas.is_empty() {
+            return false;
+        }
+
+        *attr = syn::parse_quote!(#[getset(#metas)]);
+        true
+    });
+}
+
+/// Remove `DerefGetters` and `CowGetters` from `#[derive(...)]` attributes
+/// since they are only markers, there are no such derive macros
+pub fn strip_ffi_marker_derives(attrs: &mut Vec<Attribute>) {
+    let is_marker = |path: &syn::Path| {
+        GetSetDerive::try_from_path(path).map_or(false, GetSetDerive::is_ffi_marker)
+    };
+
+    attrs.retain_mut(|attr| {
+        if !attr.path().is_ident("derive") {
+            return true;
+        }
+        let Ok(paths) = attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+        else {
+            // leave malformed attributes for the compiler to report
+            return true;
+        };
+        if !paths.iter().any(is_marker) {
+            return true;
+        }
+
+        let paths = paths
+            .into_iter()
+            .filter(|path| !is_marker(path))
+            .collect::<Punctuated<_, Token![,]>>();
+        if paths.is_empty() {
+            return false;
+        }
+
+        *attr = syn::parse_quote!(#[derive(#paths)]);
+        true
+    });
+}
+
+/// Rewrite `get_both` into `get` and `get_mut` with the same options since the `getset` crate doesn't know the shorthand
+///
+/// # Errors
+///
+/// Fails if `get_both` is used without deriving both `Getters` and `MutGetters`,
+/// as only one of the accessors would be generated otherwise
+pub fn expand_get_both(attrs: &mut [Attribute], derives: &DeriveAttrs) -> darling::Result<()> {
+    let is_derived = |derive| derives.derives.contains(&Derive::GetSet(derive));
+    let both_derived = is_derived(GetSetDerive::Getters) && is_derived(GetSetDerive::MutGetters);
+    let mut accumulator = darling::error::Accumulator::default();
+
+    for attr in attrs
+        .iter_mut()
+        .filter(|attr| attr.path().is_ident("getset"))
+    {
+        let Ok(metas) = attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+        else {
+            // leave malformed attributes for the `getset` crate to report
+            continue;
+        };
+        if !metas.iter().any(|meta| meta.path().is_ident("get_both")) {
+            continue;
+        }
+
+        let metas = metas
+            .into_iter()
+            .flat_map(|meta| {
+                if !meta.path().is_ident("get_both") {
+                    return vec![meta];
+                }
+                if !both_derived {
+                    accumulator.push(
+                        darling::Error::custom(
+                            "`get_both` requires both `Getters` and `MutGetters` to be derived",
+                        )
+                        .with_span(meta.path()),
+                    );
+                }
+
+                match &meta {
+                    syn::Meta::NameValue(syn::MetaNameValue { value, .. }) => vec![
+                        syn::parse_quote!(get = #value),
+                        syn::parse_quote!(get_mut = #value),
+                    ],
+                    _ => vec![syn::parse_quote!(get), syn::parse_quote!(get_mut)],
+                }
+            })
+            .collect::<Punctuated<syn::Meta, Token![,]>>();
+        *attr = syn::parse_quote!(#[getset(#metas)]);
+    }
+
+    accumulator.finish()
+}
+
+/// Rewrite per-mode `skip(...)` into the form understood by the `getset` crate.
+///
+/// The `getset` crate treats `skip(...)` as a blanket `skip`, but it takes the last matching attribute of the field,
+/// so moving `skip(...)` into a leading attribute and following it with explicit requests for the remaining inherited
+/// accessors skips just the listed ones
+pub fn expand_skip_modes(attrs: &mut Vec<Attribute>, struct_attrs: &[Attribute]) {
+    fn getset_metas(attr: &Attribute) -> Option<Punctuated<syn::Meta, Token![,]>> {
+        if !attr.path().is_ident("getset") {
+            return None;
+        }
+        attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+            .ok()
+    }
+
+    let mut skip_modes = Vec::new();
+    let mut requested = FxHashSet::default();
+    attrs.retain_mut(|attr| {
+        let Some(metas) = getset_metas(attr) else {
+            return true;
+        };
+
+        let (skips, metas): (Vec<_>, Vec<_>) = metas
+            .into_iter()
+            .partition(|meta| matches!(meta, syn::Meta::List(list) if list.path.is_ident("skip")));
+        requested.extend(
+            metas
+                .iter()
+                .filter_map(|meta| meta.path().get_ident().cloned()),
+        );
+        if skips.is_empty() {
+            return true;
+        }
+
+        skip_modes.extend(skips.into_iter().filter_map(|skip| {
+            skip.require_list()
+                .and_then(|list| {
+                    list.parse_args_with(Punctuated::<syn::Ident, Token![,]>::parse_terminated)
+                })
+                .ok()
+        }));
+        if metas.is_empty() {
+            return false;
+        }
+
+        let metas = metas.into_iter().collect::<Punctuated<_, Token![,]>>();
+        *attr = syn::parse_quote!(#[getset(#metas)]);
+        true
+    });
+
+    if skip_modes.is_empty() {
+        return;
+    }
+
+    let skipped = skip_modes
+        .iter()
+        .flatten()
+        .flat_map(|mode| {
+            if mode == "get_both" {
+                vec!["get".to_owned(), "get_mut".to_owned()]
+            } else {
+                vec![mode.to_string()]
+            }
+        })
+        .collect::<FxHashSet<_>>();
+    let inherited = struct_attrs
+        .iter()
+        .filter_map(getset_metas)
+        .flatten()
+        .filter(|meta| {
+            meta.path().get_ident().map_or(false, |mode| {
+                !skipped.contains(&mode.to_string()) && !requested.contains(mode)
+            })
+        })
+        .collect::<Punctuated<_, Token![,]>>();
+
+    let skip_modes = skip_modes
+        .into_iter()
+        .flatten()
+        .collect::<Punctuated<_, Token![,]>>();
+    attrs.insert(0, syn::parse_quote!(#[getset(skip(#skip_modes))]));
+    if !inherited.is_empty() {
+        attrs.push(syn::parse_quote!(#[getset(#inherited)]));
+    }
+}
+
+/// Replace `pinned` and `raw_ptr` getters with `skip(get)`, `try` setters with `skip(set)`
+/// and `get_cow` getters with `skip(get, get_copy)`, since they are generated by `iroha_ffi_derive`
+/// instead of the `getset` crate
+pub fn strip_ffi_accessors(attrs: &mut [Attribute]) {
+    for attr in attrs
+        .iter_mut()
+        .filter(|attr| attr.path().is_ident("getset"))
+    {
+        let Ok(metas) = attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+        else {
+            // leave malformed attributes for the `getset` crate to report
+            continue;
+        };
+
+        let mut stripped = false;
+        let metas = metas
+            .into_iter()
+            .map(|meta| {
+                if meta.path().is_ident("get_cow") {
+                    // the inherited `get` and `get_copy` generate the same method
+                    stripped = true;
+                    return syn::parse_quote!(skip(get, get_copy));
+                }
+                let syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    value:
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(lit),
+                            ..
+                        }),
+                    ..
+                }) = &meta
+                else {
+                    return meta;
+                };
+                let value = lit.value();
+                let Ok(parts) = split_options(&value) else {
+                    return meta;
+                };
+                if path.is_ident("get") && (parts.contains(&"pinned") || parts.contains(&"raw_ptr"))
+                {
+                    stripped = true;
+                    return syn::parse_quote!(skip(get));
+                }
+                if path.is_ident("set") && parts.contains(&"try") {
+                    stripped = true;
+                    return syn::parse_quote!(skip(set));
+                }
+
+                meta
+            })
+            .collect::<Punctuated<_, Token![,]>>();
+
+        if stripped {
+            *attr = syn::parse_quote!(#[getset(#metas)]);
+        }
+    }
+}
+
+/// Returns `true` for the options understood only by `iroha_ffi_derive`, i.e. `doc="..."` and `no_inline`
+fn is_ffi_only_option(part: &str) -> bool {
+    part.starts_with("doc=") || part == "no_inline"
+}
+
+/// Remove `doc="..."` and `no_inline` from `#[getset(...)]` options since the `getset` crate doesn't understand them.
+///
+/// The `getset` crate also splits the options on spaces, so spaces inside `pub(in some::path)` are replaced with tabs,
+/// which are whitespace to the Rust tokenizer as well
+pub fn strip_ffi_options(attrs: &mut [Attribute]) {
+    for attr in attrs
+        .iter_mut()
+        .filter(|attr| attr.path().is_ident("getset"))
+    {
+        let Ok(metas) = attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+        else {
+            // leave malformed attributes for the `getset` crate to report
+            continue;
+        };
+
+        let mut stripped = false;
+        let metas = metas
+            .into_iter()
+            .map(|meta| {
+                let syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    value:
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(lit),
+                            ..
+                        }),
+                    ..
+                }) = &meta
+                else {
+                    return meta;
+                };
+                let value = lit.value();
+                let Ok(parts) = split_options(&value) else {
+                    return meta;
+                };
+                let options = parts
+                    .into_iter()
+                    .filter(|part| !is_ffi_only_option(part))
+                    .map(|part| {
+                        if part.starts_with("pub(") {
+                            part.replace(' ', "\t")
+                        } else {
+                            part.to_owned()
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                if options == value {
+                    return meta;
+                }
+
+                stripped = true;
+                if options.is_empty() {
+                    syn::parse_quote!(#path)
+                } else {
+                    syn::parse_quote!(#path = #options)
+                }
+            })
+            .collect::<Punctuated<_, Token![,]>>();
+
+        if stripped {
+            *attr = syn::parse_quote!(#[getset(#metas)]);
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::{
-        GetSetFieldAttrs, GetSetGenMode, GetSetOptions, GetSetStructAttrs, RequestedAccessors,
+        GetSetFieldAttrs, GetSetGenMode, GetSetOptions, GetSetStructAttrs, GetterKind,
+        RequestedAccessors, SetterKind,
     };
 
     mod parse {
@@ -348,8 +1197,11 @@ mod test {
         use rustc_hash::FxHashMap;
         use syn::parse_quote;
 
-        use super::{GetSetFieldAttrs, GetSetGenMode, GetSetOptions, GetSetStructAttrs};
-        use crate::parse_attributes;
+        use super::{
+            GetSetFieldAttrs, GetSetGenMode, GetSetOptions, GetSetStructAttrs, GetterKind,
+            SetterKind,
+        };
+        use crate::{attr_parse::derive::DeriveAttrs, parse_attributes};
 
         macro_rules! assert_getset_ok {
         ($( #[$meta:meta] )*,
@@ -399,9 +1251,22 @@ mod test {
         }
 
         #[test]
-        fn field_get() {
+        fn field_skip_modes() {
             assert_getset_ok!(
-                #[getset(get)],
+                #[getset(skip(set, get_both))],
+                GetSetFieldAttrs {
+                    skip_modes: [GetSetGenMode::Set, GetSetGenMode::Get, GetSetGenMode::GetMut]
+                        .into_iter()
+                        .collect(),
+                    ..Default::default()
+                }
+            );
+        }
+
+        #[test]
+        fn field_get() {
+            assert_getset_ok!(
+                #[getset(get)],
                 GetSetFieldAttrs {
                     gen: FxHashMap::from_iter([
                         (GetSetGenMode::Get, GetSetOptions::default()),
@@ -436,6 +1301,7 @@ mod test {
                         (GetSetGenMode::Get, GetSetOptions {
                             visibility: Some(parse_quote! { pub }),
                             with_prefix: true,
+                            ..Default::default()
                         }),
                     ]),
                     ..Default::default()
@@ -448,6 +1314,41 @@ mod test {
                         (GetSetGenMode::Get, GetSetOptions {
                             visibility: Some(parse_quote! { pub }),
                             with_prefix: true,
+                            ..Default::default()
+                        }),
+                    ]),
+                    ..Default::default()
+                }
+            );
+        }
+
+        #[test]
+        fn field_get_pub_in_path() {
+            assert_getset_ok!(
+                #[getset(get = "pub(in crate::some::path) with_prefix")],
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::Get, GetSetOptions {
+                            visibility: Some(parse_quote! { pub(in crate::some::path) }),
+                            with_prefix: true,
+                            ..Default::default()
+                        }),
+                    ]),
+                    ..Default::default()
+                }
+            );
+        }
+
+        #[test]
+        fn field_get_double_space() {
+            assert_getset_ok!(
+                #[getset(get = "pub  with_prefix")],
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::Get, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            with_prefix: true,
+                            ..Default::default()
                         }),
                     ]),
                     ..Default::default()
@@ -462,7 +1363,8 @@ mod test {
                 GetSetStructAttrs {
                     gen: FxHashMap::from_iter([
                         (GetSetGenMode::Get, GetSetOptions::default()),
-                    ])
+                    ]),
+                    ..Default::default()
                 }
             );
         }
@@ -477,7 +1379,8 @@ mod test {
                             visibility: Some(parse_quote! { pub }),
                             ..Default::default()
                         }),
-                    ])
+                    ]),
+                    ..Default::default()
                 }
             );
         }
@@ -491,8 +1394,10 @@ mod test {
                         (GetSetGenMode::Get, GetSetOptions {
                             visibility: Some(parse_quote! { pub }),
                             with_prefix: true,
+                            ..Default::default()
                         }),
-                    ])
+                    ]),
+                    ..Default::default()
                 }
             );
             assert_getset_ok!(
@@ -502,8 +1407,10 @@ mod test {
                         (GetSetGenMode::Get, GetSetOptions {
                             visibility: Some(parse_quote! { pub }),
                             with_prefix: true,
+                            ..Default::default()
                         }),
-                    ])
+                    ]),
+                    ..Default::default()
                 }
             );
         }
@@ -554,7 +1461,8 @@ mod test {
                 GetSetStructAttrs {
                     gen: FxHashMap::from_iter([
                         (GetSetGenMode::GetCopy, GetSetOptions::default()),
-                    ])
+                    ]),
+                    ..Default::default()
                 }
             );
         }
@@ -566,7 +1474,8 @@ mod test {
                 GetSetStructAttrs {
                     gen: FxHashMap::from_iter([
                         (GetSetGenMode::Set, GetSetOptions::default()),
-                    ])
+                    ]),
+                    ..Default::default()
                 }
             );
         }
@@ -578,179 +1487,1215 @@ mod test {
                 GetSetStructAttrs {
                     gen: FxHashMap::from_iter([
                         (GetSetGenMode::GetMut, GetSetOptions::default()),
-                    ])
+                    ]),
+                    ..Default::default()
                 }
             );
         }
 
-        macro_rules! assert_getset_err {
-        ($( #[$meta:meta] )*, $ty:ident, $error:expr) => {
-            assert_eq!(
-                $ty::from_attributes(&parse_attributes(quote! {
-                    $( #[$meta] )*
-                }))
-                .unwrap_err()
-                .to_string(),
-                $error,
-                "The error message does not match the expected one"
-            )
-        };
-    }
-
         #[test]
-        fn err_unknown_token() {
-            assert_getset_err!(
-                #[getset(unknown_token)],
-                GetSetStructAttrs,
-                "expected one of `get`, `get_copy`, `get_mut`, `set`, `skip`"
+        fn field_get_both() {
+            assert_getset_ok!(
+                #[getset(get_both = "pub")],
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::Get, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            ..Default::default()
+                        }),
+                        (GetSetGenMode::GetMut, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            ..Default::default()
+                        }),
+                    ]),
+                    ..Default::default()
+                }
             );
         }
 
         #[test]
-        fn err_skip_struct() {
-            assert_getset_err!(
-                #[getset(skip)],
-                GetSetStructAttrs,
-                "`skip` is not valid on a struct"
+        fn struct_get_both() {
+            assert_getset_ok!(
+                #[getset(get_both)],
+                GetSetStructAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::Get, GetSetOptions::default()),
+                        (GetSetGenMode::GetMut, GetSetOptions::default()),
+                    ]),
+                    ..Default::default()
+                }
             );
         }
 
         #[test]
-        fn err_duplicate_accessor() {
-            assert_getset_err!(
-                #[getset(get = "pub", get)],
-                GetSetStructAttrs,
-                "duplicate `getset(get)` attribute"
+        fn field_get_deref() {
+            assert_getset_ok!(
+                #[getset(get_deref)],
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::GetDeref, GetSetOptions::default()),
+                    ]),
+                    ..Default::default()
+                }
             );
         }
 
         #[test]
-        fn err_unknown_option() {
-            assert_getset_err!(
-                #[getset(get = "aboba")],
-                GetSetStructAttrs,
-                "Failed to parse getset options at `aboba`: expected visibility or `with_prefix`"
+        fn field_get_deref_pub() {
+            assert_getset_ok!(
+                #[getset(get_deref = "pub")],
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::GetDeref, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            ..Default::default()
+                        }),
+                    ]),
+                    ..Default::default()
+                }
             );
         }
-    }
-    mod inheritance {
-        use darling::FromAttributes;
-        use proc_macro2::TokenStream;
-        use quote::quote;
-        use syn::parse_quote;
-
-        use super::{
-            GetSetFieldAttrs, GetSetGenMode, GetSetOptions, GetSetStructAttrs, RequestedAccessors,
-        };
-        use crate::attr_parse::derive::DeriveAttrs;
-
-        fn get_field_derives(
-            derive: TokenStream,
-            struct_attr: TokenStream,
-            field_attr: TokenStream,
-        ) -> RequestedAccessors {
-            fn parse_attributes<T: FromAttributes>(ts: TokenStream) -> T {
-                let attrs = crate::parse_attributes(ts);
-                T::from_attributes(&attrs).expect("Failed to parse attributes")
-            }
-
-            let derive = parse_attributes::<DeriveAttrs>(derive);
-            let struct_attr = parse_attributes::<GetSetStructAttrs>(struct_attr);
-            let field_attr = parse_attributes::<GetSetFieldAttrs>(field_attr);
-
-            field_attr.get_field_accessors(&derive, &struct_attr)
-        }
 
-        macro_rules! assert_getset_ok {
-            (
-                $( #[$derive:meta] )*,
-                $( #[$struct_attr:meta] )*,
-                $( #[$field_attr:meta] )*,
-                $expected:expr
-            ) => {
-                assert_eq!(
-                    get_field_derives(
-                        quote! { $( #[$derive] )* },
-                        quote! { $( #[$struct_attr] )* },
-                        quote! { $( #[$field_attr] )* },
-                    ),
-                    $expected
-                )
-            };
+        #[test]
+        fn struct_get_deref() {
+            assert_getset_ok!(
+                #[getset(get_deref = "pub with_prefix")],
+                GetSetStructAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::GetDeref, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            with_prefix: true,
+                            ..Default::default()
+                        }),
+                    ]),
+                    ..Default::default()
+                }
+            );
         }
 
         #[test]
-        fn getset_basic() {
+        fn field_get_cow() {
             assert_getset_ok!(
-                #[derive(Getters, Setters)],
-                ,
-                #[getset(get, set)],
-                RequestedAccessors::from_iter([
-                    (GetSetGenMode::Get, GetSetOptions::default()),
-                    (GetSetGenMode::Set, GetSetOptions::default()),
-                ])
+                #[getset(get_cow)],
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::GetCow, GetSetOptions::default()),
+                    ]),
+                    ..Default::default()
+                }
             );
         }
 
         #[test]
-        fn getset_derive_disabled() {
-            // no Setters - no Set generated
+        fn field_get_cow_pub() {
             assert_getset_ok!(
-                #[derive(Getters)],
-                ,
-                #[getset(get, set)],
-                RequestedAccessors::from_iter([
-                    (GetSetGenMode::Get, GetSetOptions::default())
-                ])
+                #[getset(get_cow = "pub")],
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::GetCow, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            ..Default::default()
+                        }),
+                    ]),
+                    ..Default::default()
+                }
             );
         }
 
         #[test]
-        fn getset_inherit() {
+        fn struct_get_cow() {
             assert_getset_ok!(
-                #[derive(Getters, Setters)],
-                #[getset(get)],
-                #[getset(set)],
-                RequestedAccessors::from_iter([
-                    (GetSetGenMode::Get, GetSetOptions::default()),
-                    (GetSetGenMode::Set, GetSetOptions::default()),
-                ])
+                #[getset(get_cow = "pub with_prefix", set = "pub")],
+                GetSetStructAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::GetCow, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            with_prefix: true,
+                            ..Default::default()
+                        }),
+                        (GetSetGenMode::Set, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            ..Default::default()
+                        }),
+                    ]),
+                    ..Default::default()
+                }
             );
         }
 
         #[test]
-        fn getset_overwrite_visibility() {
+        fn field_get_discriminant() {
             assert_getset_ok!(
-                #[derive(Getters, Setters)],
-                #[getset(get = "pub(crate)", set = "pub(crate)")],
-                #[getset(set = "pub")],
-                RequestedAccessors::from_iter([
-                    (GetSetGenMode::Get, GetSetOptions {
-                        visibility: Some(parse_quote! { pub(crate) }),
-                        ..Default::default()
-                    }),
-                    (GetSetGenMode::Set, GetSetOptions {
-                        visibility: Some(parse_quote! { pub }),
-                        ..Default::default()
-                    }),
-                ])
+                #[getset(get_discriminant = "pub")],
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::GetDiscriminant, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            ..Default::default()
+                        }),
+                    ]),
+                    ..Default::default()
+                }
             );
         }
 
         #[test]
-        fn inherit_with_prefix() {
-            assert_getset_ok!(
-                #[derive(Getters, CopyGetters)],
-                #[getset(get = "with_prefix", get_copy = "pub")],
+        fn field_get_discriminant_type() {
+            let attrs = GetSetFieldAttrs::from_attributes(&parse_attributes(quote! {
+                #[getset(get_discriminant = "pub")]
+            }))
+            .unwrap();
+
+            attrs
+                .check_field_type(&parse_quote! { AssetValueType })
+                .unwrap();
+            attrs
+                .check_field_type(&parse_quote! { crate::asset::AssetValueType })
+                .unwrap();
+            for ty in [
+                parse_quote! { u32 },
+                parse_quote! { &Kind },
+                parse_quote! { [Kind; 2] },
+                parse_quote! { (Kind, u8) },
+            ] {
+                assert_eq!(
+                    attrs.check_field_type(&ty).unwrap_err().to_string(),
+                    "`get_discriminant` is only applicable to fields of enum type"
+                );
+            }
+        }
+
+        #[test]
+        fn field_get_and_get_slice() {
+            let attrs = GetSetFieldAttrs::from_attributes(&parse_attributes(quote! {
+                #[getset(get = "pub", get_slice = "pub")]
+            }))
+            .unwrap();
+
+            assert_eq!(
+                attrs,
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (
+                            GetSetGenMode::Get,
+                            GetSetOptions {
+                                visibility: Some(parse_quote! { pub }),
+                                ..Default::default()
+                            }
+                        ),
+                        (
+                            GetSetGenMode::GetSlice,
+                            GetSetOptions {
+                                visibility: Some(parse_quote! { pub }),
+                                ..Default::default()
+                            }
+                        ),
+                    ]),
+                    ..Default::default()
+                }
+            );
+            attrs.check_field_type(&parse_quote! { [u8; 32] }).unwrap();
+            attrs.check_field_type(&parse_quote! { [T; N] }).unwrap();
+            for ty in [
+                parse_quote! { Vec<u8> },
+                parse_quote! { &[u8] },
+                parse_quote! { u32 },
+            ] {
+                assert_eq!(
+                    attrs.check_field_type(&ty).unwrap_err().to_string(),
+                    "`get_slice` is only applicable to fields of array type"
+                );
+            }
+        }
+
+        #[test]
+        fn strip_getset_token() {
+            let mut attrs = parse_attributes(quote! {
+                #[getset(get = "pub", get_discriminant = "pub")]
+                #[getset(get_discriminant)]
+                #[abra_cadabra]
+            });
+            super::super::strip_getset_token(&mut attrs, "get_discriminant");
+
+            assert_eq!(
+                attrs,
+                parse_attributes(quote! {
+                    #[getset(get = "pub")]
+                    #[abra_cadabra]
+                })
+            );
+        }
+
+        #[test]
+        fn strip_ffi_marker_derives() {
+            let mut attrs = parse_attributes(quote! {
+                #[derive(Clone, Getters, DerefGetters)]
+                #[derive(getset::DerefGetters, CowGetters)]
+                #[abra_cadabra]
+            });
+            super::super::strip_ffi_marker_derives(&mut attrs);
+
+            assert_eq!(
+                attrs,
+                parse_attributes(quote! {
+                    #[derive(Clone, Getters)]
+                    #[abra_cadabra]
+                })
+            );
+        }
+
+        #[test]
+        fn expand_get_both() {
+            let derives = DeriveAttrs::from_attributes(&parse_attributes(quote! {
+                #[derive(Getters, MutGetters)]
+            }))
+            .unwrap();
+            let mut attrs = parse_attributes(quote! {
+                #[getset(get_both = "pub", set)]
+                #[getset(skip(get_both))]
+                #[getset(get_both)]
+            });
+            super::super::expand_get_both(&mut attrs, &derives).unwrap();
+
+            assert_eq!(
+                attrs,
+                parse_attributes(quote! {
+                    #[getset(get = "pub", get_mut = "pub", set)]
+                    #[getset(skip(get_both))]
+                    #[getset(get, get_mut)]
+                })
+            );
+        }
+
+        #[test]
+        fn err_get_both_without_mut_getters() {
+            let derives = DeriveAttrs::from_attributes(&parse_attributes(quote! {
+                #[derive(Getters)]
+            }))
+            .unwrap();
+            let mut attrs = parse_attributes(quote! {
+                #[getset(get_both = "pub")]
+            });
+
+            assert_eq!(
+                super::super::expand_get_both(&mut attrs, &derives)
+                    .unwrap_err()
+                    .to_string(),
+                "`get_both` requires both `Getters` and `MutGetters` to be derived"
+            );
+        }
+
+        #[test]
+        fn struct_view() {
+            assert_getset_ok!(
+                #[getset(get = "pub", view)],
+                GetSetStructAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::Get, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            ..Default::default()
+                        }),
+                    ]),
+                    view: true,
+                    ..Default::default()
+                }
+            );
+        }
+
+        #[test]
+        fn struct_inline() {
+            assert_getset_ok!(
+                #[getset(get = "pub", inline)],
+                GetSetStructAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::Get, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            ..Default::default()
+                        }),
+                    ]),
+                    inline: true,
+                    ..Default::default()
+                }
+            );
+        }
+
+        #[test]
+        fn field_get_no_inline() {
+            assert_getset_ok!(
+                #[getset(get = "pub no_inline")],
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::Get, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            inline: Some(false),
+                            ..Default::default()
+                        }),
+                    ]),
+                    ..Default::default()
+                }
+            );
+        }
+
+        #[test]
+        fn field_get_doc() {
+            assert_getset_ok!(
+                #[getset(get = "pub doc=\"Returns the balance\" with_prefix")],
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::Get, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            with_prefix: true,
+                            doc: Some("Returns the balance".to_owned()),
+                            ..Default::default()
+                        }),
+                    ]),
+                    ..Default::default()
+                }
+            );
+        }
+
+        #[test]
+        fn field_get_pinned() {
+            assert_getset_ok!(
+                #[getset(get = "pub pinned")],
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::Get, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            getter: GetterKind::Pinned,
+                            ..Default::default()
+                        }),
+                    ]),
+                    ..Default::default()
+                }
+            );
+        }
+
+        #[test]
+        fn field_get_raw_ptr() {
+            assert_getset_ok!(
+                #[getset(get = "pub raw_ptr")],
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::Get, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            getter: GetterKind::RawPtr,
+                            ..Default::default()
+                        }),
+                    ]),
+                    ..Default::default()
+                }
+            );
+        }
+
+        #[test]
+        fn strip_pinned_getters() {
+            let mut attrs = parse_attributes(quote! {
+                #[getset(get = "pub pinned")]
+                #[getset(get_copy = "pub")]
+            });
+            super::super::strip_ffi_accessors(&mut attrs);
+
+            assert_eq!(
+                attrs,
+                parse_attributes(quote! {
+                    #[getset(skip(get))]
+                    #[getset(get_copy = "pub")]
+                })
+            );
+        }
+
+        #[test]
+        fn strip_raw_ptr_getters() {
+            let mut attrs = parse_attributes(quote! {
+                #[getset(get = "pub raw_ptr")]
+                #[getset(set = "pub")]
+            });
+            super::super::strip_ffi_accessors(&mut attrs);
+
+            assert_eq!(
+                attrs,
+                parse_attributes(quote! {
+                    #[getset(skip(get))]
+                    #[getset(set = "pub")]
+                })
+            );
+        }
+
+        #[test]
+        fn strip_try_setters() {
+            let mut attrs = parse_attributes(quote! {
+                #[getset(get = "pub", set = "pub try validate=validate_name error=NameError")]
+            });
+            super::super::strip_ffi_accessors(&mut attrs);
+
+            assert_eq!(
+                attrs,
+                parse_attributes(quote! {
+                    #[getset(get = "pub", skip(set))]
+                })
+            );
+        }
+
+        #[test]
+        fn field_set_try() {
+            assert_getset_ok!(
+                #[getset(set = "pub try validate=crate::validate_name error=crate::NameError")],
+                GetSetFieldAttrs {
+                    gen: FxHashMap::from_iter([
+                        (GetSetGenMode::Set, GetSetOptions {
+                            visibility: Some(parse_quote! { pub }),
+                            setter: SetterKind::Try,
+                            validate: Some(parse_quote! { crate::validate_name }),
+                            error: Some(parse_quote! { crate::NameError }),
+                            ..Default::default()
+                        }),
+                    ]),
+                    ..Default::default()
+                }
+            );
+        }
+
+        #[test]
+        fn literal_source_offset() {
+            let repr = r#""pub doc=\"Balance\" aboba""#;
+            let value = "pub doc=\"Balance\" aboba";
+
+            let start = value.find("aboba").unwrap();
+            assert_eq!(
+                super::super::literal_source_offset(repr, start),
+                repr.find("aboba")
+            );
+            assert_eq!(super::super::literal_source_offset(repr, 0), Some(1));
+            assert_eq!(
+                super::super::literal_source_offset(r#""\u{61}boba""#, 1),
+                None
+            );
+            assert_eq!(super::super::literal_source_offset(r#"r"aboba""#, 1), None);
+        }
+
+        #[test]
+        fn strip_ffi_options() {
+            let mut attrs = parse_attributes(quote! {
+                #[getset(get = "pub doc=\"Returns the balance\"", set = "doc=\"Sets it\" no_inline")]
+                #[getset(get_mut = "pub(crate)")]
+                #[getset(get = "pub(in crate::some::path)  with_prefix")]
+            });
+            super::super::strip_ffi_options(&mut attrs);
+
+            assert_eq!(
+                attrs,
+                parse_attributes(quote! {
+                    #[getset(get = "pub", set)]
+                    #[getset(get_mut = "pub(crate)")]
+                    #[getset(get = "pub(in\tcrate::some::path) with_prefix")]
+                })
+            );
+        }
+
+        macro_rules! assert_getset_err {
+        ($( #[$meta:meta] )*, $ty:ident, $error:expr) => {
+            assert_eq!(
+                $ty::from_attributes(&parse_attributes(quote! {
+                    $( #[$meta] )*
+                }))
+                .unwrap_err()
+                .to_string(),
+                $error,
+                "The error message does not match the expected one"
+            )
+        };
+    }
+
+        #[test]
+        fn err_unknown_token() {
+            assert_getset_err!(
+                #[getset(unknown_token)],
+                GetSetStructAttrs,
+                "expected one of `get`, `get_both`, `get_copy`, `get_cow`, `get_deref`, `get_discriminant`, `get_mut`, `get_slice`, `inline`, `set`, `skip`, `view`"
+            );
+        }
+
+        #[test]
+        fn err_get_cow_with_get() {
+            assert_getset_err!(
+                #[getset(get = "pub", get_cow = "pub")],
+                GetSetFieldAttrs,
+                "`get_cow` can't be combined with `get`, they generate the same method"
+            );
+        }
+
+        #[test]
+        fn err_get_cow_with_get_copy() {
+            assert_getset_err!(
+                #[getset(get_cow)]
+                #[getset(get_copy)],
+                GetSetStructAttrs,
+                "`get_cow` can't be combined with `get_copy`, they generate the same method"
+            );
+        }
+
+        #[test]
+        fn err_get_discriminant_struct() {
+            assert_getset_err!(
+                #[getset(get_discriminant)],
+                GetSetStructAttrs,
+                "`get_discriminant` is not valid on a struct"
+            );
+        }
+
+        #[test]
+        fn err_get_discriminant_with_prefix() {
+            assert_getset_err!(
+                #[getset(get_discriminant = "pub with_prefix")],
+                GetSetFieldAttrs,
+                "`with_prefix` is not supported for `get_discriminant`"
+            );
+        }
+
+        #[test]
+        fn err_get_slice_struct() {
+            assert_getset_err!(
+                #[getset(get_slice)],
+                GetSetStructAttrs,
+                "`get_slice` is not valid on a struct"
+            );
+        }
+
+        #[test]
+        fn err_inline_field() {
+            assert_getset_err!(
+                #[getset(inline)],
+                GetSetFieldAttrs,
+                "`inline` is only valid on a struct, use `no_inline` option to opt out of it"
+            );
+        }
+
+        #[test]
+        fn err_view_field() {
+            assert_getset_err!(
+                #[getset(view)],
+                GetSetFieldAttrs,
+                "`view` is only valid on a struct"
+            );
+        }
+
+        #[test]
+        fn err_skip_modes_struct() {
+            assert_getset_err!(
+                #[getset(skip(set))],
+                GetSetStructAttrs,
+                "`skip` is not valid on a struct"
+            );
+        }
+
+        #[test]
+        fn err_skip_modes_requested() {
+            assert_getset_err!(
+                #[getset(set = "pub", skip(set))],
+                GetSetFieldAttrs,
+                "`getset(set)` is requested, but also skipped with `skip(set)`"
+            );
+        }
+
+        #[test]
+        fn err_skip_modes_unknown() {
+            assert_getset_err!(
+                #[getset(skip(aboba))],
+                GetSetFieldAttrs,
+                "unknown accessor `aboba` in `skip(...)`"
+            );
+        }
+
+        #[test]
+        fn expand_skip_modes() {
+            let struct_attrs = parse_attributes(quote! {
+                #[getset(get = "pub", set = "pub")]
+                #[getset(get_mut = "pub")]
+            });
+            let mut attrs = parse_attributes(quote! {
+                #[doc = " Field"]
+                #[getset(skip(set), get_mut = "pub(crate)")]
+            });
+            super::super::expand_skip_modes(&mut attrs, &struct_attrs);
+
+            assert_eq!(
+                attrs,
+                parse_attributes(quote! {
+                    #[getset(skip(set))]
+                    #[doc = " Field"]
+                    #[getset(get_mut = "pub(crate)")]
+                    #[getset(get = "pub")]
+                })
+            );
+        }
+
+        #[test]
+        fn err_skip_struct() {
+            assert_getset_err!(
+                #[getset(skip)],
+                GetSetStructAttrs,
+                "`skip` is not valid on a struct"
+            );
+        }
+
+        #[test]
+        fn err_duplicate_accessor() {
+            assert_getset_err!(
+                #[getset(get = "pub", get)],
+                GetSetStructAttrs,
+                "duplicate `getset(get)` attribute"
+            );
+        }
+
+        #[test]
+        fn err_duplicate_get_both() {
+            assert_getset_err!(
+                #[getset(get_mut, get_both)],
+                GetSetFieldAttrs,
+                "duplicate `getset(get_mut)` attribute"
+            );
+        }
+
+        #[test]
+        fn err_unknown_option() {
+            assert_getset_err!(
+                #[getset(get = "aboba")],
+                GetSetStructAttrs,
+                "Failed to parse getset options at `aboba`: expected visibility, `with_prefix`, `try`, `validate=...`, `error=...`, `no_inline`, `pinned`, `raw_ptr` or `doc=\"...\"`"
+            );
+        }
+
+        #[test]
+        fn err_invalid_visibility() {
+            assert_getset_err!(
+                #[getset(get = "pub(in) with_prefix")],
+                GetSetFieldAttrs,
+                "Failed to parse getset options at `pub(in)`: expected visibility, `with_prefix`, `try`, `validate=...`, `error=...`, `no_inline`, `pinned`, `raw_ptr` or `doc=\"...\"`"
+            );
+        }
+
+        #[test]
+        fn err_doc_unterminated_quote() {
+            assert_getset_err!(
+                #[getset(get = "pub doc=\"Returns the balance")],
+                GetSetFieldAttrs,
+                "Failed to parse getset options at `doc=\"Returns the balance`: unterminated quote"
+            );
+        }
+
+        #[test]
+        fn err_doc_unquoted() {
+            assert_getset_err!(
+                #[getset(get = "pub doc=balance")],
+                GetSetFieldAttrs,
+                "Failed to parse getset options at `doc=balance`: expected `doc=\"...\"`"
+            );
+        }
+
+        #[test]
+        fn err_pinned_set() {
+            assert_getset_err!(
+                #[getset(get = "pub pinned", set = "pub")],
+                GetSetFieldAttrs,
+                "`pinned` getter can't be combined with `set` or `get_mut`, they allow moving the field"
+            );
+        }
+
+        #[test]
+        fn err_pinned_struct() {
+            assert_getset_err!(
+                #[getset(get = "pub pinned")],
+                GetSetStructAttrs,
+                "`pinned` is only valid on a field"
+            );
+        }
+
+        #[test]
+        fn err_pinned_get_copy() {
+            assert_getset_err!(
+                #[getset(get_copy = "pub pinned")],
+                GetSetFieldAttrs,
+                "`pinned` is not supported for `get_copy`"
+            );
+        }
+
+        #[test]
+        fn err_raw_ptr_get_copy() {
+            assert_getset_err!(
+                #[getset(get_copy = "pub raw_ptr")],
+                GetSetFieldAttrs,
+                "`raw_ptr` is not supported for `get_copy`"
+            );
+        }
+
+        #[test]
+        fn err_raw_ptr_set() {
+            assert_getset_err!(
+                #[getset(set = "pub raw_ptr")],
+                GetSetFieldAttrs,
+                "`raw_ptr` is not supported for `set`"
+            );
+        }
+
+        #[test]
+        fn err_raw_ptr_pinned() {
+            assert_getset_err!(
+                #[getset(get = "pub pinned raw_ptr")],
+                GetSetFieldAttrs,
+                "`raw_ptr` can't be combined with `pinned`"
+            );
+        }
+
+        #[test]
+        fn err_raw_ptr_struct() {
+            assert_getset_err!(
+                #[getset(get = "pub raw_ptr")],
+                GetSetStructAttrs,
+                "`raw_ptr` is only valid on a field"
+            );
+        }
+
+        #[test]
+        fn err_try_and_plain_set() {
+            assert_getset_err!(
+                #[getset(set = "pub")]
+                #[getset(set = "pub try validate=validate_name error=NameError")],
+                GetSetFieldAttrs,
+                "both fallible (`try`) and plain setters are requested for the same field"
+            );
+        }
+
+        #[test]
+        fn err_try_without_validate() {
+            assert_getset_err!(
+                #[getset(set = "pub try")],
+                GetSetFieldAttrs,
+                "`try`, `validate=...` and `error=...` have to be used together"
+            );
+        }
+
+        #[test]
+        fn err_try_without_error() {
+            assert_getset_err!(
+                #[getset(set = "pub try validate=validate_name")],
+                GetSetFieldAttrs,
+                "`try`, `validate=...` and `error=...` have to be used together"
+            );
+        }
+
+        #[test]
+        fn err_try_struct() {
+            assert_getset_err!(
+                #[getset(set = "pub try validate=validate_name error=NameError")],
+                GetSetStructAttrs,
+                "`try` is only valid on a field"
+            );
+        }
+
+        #[test]
+        fn err_try_get() {
+            assert_getset_err!(
+                #[getset(get = "pub try validate=validate_name error=NameError")],
+                GetSetFieldAttrs,
+                "`try`, `validate` and `error` are not supported for `get`"
+            );
+        }
+
+        #[test]
+        fn err_validate_invalid_path() {
+            assert_getset_err!(
+                #[getset(set = "pub try validate=1 error=NameError")],
+                GetSetFieldAttrs,
+                "Failed to parse getset options at `validate=1`: expected `validate=path::to::fn`"
+            );
+        }
+    }
+    mod inheritance {
+        use darling::FromAttributes;
+        use proc_macro2::TokenStream;
+        use quote::quote;
+        use syn::parse_quote;
+
+        use super::{
+            GetSetFieldAttrs, GetSetGenMode, GetSetOptions, GetSetStructAttrs, RequestedAccessors,
+            SetterKind,
+        };
+        use crate::attr_parse::derive::DeriveAttrs;
+
+        fn get_field_derives(
+            derive: TokenStream,
+            struct_attr: TokenStream,
+            field_attr: TokenStream,
+        ) -> RequestedAccessors {
+            fn parse_attributes<T: FromAttributes>(ts: TokenStream) -> T {
+                let attrs = crate::parse_attributes(ts);
+                T::from_attributes(&attrs).expect("Failed to parse attributes")
+            }
+
+            let derive = parse_attributes::<DeriveAttrs>(derive);
+            let struct_attr = parse_attributes::<GetSetStructAttrs>(struct_attr);
+            let field_attr = parse_attributes::<GetSetFieldAttrs>(field_attr);
+
+            field_attr.get_field_accessors(&derive, &struct_attr)
+        }
+
+        macro_rules! assert_getset_ok {
+            (
+                $( #[$derive:meta] )*,
+                $( #[$struct_attr:meta] )*,
+                $( #[$field_attr:meta] )*,
+                $expected:expr
+            ) => {
+                assert_eq!(
+                    get_field_derives(
+                        quote! { $( #[$derive] )* },
+                        quote! { $( #[$struct_attr] )* },
+                        quote! { $( #[$field_attr] )* },
+                    ),
+                    $expected
+                )
+            };
+        }
+
+        #[test]
+        fn getset_basic() {
+            assert_getset_ok!(
+                #[derive(Getters, Setters)],
+                ,
+                #[getset(get, set)],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions::default()),
+                    (GetSetGenMode::Set, GetSetOptions::default()),
+                ])
+            );
+        }
+
+        #[test]
+        fn getset_derive_disabled() {
+            // no Setters - no Set generated
+            assert_getset_ok!(
+                #[derive(Getters)],
+                ,
+                #[getset(get, set)],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions::default())
+                ])
+            );
+        }
+
+        #[test]
+        fn getset_inherit() {
+            assert_getset_ok!(
+                #[derive(Getters, Setters)],
+                #[getset(get)],
+                #[getset(set)],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions::default()),
+                    (GetSetGenMode::Set, GetSetOptions::default()),
+                ])
+            );
+        }
+
+        #[test]
+        fn skip_modes_inherit() {
+            assert_getset_ok!(
+                #[derive(Getters, Setters)],
+                #[getset(get, set)],
+                #[getset(skip(set))],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions::default()),
+                ])
+            );
+            assert_getset_ok!(
+                #[derive(Getters, Setters, MutGetters)],
+                #[getset(get = "pub", set = "pub", get_mut = "pub")],
+                #[getset(skip(set, get_mut))],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        ..Default::default()
+                    }),
+                ])
+            );
+        }
+
+        #[test]
+        fn getset_overwrite_visibility() {
+            assert_getset_ok!(
+                #[derive(Getters, Setters)],
+                #[getset(get = "pub(crate)", set = "pub(crate)")],
+                #[getset(set = "pub")],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions {
+                        visibility: Some(parse_quote! { pub(crate) }),
+                        ..Default::default()
+                    }),
+                    (GetSetGenMode::Set, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        ..Default::default()
+                    }),
+                ])
+            );
+        }
+
+        #[test]
+        fn inherit_with_prefix() {
+            assert_getset_ok!(
+                #[derive(Getters, CopyGetters)],
+                #[getset(get = "with_prefix", get_copy = "pub")],
                 #[getset(get = "pub", get_copy = "pub(crate) with_prefix")],
                 RequestedAccessors::from_iter([
                     (GetSetGenMode::Get, GetSetOptions {
                         visibility: Some(parse_quote! { pub }),
                         with_prefix: true,
+                        ..Default::default()
                     }),
                     (GetSetGenMode::GetCopy, GetSetOptions {
                         visibility: Some(parse_quote! { pub(crate) }),
                         with_prefix: true,
+                        ..Default::default()
+                    }),
+                ])
+            );
+        }
+
+        #[test]
+        fn get_deref_inherit() {
+            assert_getset_ok!(
+                #[derive(Getters, DerefGetters)],
+                #[getset(get = "pub", get_deref = "pub(crate) with_prefix")],
+                #[getset(get_deref = "pub")],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        ..Default::default()
+                    }),
+                    (GetSetGenMode::GetDeref, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        with_prefix: true,
+                        ..Default::default()
+                    }),
+                ])
+            );
+        }
+
+        #[test]
+        fn get_deref_derive_disabled() {
+            // no DerefGetters - no GetDeref generated
+            assert_getset_ok!(
+                #[derive(Getters)],
+                ,
+                #[getset(get, get_deref)],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions::default())
+                ])
+            );
+        }
+
+        #[test]
+        fn get_cow_inherit() {
+            assert_getset_ok!(
+                #[derive(CowGetters, Setters)],
+                #[getset(get_cow = "pub(crate) with_prefix", set = "pub")],
+                #[getset(get_cow = "pub")],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::GetCow, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        with_prefix: true,
+                        ..Default::default()
+                    }),
+                    (GetSetGenMode::Set, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        ..Default::default()
+                    }),
+                ])
+            );
+        }
+
+        #[test]
+        fn get_cow_replaces_inherited_get() {
+            assert_getset_ok!(
+                #[derive(Getters, CowGetters)],
+                #[getset(get = "pub")],
+                #[getset(get_cow = "pub")],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::GetCow, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        ..Default::default()
+                    }),
+                ])
+            );
+        }
+
+        #[test]
+        fn get_copy_replaces_inherited_get_cow() {
+            assert_getset_ok!(
+                #[derive(CopyGetters, CowGetters)],
+                #[getset(get_cow = "pub")],
+                #[getset(get_copy = "pub")],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::GetCopy, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        ..Default::default()
+                    }),
+                ])
+            );
+        }
+
+        #[test]
+        fn get_cow_derive_disabled() {
+            // no CowGetters - no GetCow generated
+            assert_getset_ok!(
+                #[derive(Getters)],
+                ,
+                #[getset(get_cow)],
+                RequestedAccessors::default()
+            );
+            // the inherited `get` is still skipped for the field
+            assert_getset_ok!(
+                #[derive(Getters)],
+                #[getset(get = "pub")],
+                #[getset(get_cow)],
+                RequestedAccessors::default()
+            );
+        }
+
+        #[test]
+        fn get_discriminant_without_derive() {
+            // `get_discriminant` is generated by `ffi_export`, not by the `getset` derives
+            assert_getset_ok!(
+                #[derive(Getters)],
+                ,
+                #[getset(get, get_discriminant = "pub")],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions::default()),
+                    (GetSetGenMode::GetDiscriminant, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        ..Default::default()
+                    }),
+                ])
+            );
+        }
+
+        #[test]
+        fn get_both_both_derives() {
+            assert_getset_ok!(
+                #[derive(Getters, MutGetters)],
+                ,
+                #[getset(get_both = "pub")],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        ..Default::default()
+                    }),
+                    (GetSetGenMode::GetMut, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        ..Default::default()
+                    }),
+                ])
+            );
+        }
+
+        #[test]
+        fn get_both_derive_disabled() {
+            // `ffi_export` rejects such structs in `expand_get_both`, here the accessors are just filtered by the derives
+            // no MutGetters - no GetMut generated
+            assert_getset_ok!(
+                #[derive(Getters)],
+                ,
+                #[getset(get_both)],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions::default())
+                ])
+            );
+            // no Getters - no Get generated
+            assert_getset_ok!(
+                #[derive(MutGetters)],
+                #[getset(get_both)],
+                ,
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::GetMut, GetSetOptions::default())
+                ])
+            );
+        }
+
+        #[test]
+        fn doc_inherit() {
+            assert_getset_ok!(
+                #[derive(Getters, Setters)],
+                #[getset(get = "pub doc=\"Struct getter\"", set = "pub doc=\"Struct setter\"")],
+                #[getset(get = "pub(crate)", set = "pub doc=\"Field setter\"")],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions {
+                        visibility: Some(parse_quote! { pub(crate) }),
+                        doc: Some("Struct getter".to_owned()),
+                        ..Default::default()
+                    }),
+                    (GetSetGenMode::Set, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        doc: Some("Field setter".to_owned()),
+                        ..Default::default()
+                    }),
+                ])
+            );
+        }
+
+        #[test]
+        fn try_set_inherit() {
+            assert_getset_ok!(
+                #[derive(Setters)],
+                #[getset(set = "pub")],
+                #[getset(set = "pub try validate=validate_name error=NameError")],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Set, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        setter: SetterKind::Try,
+                        validate: Some(parse_quote! { validate_name }),
+                        error: Some(parse_quote! { NameError }),
+                        ..Default::default()
+                    }),
+                ])
+            );
+        }
+
+        #[test]
+        fn inline_inherit() {
+            assert_getset_ok!(
+                #[derive(Getters, Setters)],
+                #[getset(get = "pub", inline)],
+                #[getset(set = "pub")],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        inline: Some(true),
+                        ..Default::default()
+                    }),
+                    (GetSetGenMode::Set, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        inline: Some(true),
+                        ..Default::default()
+                    }),
+                ])
+            );
+        }
+
+        #[test]
+        fn inline_overwrite() {
+            assert_getset_ok!(
+                #[derive(Getters, Setters)],
+                #[getset(get = "pub", set = "pub", inline)],
+                #[getset(get = "pub no_inline")],
+                RequestedAccessors::from_iter([
+                    (GetSetGenMode::Get, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        inline: Some(false),
+                        ..Default::default()
+                    }),
+                    (GetSetGenMode::Set, GetSetOptions {
+                        visibility: Some(parse_quote! { pub }),
+                        inline: Some(true),
+                        ..Default::default()
                     }),
                 ])
             );
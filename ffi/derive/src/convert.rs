@@ -235,6 +235,7 @@ impl FromField for FfiTypeField {
         let doc_attrs = DocAttrs::from_attributes(&field.attrs)?;
         let ffi_type_attr = FfiTypeFieldAttr::from_attributes(&field.attrs)?;
         let getset_attr = GetSetFieldAttrs::from_attributes(&field.attrs)?;
+        getset_attr.check_field_type(&ty)?;
         Ok(Self {
             ident,
             ty,
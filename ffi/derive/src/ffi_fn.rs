@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{visit_mut::VisitMut, Ident};
@@ -7,7 +9,9 @@ use crate::{
     impl_visitor::{Arg, FnDescriptor},
 };
 
-fn prune_fn_declaration_attributes<'a>(attrs: &[&'a syn::Attribute]) -> Vec<&'a syn::Attribute> {
+fn prune_fn_declaration_attributes<'a>(
+    attrs: &'a [Cow<'_, syn::Attribute>],
+) -> Vec<&'a syn::Attribute> {
     let mut pruned = Vec::new();
 
     for attr in attrs {
@@ -15,7 +19,7 @@ fn prune_fn_declaration_attributes<'a>(attrs: &[&'a syn::Attribute]) -> Vec<&'a
             continue;
         }
 
-        pruned.push(*attr);
+        pruned.push(&**attr);
     }
 
     pruned
@@ -1,4 +1,4 @@
-use std::default::Default;
+use std::{borrow::Cow, default::Default};
 
 use darling::ast::Style;
 use iroha_macro_utils::Emitter;
@@ -11,9 +11,12 @@ use syn::{parse_quote, visit::Visit, Ident};
 use crate::{
     attr_parse::{
         derive::DeriveAttrs,
-        getset::{GetSetGenMode, GetSetStructAttrs},
+        getset::{
+            array_elem_type, GetSetGenMode, GetSetOptions, GetSetStructAttrs, GetterKind,
+            SetterKind,
+        },
     },
-    convert::{FfiTypeField, FfiTypeFields},
+    convert::{FfiTypeField, FfiTypeFields, FfiTypeInput},
     impl_visitor::{unwrap_result_type, Arg, FnDescriptor},
 };
 
@@ -50,8 +53,17 @@ pub fn gen_derived_methods<'a>(
                 // ignore non-public accessors
                 continue;
             }
+            if options.getter != GetterKind::Plain {
+                // pinned getters take `Pin<&Self>` which can't be obtained from an FFI handle,
+                // while raw pointer getters are meant to be called by hand-written FFI wrappers
+                continue;
+            }
+            if mode == GetSetGenMode::GetCow {
+                // `Cow` has no FFI representation, the owned value is left to hand-written FFI wrappers
+                continue;
+            }
 
-            let fn_ = gen_derived_method(name, field, mode);
+            let fn_ = gen_derived_method(name, field, mode, &options);
             ffi_derives.insert(fn_.sig.ident.clone(), fn_);
         }
     }
@@ -82,6 +94,7 @@ fn gen_derived_method<'ast>(
     item_name: &Ident,
     field: &'ast FfiTypeField,
     mode: GetSetGenMode,
+    options: &GetSetOptions,
 ) -> FnDescriptor<'ast> {
     let handle_name = Ident::new("__handle", proc_macro2::Span::call_site());
     let field_name = field
@@ -89,49 +102,73 @@ fn gen_derived_method<'ast>(
         .as_ref()
         .expect("BUG: Field name not defined")
         .clone();
-    let sig = gen_derived_method_sig(field, mode);
+    let try_error = options
+        .error
+        .as_ref()
+        .filter(|_| options.setter == SetterKind::Try);
+    let sig = try_error.map_or_else(
+        || gen_derived_method_sig(field, mode),
+        |error| gen_try_setter_sig(field, error),
+    );
     let self_ty = Some(parse_quote! {#item_name});
 
-    let doc = field.doc_attrs.attrs.iter().collect();
+    // documentation given in the getset options takes precedence over the field documentation
+    let doc = options.doc.as_deref().map_or_else(
+        || field.doc_attrs.attrs.iter().map(Cow::Borrowed).collect(),
+        |doc| {
+            // NOTE: [#docs = "some_doc"] expands to ///some_doc, therefore the leading space
+            let doc = format!(" {doc}");
+            vec![Cow::Owned(parse_quote! { #[doc = #doc] })]
+        },
+    );
 
     let field_ty = &field.ty;
     let (receiver, input_args, output_arg) = match mode {
         GetSetGenMode::Set => (
             Arg::new(self_ty.clone(), handle_name, parse_quote! {&mut Self}),
             vec![Arg::new(self_ty.clone(), field_name, field_ty.clone())],
-            None,
-        ),
-        GetSetGenMode::Get => (
-            Arg::new(self_ty.clone(), handle_name, parse_quote! {&Self}),
-            Vec::new(),
-            Some(Arg::new(
-                self_ty.clone(),
-                field_name,
-                parse_quote!(& #field_ty),
-            )),
-        ),
-        GetSetGenMode::GetCopy => (
-            Arg::new(self_ty.clone(), handle_name, parse_quote! {&Self}),
-            Vec::new(),
-            Some(Arg::new(
-                self_ty.clone(),
-                field_name,
-                parse_quote!(#field_ty),
-            )),
-        ),
-        GetSetGenMode::GetMut => (
-            Arg::new(self_ty.clone(), handle_name, parse_quote! {&mut Self}),
-            Vec::new(),
-            Some(Arg::new(
-                self_ty.clone(),
-                field_name,
-                parse_quote!(&mut #field_ty),
-            )),
+            try_error.map(|error| {
+                Arg::new(
+                    self_ty.clone(),
+                    Ident::new("__output", proc_macro2::Span::call_site()),
+                    parse_quote!(core::result::Result<(), #error>),
+                )
+            }),
         ),
+        GetSetGenMode::GetCow => unreachable!("BUG: `get_cow` getters are not exported"),
+        GetSetGenMode::Get
+        | GetSetGenMode::GetCopy
+        | GetSetGenMode::GetDeref
+        | GetSetGenMode::GetMut
+        | GetSetGenMode::GetDiscriminant
+        | GetSetGenMode::GetSlice => {
+            let receiver_ty = if mode == GetSetGenMode::GetMut {
+                parse_quote! {&mut Self}
+            } else {
+                parse_quote! {&Self}
+            };
+            // getters return the field through the FFI output argument
+            let syn::ReturnType::Type(_, output_ty) = &sig.output else {
+                unreachable!("BUG: Getter signature without an output")
+            };
+
+            (
+                Arg::new(self_ty.clone(), handle_name, receiver_ty),
+                Vec::new(),
+                Some(Arg::new(self_ty.clone(), field_name, (**output_ty).clone())),
+            )
+        }
+    };
+
+    // accessors of a struct marked with `#[getset(inline)]` get `#[inline]` FFI functions, unless opted out of with `no_inline`
+    let attrs = if options.inline == Some(true) {
+        vec![Cow::Owned(parse_quote! { #[inline] })]
+    } else {
+        Vec::new()
     };
 
     FnDescriptor {
-        attrs: Vec::new(),
+        attrs,
         self_ty,
         doc,
         sig,
@@ -148,8 +185,13 @@ fn gen_derived_method_sig(field: &FfiTypeField, mode: GetSetGenMode) -> syn::Sig
     let method_name = Ident::new(
         &match mode {
             GetSetGenMode::Set => format!("set_{field_name}"),
-            GetSetGenMode::Get | GetSetGenMode::GetCopy => format!("{field_name}"),
+            GetSetGenMode::Get
+            | GetSetGenMode::GetCopy
+            | GetSetGenMode::GetDeref
+            | GetSetGenMode::GetCow => format!("{field_name}"),
             GetSetGenMode::GetMut => format!("{field_name}_mut"),
+            GetSetGenMode::GetDiscriminant => format!("{field_name}_discriminant"),
+            GetSetGenMode::GetSlice => format!("{field_name}_as_slice"),
         },
         proc_macro2::Span::call_site(),
     );
@@ -164,9 +206,447 @@ fn gen_derived_method_sig(field: &FfiTypeField, mode: GetSetGenMode) -> syn::Sig
         GetSetGenMode::GetCopy => parse_quote! {
             fn #method_name(&self) -> #field_ty
         },
+        GetSetGenMode::GetDeref => parse_quote! {
+            fn #method_name(&self) -> &<#field_ty as core::ops::Deref>::Target
+        },
+        GetSetGenMode::GetCow => parse_quote! {
+            fn #method_name(&self) -> std::borrow::Cow<'_, #field_ty>
+        },
         GetSetGenMode::GetMut => parse_quote! {
             fn #method_name(&mut self) -> &mut #field_ty
         },
+        GetSetGenMode::GetDiscriminant => parse_quote! {
+            fn #method_name(&self) -> u8
+        },
+        GetSetGenMode::GetSlice => {
+            let elem_ty = array_elem_type(field_ty).expect("BUG: Field is not an array");
+            parse_quote! {
+                fn #method_name(&self) -> &[#elem_ty]
+            }
+        }
+    }
+}
+
+fn gen_try_setter_sig(field: &FfiTypeField, error: &syn::Type) -> syn::Signature {
+    let field_name = field.ident.as_ref().expect("BUG: Field name not defined");
+    let field_ty = &field.ty;
+    let method_name = Ident::new(&format!("set_{field_name}"), proc_macro2::Span::call_site());
+
+    parse_quote! {
+        fn #method_name(&mut self, #field_name: #field_ty) -> core::result::Result<(), #error>
+    }
+}
+
+/// `#[inline(always)]` of an accessor generated by `iroha_ffi_derive` itself, omitted if opted out of with `no_inline`
+fn gen_inline_attr(options: &GetSetOptions) -> TokenStream {
+    if options.inline == Some(false) {
+        return quote! {};
+    }
+
+    quote! { #[inline(always)] }
+}
+
+/// Generate inherent methods for `#[getset(get_discriminant)]` fields.
+///
+/// These are not provided by the `getset` crate. Field type must be a `Copy` fieldless enum
+pub fn gen_discriminant_getters(
+    name: &Ident,
+    generics: &syn::Generics,
+    fields: &FfiTypeFields,
+) -> TokenStream {
+    let getters: Vec<_> = fields
+        .iter()
+        .filter(|field| !field.getset_attr.skip)
+        .filter_map(|field| {
+            let options = field.getset_attr.gen.get(&GetSetGenMode::GetDiscriminant)?;
+            let field_name = field.ident.as_ref()?;
+            let vis = &options.visibility;
+            let inline = gen_inline_attr(options);
+            let sig = gen_derived_method_sig(field, GetSetGenMode::GetDiscriminant);
+            let doc = format!(" Discriminant of the `{field_name}` field");
+
+            Some(quote! {
+                #[doc = #doc]
+                #inline
+                #vis #sig {
+                    self.#field_name as u8
+                }
+            })
+        })
+        .collect();
+
+    if getters.is_empty() {
+        return quote! {};
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#getters)*
+        }
+    }
+}
+
+/// Generate inherent methods for `#[getset(get_slice)]` fields.
+///
+/// These are not provided by the `getset` crate. Field type must be an array
+pub fn gen_slice_getters(
+    name: &Ident,
+    generics: &syn::Generics,
+    fields: &FfiTypeFields,
+) -> TokenStream {
+    let getters: Vec<_> = fields
+        .iter()
+        .filter(|field| !field.getset_attr.skip)
+        .filter_map(|field| {
+            let options = field.getset_attr.gen.get(&GetSetGenMode::GetSlice)?;
+            let field_name = field.ident.as_ref()?;
+            let vis = &options.visibility;
+            let inline = gen_inline_attr(options);
+            let sig = gen_derived_method_sig(field, GetSetGenMode::GetSlice);
+            let doc = format!(" Elements of the `{field_name}` field as a slice");
+
+            Some(quote! {
+                #[doc = #doc]
+                #inline
+                #vis #sig {
+                    &self.#field_name
+                }
+            })
+        })
+        .collect();
+
+    if getters.is_empty() {
+        return quote! {};
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#getters)*
+        }
+    }
+}
+
+/// Generate inherent methods for `#[getset(get_deref)]` fields of a structure deriving `DerefGetters`.
+///
+/// These are not provided by the `getset` crate, `DerefGetters` is only a marker
+pub fn gen_deref_getters(input: &FfiTypeInput, fields: &FfiTypeFields) -> TokenStream {
+    gen_inherited_getters(input, fields, GetSetGenMode::GetDeref, |field_name| {
+        quote! { core::ops::Deref::deref(&self.#field_name) }
+    })
+}
+
+/// Generate inherent methods for `#[getset(get_cow)]` fields of a structure deriving `CowGetters`.
+///
+/// These are not provided by the `getset` crate, `CowGetters` is only a marker. Field type must implement `ToOwned`
+pub fn gen_cow_getters(input: &FfiTypeInput, fields: &FfiTypeFields) -> TokenStream {
+    gen_inherited_getters(input, fields, GetSetGenMode::GetCow, |field_name| {
+        quote! { std::borrow::Cow::Borrowed(&self.#field_name) }
+    })
+}
+
+/// Generate inherent getters of the given mode, taking into account the accessors inherited from the structure
+fn gen_inherited_getters(
+    input: &FfiTypeInput,
+    fields: &FfiTypeFields,
+    mode: GetSetGenMode,
+    gen_body: impl Fn(&Ident) -> TokenStream,
+) -> TokenStream {
+    let getters: Vec<_> = fields
+        .iter()
+        .filter_map(|field| {
+            let accessors = field
+                .getset_attr
+                .get_field_accessors(&input.derive_attr, &input.getset_attr);
+            let options = accessors.get(&mode)?;
+            let field_name = field.ident.as_ref()?;
+            let vis = &options.visibility;
+            let inline = gen_inline_attr(options);
+            let mut sig = gen_derived_method_sig(field, mode);
+            if options.with_prefix {
+                sig.ident = Ident::new(&format!("get_{}", sig.ident), sig.ident.span());
+            }
+            let doc = options.doc.as_ref().map_or_else(
+                || {
+                    let attrs = &field.doc_attrs.attrs;
+                    quote! { #(#attrs)* }
+                },
+                |doc| {
+                    let doc = format!(" {doc}");
+                    quote! { #[doc = #doc] }
+                },
+            );
+            let body = gen_body(field_name);
+
+            Some(quote! {
+                #doc
+                #inline
+                #vis #sig {
+                    #body
+                }
+            })
+        })
+        .collect();
+
+    if getters.is_empty() {
+        return quote! {};
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#getters)*
+        }
+    }
+}
+
+/// Generate inherent methods for `#[getset(get = "pinned")]` fields.
+///
+/// These are not provided by the `getset` crate. The fields are structurally pinned,
+/// which is why `pinned` can't be combined with accessors handing out `&mut` to the field
+pub fn gen_pinned_getters(
+    name: &Ident,
+    generics: &syn::Generics,
+    fields: &FfiTypeFields,
+) -> TokenStream {
+    let getters: Vec<_> = fields
+        .iter()
+        .filter(|field| !field.getset_attr.skip)
+        .filter_map(|field| {
+            let options = field
+                .getset_attr
+                .gen
+                .get(&GetSetGenMode::Get)
+                .filter(|options| options.getter == GetterKind::Pinned)?;
+            let field_name = field.ident.as_ref()?;
+            let field_ty = &field.ty;
+            let vis = &options.visibility;
+            let inline = gen_inline_attr(options);
+            let doc = options.doc.as_ref().map_or_else(
+                || {
+                    let attrs = &field.doc_attrs.attrs;
+                    quote! { #(#attrs)* }
+                },
+                |doc| {
+                    let doc = format!(" {doc}");
+                    quote! { #[doc = #doc] }
+                },
+            );
+
+            Some(quote! {
+                #doc
+                #inline
+                #vis fn #field_name(self: core::pin::Pin<&Self>) -> core::pin::Pin<&#field_ty> {
+                    // SAFETY: the field is structurally pinned, no accessor moving it out is generated
+                    unsafe { self.map_unchecked(|this| &this.#field_name) }
+                }
+            })
+        })
+        .collect();
+
+    if getters.is_empty() {
+        return quote! {};
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#getters)*
+        }
+    }
+}
+
+/// Generate inherent methods for `#[getset(get = "raw_ptr")]` fields.
+///
+/// These are not provided by the `getset` crate. They are `unsafe` since nothing ties
+/// the returned pointer to the lifetime of the borrow of the structure
+pub fn gen_raw_ptr_getters(
+    name: &Ident,
+    generics: &syn::Generics,
+    fields: &FfiTypeFields,
+) -> TokenStream {
+    let getters: Vec<_> = fields
+        .iter()
+        .filter(|field| !field.getset_attr.skip)
+        .filter_map(|field| {
+            let options = field
+                .getset_attr
+                .gen
+                .get(&GetSetGenMode::Get)
+                .filter(|options| options.getter == GetterKind::RawPtr)?;
+            let field_name = field.ident.as_ref()?;
+            let field_ty = &field.ty;
+            let vis = &options.visibility;
+            let inline = gen_inline_attr(options);
+            let doc = options.doc.as_ref().map_or_else(
+                || {
+                    let attrs = &field.doc_attrs.attrs;
+                    quote! { #(#attrs)* }
+                },
+                |doc| {
+                    let doc = format!(" {doc}");
+                    quote! { #[doc = #doc] }
+                },
+            );
+
+            Some(quote! {
+                #doc
+                ///
+                /// # Safety
+                ///
+                /// The returned pointer is valid only until the structure is moved or dropped.
+                /// It must not be written through while the structure is borrowed elsewhere
+                #inline
+                #vis unsafe fn #field_name(&self) -> core::ptr::NonNull<#field_ty> {
+                    core::ptr::NonNull::from(&self.#field_name)
+                }
+            })
+        })
+        .collect();
+
+    if getters.is_empty() {
+        return quote! {};
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#getters)*
+        }
+    }
+}
+
+/// Generate inherent methods for `#[getset(set = "try validate=... error=...")]` fields.
+///
+/// These are not provided by the `getset` crate. The field is only assigned if `validate` accepts the new value,
+/// otherwise the error returned by it is propagated
+pub fn gen_try_setters(
+    name: &Ident,
+    generics: &syn::Generics,
+    fields: &FfiTypeFields,
+) -> TokenStream {
+    let setters: Vec<_> = fields
+        .iter()
+        .filter(|field| !field.getset_attr.skip)
+        .filter_map(|field| {
+            let options = field
+                .getset_attr
+                .gen
+                .get(&GetSetGenMode::Set)
+                .filter(|options| options.setter == SetterKind::Try)?;
+            let (validate, error) = (options.validate.as_ref()?, options.error.as_ref()?);
+            let field_name = field.ident.as_ref()?;
+            let vis = &options.visibility;
+            let inline = gen_inline_attr(options);
+            let sig = gen_try_setter_sig(field, error);
+            let doc = options.doc.as_ref().map_or_else(
+                || {
+                    let attrs = &field.doc_attrs.attrs;
+                    quote! { #(#attrs)* }
+                },
+                |doc| {
+                    let doc = format!(" {doc}");
+                    quote! { #[doc = #doc] }
+                },
+            );
+
+            Some(quote! {
+                #doc
+                ///
+                /// # Errors
+                ///
+                /// Fails if the new value is rejected by the validation function
+                #inline
+                #vis #sig {
+                    #validate(&#field_name)?;
+                    self.#field_name = #field_name;
+                    Ok(())
+                }
+            })
+        })
+        .collect();
+
+    if setters.is_empty() {
+        return quote! {};
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#setters)*
+        }
+    }
+}
+
+/// Generate a `{Struct}View<'a>` struct borrowing all the fields exposed through `get`,
+/// along with a conversion from `&Struct`
+pub fn gen_view(
+    emitter: &mut Emitter,
+    input: &FfiTypeInput,
+    fields: &FfiTypeFields,
+) -> TokenStream {
+    if !input.getset_attr.view {
+        return quote! {};
+    }
+    if !input.generics.params.is_empty() {
+        emit!(
+            emitter,
+            input.generics,
+            "`view` is not supported on generic structs"
+        );
+        return quote! {};
+    }
+    match fields.style {
+        Style::Struct => {}
+        Style::Tuple | Style::Unit => {
+            emit!(emitter, "Only named structs supported");
+            return quote! {};
+        }
+    }
+
+    let (view_fields, field_names): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .filter_map(|field| {
+            let accessors = field
+                .getset_attr
+                .get_field_accessors(&input.derive_attr, &input.getset_attr);
+            let options = accessors.get(&GetSetGenMode::Get)?;
+            let field_name = field.ident.as_ref()?;
+            let (vis, doc, ty) = (&options.visibility, &field.doc_attrs.attrs, &field.ty);
+
+            Some((quote! { #(#doc)* #vis #field_name: &'a #ty }, field_name))
+        })
+        .unzip();
+
+    let name = &input.ident;
+    if field_names.is_empty() {
+        emit!(
+            emitter,
+            name,
+            "`view` requires at least one field exposed through `get`"
+        );
+        return quote! {};
+    }
+
+    let vis = &input.vis;
+    let view_name = Ident::new(&format!("{name}View"), proc_macro2::Span::call_site());
+    let doc = format!(" Borrowed view of the [`{name}`] fields exposed through getters");
+    quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy)]
+        #vis struct #view_name<'a> {
+            #(#view_fields),*
+        }
+
+        impl<'a> From<&'a #name> for #view_name<'a> {
+            fn from(source: &'a #name) -> Self {
+                Self {
+                    #(#field_names: &source.#field_names),*
+                }
+            }
+        }
     }
 }
 
@@ -189,3 +669,60 @@ impl<'itm> Visit<'itm> for FfiTypeResolver<'itm> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use darling::{ast::Data, FromDeriveInput};
+    use iroha_macro_utils::Emitter;
+    use quote::quote;
+    use rustc_hash::FxHashMap;
+    use syn::parse_quote;
+
+    use super::gen_derived_methods;
+    use crate::{convert::FfiTypeInput, ffi_fn};
+
+    #[test]
+    fn derived_methods_inline() {
+        let input = FfiTypeInput::from_derive_input(&parse_quote! {
+            #[derive(Getters, Setters)]
+            #[getset(get = "pub", set = "pub", inline)]
+            pub struct FfiStruct {
+                /// Inlined
+                inlined: u32,
+                /// Not inlined
+                #[getset(get = "pub no_inline")]
+                not_inlined: u32,
+            }
+        })
+        .expect("Valid input");
+        let Data::Struct(fields) = &input.data else {
+            unreachable!("Parsed a struct above");
+        };
+
+        let mut emitter = Emitter::new();
+        let inlined = gen_derived_methods(
+            &mut emitter,
+            &input.ident,
+            &input.derive_attr,
+            &input.getset_attr,
+            fields,
+        )
+        .map(|fn_| {
+            let definition = ffi_fn::gen_definition(&fn_, None).to_string();
+            let inline = quote! { #[inline] }.to_string();
+            (fn_.sig.ident.to_string(), definition.contains(&inline))
+        })
+        .collect::<FxHashMap<_, _>>();
+        emitter.finish().expect("No errors emitted");
+
+        assert_eq!(
+            inlined,
+            FxHashMap::from_iter([
+                ("inlined".to_owned(), true),
+                ("set_inlined".to_owned(), true),
+                ("not_inlined".to_owned(), false),
+                ("set_not_inlined".to_owned(), true),
+            ])
+        );
+    }
+}
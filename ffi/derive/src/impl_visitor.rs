@@ -2,6 +2,8 @@
 //!
 //! It also defines descriptors - types that are used for the codegen step
 
+use std::borrow::Cow;
+
 use iroha_macro_utils::Emitter;
 use manyhow::emit;
 use proc_macro2::Span;
@@ -85,13 +87,13 @@ pub struct ImplDescriptor<'ast> {
 
 pub struct FnDescriptor<'ast> {
     /// Function attributes
-    pub attrs: Vec<&'ast Attribute>,
+    pub attrs: Vec<Cow<'ast, Attribute>>,
     /// Resolved type of the `Self` type
     pub self_ty: Option<Path>,
 
     /// Function documentation
     // TODO: Could just be a part of all attrs?
-    pub doc: Vec<&'ast Attribute>,
+    pub doc: Vec<Cow<'ast, Attribute>>,
     /// Original signature of the method
     pub sig: syn::Signature,
 
@@ -187,8 +189,8 @@ impl<'ast> FnDescriptor<'ast> {
             return None;
         }
         Some(Self {
-            attrs: visitor.attrs,
-            doc: visitor.doc,
+            attrs: visitor.attrs.into_iter().map(Cow::Borrowed).collect(),
+            doc: visitor.doc.into_iter().map(Cow::Borrowed).collect(),
             self_ty: visitor.self_ty.cloned(),
 
             sig: visitor.sig.expect("Missing signature").clone(),
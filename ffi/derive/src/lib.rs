@@ -9,7 +9,13 @@ use syn::Item;
 use wrapper::wrap_method;
 
 use crate::{
-    attr_parse::derive::Derive,
+    attr_parse::{
+        derive::Derive,
+        getset::{
+            expand_get_both, expand_skip_modes, strip_ffi_accessors, strip_ffi_marker_derives,
+            strip_ffi_options, strip_getset_token,
+        },
+    },
     convert::{derive_ffi_type, FfiTypeData, FfiTypeInput},
 };
 
@@ -223,6 +229,29 @@ pub fn ffi_type_derive(input: TokenStream) -> TokenStream {
 /// When placed on a structure, it integrates with [`getset`] to export derived getter/setter methods.
 /// To be visible this attribute must be placed before/on top of any [`getset`] derive macro attributes
 ///
+/// Fields of a fieldless `Copy` enum type can be marked with `#[getset(get_discriminant = "pub")]`
+/// to generate a `<field>_discriminant` accessor which returns the discriminant by value as `u8`
+///
+/// Array fields can be marked with `#[getset(get_slice = "pub")]` to generate a `<field>_as_slice`
+/// accessor which returns the elements of the array as a slice
+///
+/// Fields which must not move can be marked with `#[getset(get = "pub pinned")]` to generate
+/// an accessor taking `Pin<&Self>` and returning `Pin<&Field>`. It can't be combined with `set` or `get_mut`
+///
+/// Fields can be marked with `#[getset(get = "pub raw_ptr")]` to generate an `unsafe` accessor
+/// returning `NonNull<Field>` for raw C interop. It is only supported for `get`
+///
+/// A setter can be made fallible with `#[getset(set = "pub try validate=path::to::fn error=path::to::Error")]`.
+/// The generated setter returns `Result<(), Error>` and only assigns the field if `fn(&Field) -> Result<(), Error>` accepts it
+///
+/// A structure can be marked with `#[getset(view)]` to generate a `<Struct>View<'a>` struct
+/// holding references to all the fields exposed through `get`, constructible with `From<&Struct>`
+///
+/// Individual accessors inherited from the structure can be skipped on a field with e.g. `#[getset(skip(set))]`
+///
+/// A structure can be marked with `#[getset(inline)]` to request all of its accessors and their FFI functions be `#[inline]`,
+/// a field opts out of it with e.g. `#[getset(get = "pub no_inline")]`
+///
 /// It also works on impl blocks (by visiting all methods in the impl block) and on enums and unions (as a no-op)
 ///
 /// # Example:
@@ -329,12 +358,46 @@ pub fn ffi_export(attr: TokenStream, item: TokenStream) -> TokenStream {
                 #ffi_fn
             }
         }
-        Item::Struct(item) => {
+        Item::Struct(mut item) => {
             // re-parse as a DeriveInput to utilize darling
             let input = syn::parse2(quote!(#item)).unwrap();
             let Some(input) = emitter.handle(FfiTypeInput::from_derive_input(&input)) else {
                 return emitter.finish_token_stream();
             };
+            let darling::ast::Data::Struct(fields) = &input.data else {
+                unreachable!("We parsed struct above");
+            };
+
+            // `get_discriminant`, `get_slice`, `get_deref`, `get_cow`, `pinned`, `raw_ptr` and `try` accessors, views, inlining and `doc` options are handled here,
+            // so hide them from the `getset` crate
+            strip_ffi_marker_derives(&mut item.attrs);
+            strip_getset_token(&mut item.attrs, "view");
+            strip_getset_token(&mut item.attrs, "inline");
+            strip_getset_token(&mut item.attrs, "get_deref");
+            strip_getset_token(&mut item.attrs, "get_cow");
+            strip_ffi_options(&mut item.attrs);
+            emitter.handle(expand_get_both(&mut item.attrs, &input.derive_attr));
+            item.fields.iter_mut().for_each(|field| {
+                emitter.handle(expand_get_both(&mut field.attrs, &input.derive_attr));
+                strip_getset_token(&mut field.attrs, "get_discriminant");
+                strip_getset_token(&mut field.attrs, "get_slice");
+                strip_getset_token(&mut field.attrs, "get_deref");
+                strip_ffi_accessors(&mut field.attrs);
+                strip_ffi_options(&mut field.attrs);
+                expand_skip_modes(&mut field.attrs, &item.attrs);
+            });
+            let discriminant_getters =
+                getset_gen::gen_discriminant_getters(&input.ident, &input.generics, fields);
+            let slice_getters =
+                getset_gen::gen_slice_getters(&input.ident, &input.generics, fields);
+            let deref_getters = getset_gen::gen_deref_getters(&input, fields);
+            let cow_getters = getset_gen::gen_cow_getters(&input, fields);
+            let pinned_getters =
+                getset_gen::gen_pinned_getters(&input.ident, &input.generics, fields);
+            let raw_ptr_getters =
+                getset_gen::gen_raw_ptr_getters(&input.ident, &input.generics, fields);
+            let try_setters = getset_gen::gen_try_setters(&input.ident, &input.generics, fields);
+            let view = getset_gen::gen_view(&mut emitter, &input, fields);
 
             // we don't need ffi fns for getset accessors if the type is not opaque or there are no accessors
             if !input.is_opaque()
@@ -344,14 +407,19 @@ pub fn ffi_export(attr: TokenStream, item: TokenStream) -> TokenStream {
                     .iter()
                     .any(|d| matches!(d, Derive::GetSet(_)))
             {
-                let input = input.ast;
-                return emitter.finish_token_stream_with(quote! { #input });
+                return emitter.finish_token_stream_with(quote! {
+                    #item
+                    #discriminant_getters
+                    #slice_getters
+                    #deref_getters
+                    #cow_getters
+                    #pinned_getters
+                    #raw_ptr_getters
+                    #try_setters
+                    #view
+                });
             }
 
-            let darling::ast::Data::Struct(fields) = &input.data else {
-                unreachable!("We parsed struct above");
-            };
-
             if !input.generics.params.is_empty() {
                 emit!(
                     emitter,
@@ -372,6 +440,14 @@ pub fn ffi_export(attr: TokenStream, item: TokenStream) -> TokenStream {
 
             quote! {
                 #item
+                #discriminant_getters
+                #slice_getters
+                #deref_getters
+                #cow_getters
+                #pinned_getters
+                #raw_ptr_getters
+                #try_setters
+                #view
                 #(#derived_ffi_fns)*
             }
         }
@@ -0,0 +1,20 @@
+use core::ptr::NonNull;
+
+use getset::Getters;
+use iroha_ffi::{ffi_export, FfiType};
+
+/// FfiStruct
+#[ffi_export]
+#[derive(Clone, Getters, FfiType)]
+#[getset(get = "pub")]
+pub struct FfiStruct {
+    /// a
+    #[getset(get = "pub raw_ptr")]
+    a: i32,
+}
+
+fn main() {
+    let s = FfiStruct { a: 42 };
+
+    let _a: NonNull<i32> = s.a();
+}
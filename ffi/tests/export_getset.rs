@@ -1,23 +1,148 @@
 #![allow(unsafe_code)]
 
-use std::mem::MaybeUninit;
+use std::{borrow::Cow, mem::MaybeUninit, pin::Pin, ptr::NonNull};
 
 use getset::{Getters, MutGetters, Setters};
-use iroha_ffi::{ffi_export, FfiType};
+use iroha_ffi::{ffi_export, FfiConvert, FfiReturn, FfiType};
 
 /// Struct
 #[derive(Debug, Clone, PartialEq, Eq, FfiType)]
 pub struct Name(String);
 
+/// Fieldless enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FfiType)]
+#[repr(u8)]
+pub enum Kind {
+    /// First
+    First,
+    /// Second
+    Second,
+}
+
 /// FfiStruct
 #[ffi_export]
 #[derive(Clone, Setters, Getters, MutGetters, FfiType)]
-#[getset(get = "pub")]
+#[getset(get = "pub", view)]
 pub struct FfiStruct {
     /// id
     #[getset(set = "pub", get_mut = "pub")]
     id: u32,
     /// Name
+    #[getset(get = "doc=\"Returns the name\" pub")]
+    name: Name,
+    /// Kind
+    #[getset(get_discriminant = "pub")]
+    kind: Kind,
+}
+
+/// Struct with the setter skipped on one of the fields
+#[ffi_export]
+#[derive(Clone, Copy, Setters, Getters, FfiType)]
+#[getset(get = "pub", set = "pub", inline)]
+pub struct SkipSetStruct {
+    /// Mutable
+    #[getset(get = "pub no_inline")]
+    mutable: u32,
+    /// Read-only
+    #[getset(skip(set))]
+    read_only: u32,
+}
+
+/// Struct with an array field
+#[ffi_export]
+#[derive(Clone, Copy, Getters, FfiType)]
+#[getset(get = "pub")]
+pub struct ArrayStruct {
+    /// Bytes
+    #[getset(get_slice = "pub")]
+    bytes: [u8; 4],
+}
+
+/// Struct with a field which must not move
+#[ffi_export]
+#[derive(Clone, Getters, FfiType)]
+#[getset(get = "pub")]
+pub struct PinnedStruct {
+    /// Pinned
+    #[getset(get = "pub pinned")]
+    pinned: Name,
+    /// Unpinned
+    unpinned: u32,
+}
+
+/// Struct with a field exposed through a raw pointer
+#[ffi_export]
+#[derive(Clone, Copy, Getters, FfiType)]
+#[getset(get = "pub")]
+pub struct RawPtrStruct {
+    /// Exposed through a raw pointer
+    #[getset(get = "pub raw_ptr")]
+    raw: u32,
+    /// Exposed through a reference
+    reference: u32,
+}
+
+/// Struct with a field exposed through its `Deref` target
+#[ffi_export]
+#[derive(Clone, Getters, DerefGetters, FfiType)]
+pub struct DerefStruct {
+    /// Exposed through the boxed value
+    #[getset(get_deref = "pub")]
+    boxed: Box<Name>,
+    /// Exposed through a reference
+    #[getset(get = "pub")]
+    reference: u32,
+}
+
+/// Struct with a field exposed through a `Cow`
+#[ffi_export]
+#[derive(Clone, Getters, CowGetters, FfiType)]
+#[getset(get = "pub")]
+pub struct CowStruct {
+    /// Exposed through a `Cow`
+    #[getset(get_cow = "pub")]
+    name: Name,
+    /// Exposed through a reference
+    id: u32,
+}
+
+/// Struct with both getters requested at once
+#[ffi_export]
+#[derive(Clone, Getters, MutGetters, FfiType)]
+pub struct GetBothStruct {
+    /// Exposed through both `get` and `get_mut`
+    #[getset(get_both = "pub")]
+    id: u32,
+}
+
+/// Struct with an accessor visible in the given path only
+#[ffi_export]
+#[derive(Clone, Copy, Getters, FfiType)]
+pub struct RestrictedStruct {
+    /// Restricted
+    #[getset(get = "pub(in crate)")]
+    restricted: u32,
+}
+
+/// Error of setting an empty [`Name`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyName;
+
+fn validate_name(name: &Name) -> Result<(), EmptyName> {
+    if name.0.is_empty() {
+        return Err(EmptyName);
+    }
+
+    Ok(())
+}
+
+/// Struct with a fallible setter
+#[ffi_export]
+#[derive(Clone, Setters, Getters, FfiType)]
+#[getset(get = "pub")]
+pub struct TrySetStruct {
+    /// Non-empty name
+    #[getset(set = "pub try validate=validate_name error=EmptyName")]
     name: Name,
 }
 
@@ -28,6 +153,7 @@ fn export_getset_get() {
     let ffi_struct = &mut FfiStruct {
         id: 1,
         name: init_name.clone(),
+        kind: Kind::Second,
     };
 
     let mut id = MaybeUninit::<*mut u32>::new(core::ptr::null_mut());
@@ -47,3 +173,225 @@ fn export_getset_get() {
         assert_eq!(&init_name, name);
     }
 }
+
+#[test]
+#[webassembly_test::webassembly_test]
+fn export_getset_get_discriminant() {
+    let ffi_struct = &FfiStruct {
+        id: 1,
+        name: Name("Name".to_owned()),
+        kind: Kind::Second,
+    };
+
+    let mut kind = MaybeUninit::<u8>::uninit();
+
+    unsafe {
+        assert_eq!(1, ffi_struct.kind_discriminant());
+
+        FfiStruct__kind_discriminant(ffi_struct, kind.as_mut_ptr());
+        assert_eq!(Kind::Second as u8, kind.assume_init());
+    }
+}
+
+#[test]
+#[webassembly_test::webassembly_test]
+fn export_getset_view() {
+    let ffi_struct = FfiStruct {
+        id: 1,
+        name: Name("Name".to_owned()),
+        kind: Kind::First,
+    };
+
+    let view = FfiStructView::from(&ffi_struct);
+    assert!(core::ptr::eq(view.id, &ffi_struct.id));
+    assert!(core::ptr::eq(view.name, &ffi_struct.name));
+    assert!(core::ptr::eq(view.kind, &ffi_struct.kind));
+}
+
+#[test]
+#[webassembly_test::webassembly_test]
+fn export_getset_skip_set() {
+    let skip_set_struct = &mut SkipSetStruct {
+        mutable: 1,
+        read_only: 2,
+    };
+
+    let mut read_only = MaybeUninit::<*const u32>::new(core::ptr::null());
+
+    unsafe {
+        SkipSetStruct__set_mutable(<*mut _>::from(skip_set_struct), 3);
+        assert_eq!(&3, skip_set_struct.mutable());
+
+        SkipSetStruct__read_only(skip_set_struct, read_only.as_mut_ptr());
+        assert_eq!(&2, &*read_only.assume_init());
+    }
+}
+
+#[test]
+#[webassembly_test::webassembly_test]
+fn export_getset_pinned() {
+    let pinned_struct = PinnedStruct {
+        pinned: Name("Name".to_owned()),
+        unpinned: 1,
+    };
+
+    let pinned: Pin<&Name> = Pin::new(&pinned_struct).pinned();
+    assert!(core::ptr::eq(&*pinned, &pinned_struct.pinned));
+
+    let mut unpinned = MaybeUninit::<*const u32>::new(core::ptr::null());
+
+    unsafe {
+        PinnedStruct__unpinned(&pinned_struct, unpinned.as_mut_ptr());
+        assert_eq!(&1, &*unpinned.assume_init());
+    }
+}
+
+#[test]
+#[webassembly_test::webassembly_test]
+fn export_getset_get_slice() {
+    use iroha_ffi::slice::RefSlice;
+
+    let array_struct = &ArrayStruct {
+        bytes: [1, 2, 3, 4],
+    };
+
+    let mut bytes = MaybeUninit::<*const [u8; 4]>::new(core::ptr::null());
+    let mut slice = MaybeUninit::new(RefSlice::from_raw_parts(core::ptr::null(), 0));
+
+    unsafe {
+        let array: &[u8; 4] = array_struct.bytes();
+        assert_eq!(&[1, 2, 3, 4], array);
+        assert_eq!(&[1, 2, 3, 4], array_struct.bytes_as_slice());
+
+        ArrayStruct__bytes(array_struct, bytes.as_mut_ptr());
+        assert_eq!(&[1, 2, 3, 4], &*bytes.assume_init());
+
+        ArrayStruct__bytes_as_slice(array_struct, slice.as_mut_ptr());
+        let slice = slice.assume_init().into_rust().expect("Valid");
+        assert_eq!(&[1, 2, 3, 4], slice);
+    }
+}
+
+#[test]
+#[webassembly_test::webassembly_test]
+fn export_getset_raw_ptr() {
+    let raw_ptr_struct = RawPtrStruct {
+        raw: 1,
+        reference: 2,
+    };
+
+    // SAFETY: `raw_ptr_struct` is neither moved nor mutated while the pointer is used
+    let raw: NonNull<u32> = unsafe { raw_ptr_struct.raw() };
+    assert!(core::ptr::eq(raw.as_ptr(), &raw_ptr_struct.raw));
+    assert_eq!(&2, raw_ptr_struct.reference());
+
+    let mut reference = MaybeUninit::<*const u32>::new(core::ptr::null());
+    unsafe {
+        assert_eq!(1, *raw.as_ref());
+
+        RawPtrStruct__reference(&raw_ptr_struct, reference.as_mut_ptr());
+        assert_eq!(&2, &*reference.assume_init());
+    }
+}
+
+#[test]
+#[webassembly_test::webassembly_test]
+fn export_getset_try_set() {
+    let try_set_struct = &mut TrySetStruct {
+        name: Name("Name".to_owned()),
+    };
+
+    assert_eq!(Err(EmptyName), try_set_struct.set_name(Name(String::new())));
+    assert_eq!(&Name("Name".to_owned()), try_set_struct.name());
+    assert_eq!(Ok(()), try_set_struct.set_name(Name("Other".to_owned())));
+    assert_eq!(&Name("Other".to_owned()), try_set_struct.name());
+
+    let mut store = Default::default();
+    unsafe {
+        assert_eq!(
+            FfiReturn::ExecutionFail,
+            TrySetStruct__set_name(
+                <*mut _>::from(&mut *try_set_struct),
+                FfiConvert::into_ffi(Name(String::new()), &mut store)
+            )
+        );
+        assert_eq!(&Name("Other".to_owned()), try_set_struct.name());
+
+        let mut store = Default::default();
+        assert_eq!(
+            FfiReturn::Ok,
+            TrySetStruct__set_name(
+                <*mut _>::from(&mut *try_set_struct),
+                FfiConvert::into_ffi(Name("New".to_owned()), &mut store)
+            )
+        );
+        assert_eq!(&Name("New".to_owned()), try_set_struct.name());
+    }
+}
+
+#[test]
+#[webassembly_test::webassembly_test]
+fn export_getset_get_deref() {
+    let deref_struct = DerefStruct {
+        boxed: Box::new(Name("Name".to_owned())),
+        reference: 1,
+    };
+
+    let name: &Name = deref_struct.boxed();
+    assert!(core::ptr::eq(name, &*deref_struct.boxed));
+    assert_eq!(&1, deref_struct.reference());
+
+    let mut boxed = MaybeUninit::<*const Name>::new(core::ptr::null());
+    unsafe {
+        DerefStruct__boxed(&deref_struct, boxed.as_mut_ptr());
+        assert!(core::ptr::eq(&*deref_struct.boxed, boxed.assume_init()));
+    }
+}
+
+#[test]
+#[webassembly_test::webassembly_test]
+fn export_getset_get_cow() {
+    let cow_struct = CowStruct {
+        name: Name("Name".to_owned()),
+        id: 1,
+    };
+
+    let name: Cow<'_, Name> = cow_struct.name();
+    assert!(matches!(name, Cow::Borrowed(name) if core::ptr::eq(name, &cow_struct.name)));
+    assert_eq!(Name("Name".to_owned()), name.into_owned());
+
+    let mut id = MaybeUninit::<*const u32>::new(core::ptr::null());
+    unsafe {
+        CowStruct__id(&cow_struct, id.as_mut_ptr());
+        assert_eq!(&1, &*id.assume_init());
+    }
+}
+
+#[test]
+#[webassembly_test::webassembly_test]
+fn export_getset_get_both() {
+    let get_both_struct = &mut GetBothStruct { id: 1 };
+
+    assert_eq!(&1, get_both_struct.id());
+    *get_both_struct.id_mut() = 2;
+    assert_eq!(&2, get_both_struct.id());
+
+    let mut id = MaybeUninit::<*const u32>::new(core::ptr::null());
+    let mut id_mut = MaybeUninit::<*mut u32>::new(core::ptr::null_mut());
+    unsafe {
+        GetBothStruct__id(get_both_struct, id.as_mut_ptr());
+        assert_eq!(&2, &*id.assume_init());
+
+        GetBothStruct__id_mut(<*mut _>::from(&mut *get_both_struct), id_mut.as_mut_ptr());
+        *id_mut.assume_init() = 3;
+    }
+    assert_eq!(&3, get_both_struct.id());
+}
+
+#[test]
+#[webassembly_test::webassembly_test]
+fn export_getset_restricted_visibility() {
+    let restricted_struct = RestrictedStruct { restricted: 1 };
+
+    assert_eq!(&1, restricted_struct.restricted());
+}
@@ -91,8 +91,11 @@ types!(
     BTreeMap<Name, MetadataValueBox>,
     BTreeSet<Permission>,
     BTreeSet<SignatureWrapperOf<BlockPayload>>,
+    BatchItemError,
     BatchedResponse<QueryOutputBox>,
     BatchedResponseV1<QueryOutputBox>,
+    BlockCommittedEvent,
+    BlockCommittedEventFilter,
     BlockEvent,
     BlockEventFilter,
     BlockHeader,
@@ -102,12 +105,14 @@ types!(
     BlockStatus,
     BlockSubscriptionRequest,
     Box<GenericPredicateBox<QueryOutputPredicate>>,
+    Box<InstructionExecutionError>,
     Box<QueryOutputPredicate>,
     Box<TransactionRejectionReason>,
     Burn<u32, Trigger>,
     Burn<Numeric, Asset>,
     BurnBox,
     ChainId,
+    ClockSkewError,
     CommittedTransaction,
     ConfigurationEvent,
     ConfigurationEventFilter,
@@ -140,6 +145,7 @@ types!(
     ExecutorUpgrade,
     ExecutorDataModel,
     Fail,
+    FailedInstruction,
     EventFilterBox,
     FetchSize,
     FindAccountById,
@@ -168,14 +174,19 @@ types!(
     FindAssetsByDomainId,
     FindAssetsByDomainIdAndAssetDefinitionId,
     FindAssetsByName,
+    FindBlockByHash,
+    FindBlockByHeight,
     FindBlockHeaderByHash,
     FindDomainById,
     FindDomainKeyValueByIdAndKey,
     FindError,
+    FindParametersBySubsystem,
     FindPermissionsByAccountId,
     FindRoleByRoleId,
     FindRolesByAccountId,
+    FindTopology,
     FindTotalAssetQuantityByAssetDefinitionId,
+    FindTotalAssetQuantityByDomainIdAndAssetDefinitionId,
     FindTransactionByHash,
     FindTransactionsByAccountId,
     FindTriggerById,
@@ -187,11 +198,14 @@ types!(
     Grant<RoleId, Account>,
     GrantBox,
     Hash,
+    HashOf<Executor>,
     HashOf<MerkleTree<SignedTransaction>>,
     HashOf<SignedBlock>,
     HashOf<SignedTransaction>,
     IdBox,
+    IdentNamingPolicy,
     IdentifiableBox,
+    IndexedInstructionError,
     InstructionBox,
     InstructionEvaluationError,
     InstructionExecutionError,
@@ -203,6 +217,8 @@ types!(
     Ipv6Addr,
     LengthLimits,
     Level,
+    Lock<Numeric, Asset>,
+    LockBox,
     Log,
     MathError,
     MerkleTree<SignedTransaction>,
@@ -215,8 +231,10 @@ types!(
     MetadataError,
     MetadataLimits,
     MetadataValueBox,
+    MigrateAssetValueType,
     Mint<u32, Trigger>,
     Mint<Numeric, Asset>,
+    MintBatch,
     MintBox,
     MintabilityError,
     Mintable,
@@ -259,8 +277,12 @@ types!(
     Option<TriggerId>,
     Pagination,
     Parameter,
+    ParameterChanged,
+    ParameterConstraint,
     ParameterId,
+    ParameterSubsystem,
     ParameterValueBox,
+    ParameterValueError,
     Peer,
     PeerEvent,
     PeerEventFilter,
@@ -284,6 +306,14 @@ types!(
     Register<Role>,
     Register<Trigger>,
     RegisterBox,
+    RegisterIfAbsent<Account>,
+    RegisterIfAbsent<AssetDefinition>,
+    RegisterIfAbsent<Domain>,
+    RegisterIfAbsentBox,
+    RegisterMany<Account>,
+    RegisterMany<AssetDefinition>,
+    RegisterMany<Domain>,
+    RegisterManyBox,
     RemoveKeyValue<Account>,
     RemoveKeyValue<Asset>,
     RemoveKeyValue<AssetDefinition>,
@@ -304,6 +334,7 @@ types!(
     SemiInterval<Numeric>,
     SemiInterval<u128>,
     SemiRange,
+    SetAccountMetadata,
     SetKeyValue<Account>,
     SetKeyValue<Asset>,
     SetKeyValue<AssetDefinition>,
@@ -311,6 +342,8 @@ types!(
     SetKeyValue<Trigger>,
     SetKeyValueBox,
     SetParameter,
+    SetParameterIf,
+    SetParameters,
     Signature,
     SignatureOf<BlockPayload>,
     SignatureOf<ClientQueryPayload>,
@@ -336,6 +369,7 @@ types!(
     TimeEventFilter,
     TimeInterval,
     TimeSchedule,
+    Topology,
     TransactionEvent,
     TransactionEventFilter,
     TransactionLimitError,
@@ -344,6 +378,7 @@ types!(
     TransactionQueryOutput,
     TransactionRejectionReason,
     TransactionStatus,
+    TransactionSummary,
     Transfer<Account, AssetDefinitionId, Account>,
     Transfer<Account, DomainId, Account>,
     Transfer<Asset, Metadata, Account>,
@@ -362,6 +397,8 @@ types!(
     TriggeringEventFilterBox,
     TypeError,
     UniqueVec<PeerId>,
+    Unlock<Numeric, Asset>,
+    UnlockBox,
     Unregister<Account>,
     Unregister<Asset>,
     Unregister<AssetDefinition>,
@@ -373,14 +410,19 @@ types!(
     Upgrade,
     ValidationFail,
     Vec<CommittedTransaction>,
+    Vec<DataEvent>,
     Vec<EventBox>,
     Vec<EventFilterBox>,
     Vec<InstructionBox>,
     Vec<MetadataValueBox>,
     Vec<Name>,
+    Vec<NewAccount>,
+    Vec<NewAssetDefinition>,
+    Vec<NewDomain>,
     Vec<PeerId>,
     Vec<PredicateBox>,
     Vec<QueryOutputBox>,
+    Vec<TransactionSummary>,
     Vec<u8>,
     WasmExecutionFail,
     WasmSmartContract,
@@ -414,18 +456,20 @@ pub mod complete_data_model {
             BlockHeader, BlockPayload, SignedBlock, SignedBlockV1,
         },
         domain::NewDomain,
-        events::pipeline::{BlockEventFilter, TransactionEventFilter},
+        events::pipeline::{BlockCommittedEventFilter, BlockEventFilter, TransactionEventFilter},
         executor::{Executor, ExecutorDataModel},
         ipfs::IpfsPath,
         isi::{
             error::{
-                InstructionEvaluationError, InstructionExecutionError, InvalidParameterError,
-                MathError, MintabilityError, Mismatch, RepetitionError, TypeError,
+                BatchItemError, IndexedInstructionError, InstructionEvaluationError,
+                InstructionExecutionError, InvalidParameterError, MathError, MintabilityError,
+                Mismatch, ParameterConstraint, ParameterValueError, RepetitionError, TypeError,
             },
             InstructionType,
         },
         metadata::{MetadataError, MetadataValueBox, SizeError},
-        parameter::ParameterValueBox,
+        parameter::{IdentNamingPolicy, ParameterValueBox},
+        peer::Topology,
         prelude::*,
         query::{
             error::{FindError, QueryExecutionFail},
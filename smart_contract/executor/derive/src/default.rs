@@ -119,18 +119,26 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_register_peer(operation: &Register<Peer>)",
         "fn visit_unregister_peer(operation: &Unregister<Peer>)",
         "fn visit_register_domain(operation: &Register<Domain>)",
+        "fn visit_register_many_domains(operation: &RegisterMany<Domain>)",
+        "fn visit_register_domain_if_absent(operation: &RegisterIfAbsent<Domain>)",
         "fn visit_unregister_domain(operation: &Unregister<Domain>)",
         "fn visit_transfer_domain(operation: &Transfer<Account, DomainId, Account>)",
         "fn visit_set_domain_key_value(operation: &SetKeyValue<Domain>)",
         "fn visit_remove_domain_key_value(operation: &RemoveKeyValue<Domain>)",
         "fn visit_register_account(operation: &Register<Account>)",
+        "fn visit_register_many_accounts(operation: &RegisterMany<Account>)",
+        "fn visit_register_account_if_absent(operation: &RegisterIfAbsent<Account>)",
         "fn visit_unregister_account(operation: &Unregister<Account>)",
         "fn visit_set_account_key_value(operation: &SetKeyValue<Account>)",
+        "fn visit_set_account_metadata(operation: &SetAccountMetadata)",
         "fn visit_remove_account_key_value(operation: &RemoveKeyValue<Account>)",
         "fn visit_register_asset(operation: &Register<Asset>)",
         "fn visit_unregister_asset(operation: &Unregister<Asset>)",
         "fn visit_mint_asset_numeric(operation: &Mint<Numeric, Asset>)",
+        "fn visit_mint_batch(operation: &MintBatch)",
         "fn visit_burn_asset_numeric(operation: &Burn<Numeric, Asset>)",
+        "fn visit_lock_asset_numeric(operation: &Lock<Numeric, Asset>)",
+        "fn visit_unlock_asset_numeric(operation: &Unlock<Numeric, Asset>)",
         "fn visit_transfer_asset_numeric(operation: &Transfer<Asset, Numeric, Account>)",
         "fn visit_transfer_asset_store(operation: &Transfer<Asset, Metadata, Account>)",
         "fn visit_set_asset_key_value(operation: &SetKeyValue<Asset>)",
@@ -138,9 +146,12 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_set_trigger_key_value(operation: &SetKeyValue<Trigger>)",
         "fn visit_remove_trigger_key_value(operation: &RemoveKeyValue<Trigger>)",
         "fn visit_register_asset_definition(operation: &Register<AssetDefinition>)",
+        "fn visit_register_many_asset_definitions(operation: &RegisterMany<AssetDefinition>)",
+        "fn visit_register_asset_definition_if_absent(operation: &RegisterIfAbsent<AssetDefinition>)",
         "fn visit_unregister_asset_definition(operation: &Unregister<AssetDefinition>)",
         "fn visit_transfer_asset_definition(operation: &Transfer<Account, AssetDefinitionId, Account>)",
         "fn visit_set_asset_definition_key_value(operation: &SetKeyValue<AssetDefinition>)",
+        "fn visit_migrate_asset_value_type(operation: &MigrateAssetValueType)",
         "fn visit_remove_asset_definition_key_value(operation: &RemoveKeyValue<AssetDefinition>)",
         "fn visit_grant_account_permission(operation: &Grant<Permission, Account>)",
         "fn visit_revoke_account_permission(operation: &Revoke<Permission, Account>)",
@@ -156,6 +167,8 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_burn_trigger_repetitions(operation: &Burn<u32, Trigger>)",
         "fn visit_execute_trigger(operation: &ExecuteTrigger)",
         "fn visit_set_parameter(operation: &SetParameter)",
+        "fn visit_set_parameter_if(operation: &SetParameterIf)",
+        "fn visit_set_parameters(operation: &SetParameters)",
         "fn visit_new_parameter(operation: &NewParameter)",
         "fn visit_upgrade(operation: &Upgrade)",
         "fn visit_log(operation: &Log)",
@@ -78,18 +78,18 @@ pub fn entrypoint(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     asset_id: AssetId,
 /// }
 ///
-/// #[entrypoint(params = "[authority, operation]")]
-/// fn validate(authority: AccountId, operation: NeedsValidationBox) -> Result {
-///     let NeedsValidationBox::Instruction(instruction) = operation else {
-///         pass!();
+/// #[entrypoint]
+/// fn validate_instruction(authority: AccountId, instruction: InstructionBox, block_height: u64) -> Result {
+///     let token = CanDoSomethingWithAsset {
+///        some_data: "some data".to_owned(),
+///        asset_id: parse!(AssetId, "rose##ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03@wonderland"),
 ///     };
 ///
-///     validate_grant_revoke!(<CanDoSomethingWithAsset>, (authority, instruction));
+///     if token.is_owned_by(&authority) {
+///         return Ok(());
+///     }
 ///
-///     CanDoSomethingWithAsset {
-///        some_data: "some data".to_owned(),
-///        asset_id: parse!(AssetId, "rose##ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03@wonderland"),
-///     }.is_owned_by(&authority)
+///     Err(ValidationFail::NotPermitted("Can't do something with asset".to_owned()))
 /// }
 /// ```
 #[manyhow]
@@ -3,31 +3,37 @@
 
 pub mod permissions;
 
-use alloc::format;
+use alloc::{collections::BTreeSet, format};
 
 pub use account::{
-    visit_register_account, visit_remove_account_key_value, visit_set_account_key_value,
+    visit_register_account, visit_register_account_if_absent, visit_register_many_accounts,
+    visit_remove_account_key_value, visit_set_account_key_value, visit_set_account_metadata,
     visit_unregister_account,
 };
 pub use asset::{
-    visit_burn_asset_numeric, visit_mint_asset_numeric, visit_register_asset,
-    visit_remove_asset_key_value, visit_set_asset_key_value, visit_transfer_asset_numeric,
-    visit_transfer_asset_store, visit_unregister_asset,
+    visit_burn_asset_numeric, visit_lock_asset_numeric, visit_mint_asset_numeric, visit_mint_batch,
+    visit_register_asset, visit_remove_asset_key_value, visit_set_asset_key_value,
+    visit_transfer_asset_numeric, visit_transfer_asset_store, visit_unlock_asset_numeric,
+    visit_unregister_asset,
 };
 pub use asset_definition::{
-    visit_register_asset_definition, visit_remove_asset_definition_key_value,
-    visit_set_asset_definition_key_value, visit_transfer_asset_definition,
-    visit_unregister_asset_definition,
+    visit_migrate_asset_value_type, visit_register_asset_definition,
+    visit_register_asset_definition_if_absent, visit_register_many_asset_definitions,
+    visit_remove_asset_definition_key_value, visit_set_asset_definition_key_value,
+    visit_transfer_asset_definition, visit_unregister_asset_definition,
 };
 pub use domain::{
-    visit_register_domain, visit_remove_domain_key_value, visit_set_domain_key_value,
-    visit_transfer_domain, visit_unregister_domain,
+    visit_register_domain, visit_register_domain_if_absent, visit_register_many_domains,
+    visit_remove_domain_key_value, visit_set_domain_key_value, visit_transfer_domain,
+    visit_unregister_domain,
 };
 pub use executor::visit_upgrade;
 pub use fail::visit_fail;
-use iroha_smart_contract::data_model::isi::InstructionBox;
+use iroha_smart_contract::data_model::isi::{error::IndexedInstructionError, InstructionBox};
 pub use log::visit_log;
-pub use parameter::{visit_new_parameter, visit_set_parameter};
+pub use parameter::{
+    visit_new_parameter, visit_set_parameter, visit_set_parameter_if, visit_set_parameters,
+};
 pub use peer::{visit_register_peer, visit_unregister_peer};
 pub use permission::{visit_grant_account_permission, visit_revoke_account_permission};
 use permissions::AnyPermission;
@@ -65,9 +71,15 @@ pub fn visit_transaction<V: Validate + Visit + ?Sized>(
     match transaction.instructions() {
         Executable::Wasm(wasm) => executor.visit_wasm(authority, wasm),
         Executable::Instructions(instructions) => {
-            for isi in instructions {
+            for (index, isi) in instructions.iter().enumerate() {
                 if executor.verdict().is_ok() {
                     executor.visit_instruction(authority, isi);
+
+                    // Report position of the failed instruction within the transaction
+                    if let Err(error) = executor.verdict() {
+                        let error = IndexedInstructionError::new(index, error.clone());
+                        executor.deny(error.into());
+                    }
                 }
             }
         }
@@ -91,6 +103,21 @@ pub fn visit_instruction<V: Validate + Visit + ?Sized>(
         InstructionBox::SetParameter(isi) => {
             executor.visit_set_parameter(authority, isi);
         }
+        InstructionBox::SetParameterIf(isi) => {
+            executor.visit_set_parameter_if(authority, isi);
+        }
+        InstructionBox::SetParameters(isi) => {
+            executor.visit_set_parameters(authority, isi);
+        }
+        InstructionBox::MintBatch(isi) => {
+            executor.visit_mint_batch(authority, isi);
+        }
+        InstructionBox::SetAccountMetadata(isi) => {
+            executor.visit_set_account_metadata(authority, isi);
+        }
+        InstructionBox::MigrateAssetValueType(isi) => {
+            executor.visit_migrate_asset_value_type(authority, isi);
+        }
         InstructionBox::Log(isi) => {
             executor.visit_log(authority, isi);
         }
@@ -106,12 +133,21 @@ pub fn visit_instruction<V: Validate + Visit + ?Sized>(
         InstructionBox::Grant(isi) => {
             executor.visit_grant(authority, isi);
         }
+        InstructionBox::Lock(isi) => {
+            executor.visit_lock(authority, isi);
+        }
         InstructionBox::Mint(isi) => {
             executor.visit_mint(authority, isi);
         }
         InstructionBox::Register(isi) => {
             executor.visit_register(authority, isi);
         }
+        InstructionBox::RegisterMany(isi) => {
+            executor.visit_register_many(authority, isi);
+        }
+        InstructionBox::RegisterIfAbsent(isi) => {
+            executor.visit_register_if_absent(authority, isi);
+        }
         InstructionBox::RemoveKeyValue(isi) => {
             executor.visit_remove_key_value(authority, isi);
         }
@@ -124,6 +160,9 @@ pub fn visit_instruction<V: Validate + Visit + ?Sized>(
         InstructionBox::Transfer(isi) => {
             executor.visit_transfer(authority, isi);
         }
+        InstructionBox::Unlock(isi) => {
+            executor.visit_unlock(authority, isi);
+        }
         InstructionBox::Unregister(isi) => {
             executor.visit_unregister(authority, isi);
         }
@@ -177,6 +216,22 @@ pub mod domain {
         execute!(executor, isi)
     }
 
+    pub fn visit_register_many_domains<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        _authority: &AccountId,
+        isi: &RegisterMany<Domain>,
+    ) {
+        execute!(executor, isi)
+    }
+
+    pub fn visit_register_domain_if_absent<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        _authority: &AccountId,
+        isi: &RegisterIfAbsent<Domain>,
+    ) {
+        execute!(executor, isi)
+    }
+
     pub fn visit_unregister_domain<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
@@ -439,6 +494,64 @@ pub mod account {
         );
     }
 
+    pub fn visit_register_account_if_absent<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &RegisterIfAbsent<Account>,
+    ) {
+        let domain_id = isi.object().id().domain_id();
+
+        match crate::permission::domain::is_domain_owner(domain_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        let can_register_account_in_domain = permissions::domain::CanRegisterAccountInDomain {
+            domain_id: domain_id.clone(),
+        };
+        if can_register_account_in_domain.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't register account in a domain owned by another account"
+        );
+    }
+
+    pub fn visit_register_many_accounts<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &RegisterMany<Account>,
+    ) {
+        let domain_ids: BTreeSet<_> = isi
+            .objects()
+            .iter()
+            .map(|new_account| new_account.id().domain_id())
+            .collect();
+
+        for domain_id in domain_ids {
+            match crate::permission::domain::is_domain_owner(domain_id, authority) {
+                Err(err) => deny!(executor, err),
+                Ok(true) => continue,
+                Ok(false) => {}
+            }
+
+            let can_register_account_in_domain = permissions::domain::CanRegisterAccountInDomain {
+                domain_id: domain_id.clone(),
+            };
+            if !can_register_account_in_domain.is_owned_by(authority) {
+                deny!(
+                    executor,
+                    "Can't register account in a domain owned by another account"
+                );
+            }
+        }
+
+        execute!(executor, isi)
+    }
+
     pub fn visit_unregister_account<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
@@ -508,6 +621,40 @@ pub mod account {
         );
     }
 
+    pub fn visit_set_account_metadata<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &SetAccountMetadata,
+    ) {
+        let account_id = isi.account_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_set_key_value_in_user_account_token =
+            permissions::account::CanSetKeyValueInAccount {
+                account_id: account_id.clone(),
+            };
+        // Replacing the metadata may remove keys, so it also requires the permission to remove them
+        let can_remove_key_value_in_user_account_token =
+            permissions::account::CanRemoveKeyValueInAccount {
+                account_id: account_id.clone(),
+            };
+        if can_set_key_value_in_user_account_token.is_owned_by(authority)
+            && (!*isi.replace()
+                || can_remove_key_value_in_user_account_token.is_owned_by(authority))
+        {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't set metadata of another account");
+    }
+
     pub fn visit_remove_account_key_value<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
@@ -649,6 +796,66 @@ pub mod asset_definition {
         );
     }
 
+    pub fn visit_register_asset_definition_if_absent<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &RegisterIfAbsent<AssetDefinition>,
+    ) {
+        let domain_id = isi.object().id().domain_id();
+
+        match crate::permission::domain::is_domain_owner(domain_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        let can_register_asset_definition_in_domain_token =
+            permissions::domain::CanRegisterAssetDefinitionInDomain {
+                domain_id: domain_id.clone(),
+            };
+        if can_register_asset_definition_in_domain_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't register asset definition in a domain owned by another account"
+        );
+    }
+
+    pub fn visit_register_many_asset_definitions<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &RegisterMany<AssetDefinition>,
+    ) {
+        let domain_ids: BTreeSet<_> = isi
+            .objects()
+            .iter()
+            .map(|new_asset_definition| new_asset_definition.id().domain_id())
+            .collect();
+
+        for domain_id in domain_ids {
+            match crate::permission::domain::is_domain_owner(domain_id, authority) {
+                Err(err) => deny!(executor, err),
+                Ok(true) => continue,
+                Ok(false) => {}
+            }
+
+            let can_register_asset_definition_in_domain_token =
+                permissions::domain::CanRegisterAssetDefinitionInDomain {
+                    domain_id: domain_id.clone(),
+                };
+            if !can_register_asset_definition_in_domain_token.is_owned_by(authority) {
+                deny!(
+                    executor,
+                    "Can't register asset definition in a domain owned by another account"
+                );
+            }
+        }
+
+        execute!(executor, isi)
+    }
+
     pub fn visit_unregister_asset_definition<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
@@ -750,6 +957,28 @@ pub mod asset_definition {
         );
     }
 
+    pub fn visit_migrate_asset_value_type<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &MigrateAssetValueType,
+    ) {
+        let asset_definition_id = isi.asset_definition_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_asset_definition_owner(asset_definition_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't migrate value type of the asset definition owned by another account"
+        );
+    }
+
     pub fn visit_remove_asset_definition_key_value<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
@@ -978,6 +1207,43 @@ pub mod asset {
         validate_mint_asset(executor, authority, isi);
     }
 
+    pub fn visit_mint_batch<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &MintBatch,
+    ) {
+        let asset_definition_id = isi.asset_definition_id();
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_asset_definition_owner(asset_definition_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_mint_assets_with_definition_token =
+            permissions::asset::CanMintAssetWithDefinition {
+                asset_definition_id: asset_definition_id.clone(),
+            };
+        if can_mint_assets_with_definition_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+        let can_mint_every_user_asset = isi.mints().keys().all(|account_id| {
+            permissions::asset::CanMintUserAsset {
+                asset_id: AssetId::new(asset_definition_id.clone(), account_id.clone()),
+            }
+            .is_owned_by(authority)
+        });
+        if can_mint_every_user_asset {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't mint assets with definitions registered by other accounts"
+        );
+    }
+
     fn validate_burn_asset<V, Q>(executor: &mut V, authority: &AccountId, isi: &Burn<Q, Asset>)
     where
         V: Validate + Visit + ?Sized,
@@ -1023,6 +1289,69 @@ pub mod asset {
         validate_burn_asset(executor, authority, isi);
     }
 
+    /// Locking restricts what can be transferred, so it's allowed to those who can transfer the asset.
+    pub fn visit_lock_asset_numeric<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Lock<Numeric, Asset>,
+    ) {
+        let asset_id = isi.destination_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_asset_owner(asset_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        match is_asset_definition_owner(asset_id.definition_id(), authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_transfer_assets_with_definition_token =
+            permissions::asset::CanTransferAssetWithDefinition {
+                asset_definition_id: asset_id.definition_id().clone(),
+            };
+        if can_transfer_assets_with_definition_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+        let can_transfer_user_asset_token = permissions::asset::CanTransferUserAsset {
+            asset_id: asset_id.clone(),
+        };
+        if can_transfer_user_asset_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't lock assets of another account");
+    }
+
+    /// Only the account which locked a part of the asset can release it,
+    /// so that e.g. the owner can't withdraw the amount held in escrow.
+    pub fn visit_unlock_asset_numeric<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Unlock<Numeric, Asset>,
+    ) {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+
+        let asset = match FindAssetById::new(isi.destination_id().clone()).execute() {
+            Ok(res) => res.into_inner(),
+            Err(error) => {
+                deny!(executor, error);
+            }
+        };
+        let asset = Asset::try_from(asset).unwrap();
+        if asset.locks().contains_key(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't unlock assets locked by another account");
+    }
+
     fn validate_transfer_asset<V, Q>(
         executor: &mut V,
         authority: &AccountId,
@@ -1178,6 +1507,44 @@ pub mod parameter {
             "Can't set executor configuration parameters without permission"
         );
     }
+
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn visit_set_parameter_if<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &SetParameterIf,
+    ) {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        if permissions::parameter::CanSetParameters.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't set executor configuration parameters without permission"
+        );
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn visit_set_parameters<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &SetParameters,
+    ) {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        if permissions::parameter::CanSetParameters.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't set executor configuration parameters without permission"
+        );
+    }
 }
 
 pub mod role {
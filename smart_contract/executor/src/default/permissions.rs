@@ -92,6 +92,8 @@ macro_rules! permission {
     };
 }
 
+// NOTE: if adding/editing permissions here, make sure to update the typed constructors of
+// `iroha_data_model::permission::Permission`
 declare_permissions! {
     crate::default::permissions::peer::{CanUnregisterAnyPeer},
 
@@ -11,7 +11,6 @@ use iroha_data_model::{
 use iroha_genesis::{
     executor_state, RawGenesisBlockBuilder, RawGenesisBlockFile, GENESIS_DOMAIN_ID,
 };
-use serde_json::json;
 use test_samples::{gen_account_in, ALICE_ID, BOB_ID, CARPENTER_ID};
 
 use super::*;
@@ -115,10 +114,8 @@ pub fn generate_default(
         44u32,
         AssetId::new("cabbage#garden_of_live_flowers".parse()?, ALICE_ID.clone()),
     );
-    let grant_permission_to_set_parameters = Grant::permission(
-        Permission::new("CanSetParameters".parse()?, json!(null)),
-        ALICE_ID.clone(),
-    );
+    let grant_permission_to_set_parameters =
+        Grant::permission(Permission::can_set_parameters(), ALICE_ID.clone());
     let transfer_rose_ownership = Transfer::asset_definition(
         genesis_account_id.clone(),
         "rose#wonderland".parse()?,
@@ -131,13 +128,9 @@ pub fn generate_default(
     );
     let register_user_metadata_access = Register::role(
         Role::new("ALICE_METADATA_ACCESS".parse()?)
-            .add_permission(Permission::new(
-                "CanSetKeyValueInAccount".parse()?,
-                json!({ "account_id": ALICE_ID.clone() }),
-            ))
-            .add_permission(Permission::new(
-                "CanRemoveKeyValueInAccount".parse()?,
-                json!({ "account_id": ALICE_ID.clone() }),
+            .add_permission(Permission::can_set_key_value_in_account(ALICE_ID.clone()))
+            .add_permission(Permission::can_remove_key_value_in_account(
+                ALICE_ID.clone(),
             )),
     )
     .into();
@@ -10,6 +10,7 @@ use iroha_data_model::prelude::*;
 mod crypto;
 mod genesis;
 mod schema;
+mod schema_diff;
 
 /// Outcome shorthand used throughout this crate
 pub(crate) type Outcome = color_eyre::Result<()>;
@@ -39,6 +40,8 @@ enum Args {
     Crypto(Box<crypto::Args>),
     /// Generate the schema used for code generation in Iroha SDKs
     Schema(schema::Args),
+    /// Compare two schemas generated by `kagami schema` and report changed types
+    SchemaDiff(schema_diff::Args),
     /// Generate the genesis block that is used in tests
     Genesis(genesis::Args),
 }
@@ -50,6 +53,7 @@ impl<T: Write> RunArgs<T> for Args {
         match self {
             Crypto(args) => args.run(writer),
             Schema(args) => args.run(writer),
+            SchemaDiff(args) => args.run(writer),
             Genesis(args) => args.run(writer),
         }
     }
@@ -0,0 +1,398 @@
+//! Comparison of two serialized schemas (as produced by `kagami schema`).
+//!
+//! Used to detect incompatible changes of on-chain types (e.g. `Parameter`)
+//! between Iroha versions before upgrading peers.
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use super::*;
+
+/// Serialized schema: type name mapped to its metadata
+type Schema = Map<String, Value>;
+
+#[derive(ClapArgs, Debug, Clone)]
+pub struct Args {
+    /// Schema of the currently deployed version
+    #[clap(value_name = "OLD")]
+    old: PathBuf,
+    /// Schema of the version to upgrade to
+    #[clap(value_name = "NEW")]
+    new: PathBuf,
+    /// Root types to compare; types they reference are compared as well
+    #[clap(long = "type", value_name = "TYPE", default_value = "Parameter")]
+    types: Vec<String>,
+}
+
+impl<T: Write> RunArgs<T> for Args {
+    fn run(self, writer: &mut BufWriter<T>) -> Outcome {
+        let old = read_schema(&self.old)?;
+        let new = read_schema(&self.new)?;
+        let diff = diff(&old, &new, &self.types);
+        writeln!(writer, "{}", serde_json::to_string_pretty(&diff)?)
+            .wrap_err("Failed to write schema diff.")
+    }
+}
+
+fn read_schema(path: &Path) -> color_eyre::Result<Schema> {
+    let content = fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read schema from {}", path.display()))?;
+    serde_json::from_str(&content)
+        .wrap_err_with(|| format!("Failed to parse schema from {}", path.display()))
+}
+
+/// Differences between two versions of a schema
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub struct SchemaDiff {
+    /// Types present only in the new schema
+    pub added: BTreeSet<String>,
+    /// Types present only in the old schema
+    pub removed: BTreeSet<String>,
+    /// Types present in both schemas whose definition differs
+    pub changed: BTreeMap<String, TypeChange>,
+}
+
+impl SchemaDiff {
+    /// Whether both schemas are identical for the compared types
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Change of a single type definition
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub enum TypeChange {
+    /// Fields of a struct or variants of an enum changed
+    Members(MemberChanges),
+    /// Type changed its kind (e.g. struct became an enum) or its non-compound definition
+    Redefined { old: Value, new: Value },
+}
+
+/// Changes of struct fields or enum variants
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub struct MemberChanges {
+    /// Members present only in the new definition
+    pub added: Vec<Member>,
+    /// Members present only in the old definition
+    pub removed: Vec<Member>,
+    /// Members which kept their position and type but changed name
+    pub renamed: Vec<Renamed>,
+    /// Members which kept their name but changed type
+    pub retyped: Vec<Retyped>,
+}
+
+impl MemberChanges {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.renamed.is_empty()
+            && self.retyped.is_empty()
+    }
+}
+
+/// Struct field or enum variant
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Member {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Renamed {
+    pub old: String,
+    pub new: String,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Retyped {
+    pub name: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// Compare `roots` and all the types reachable from them in either schema.
+pub fn diff(old: &Schema, new: &Schema, roots: &[String]) -> SchemaDiff {
+    let mut result = SchemaDiff::default();
+
+    for name in reachable_types(old, new, roots) {
+        match (old.get(&name), new.get(&name)) {
+            (Some(_), None) => {
+                result.removed.insert(name);
+            }
+            (None, Some(_)) => {
+                result.added.insert(name);
+            }
+            (Some(old_ty), Some(new_ty)) if old_ty != new_ty => {
+                if let Some(change) = diff_type(old_ty, new_ty) {
+                    result.changed.insert(name, change);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    result
+}
+
+fn diff_type(old: &Value, new: &Value) -> Option<TypeChange> {
+    let (Some((old_kind, old_members)), Some((new_kind, new_members))) =
+        (members(old), members(new))
+    else {
+        return Some(TypeChange::Redefined {
+            old: old.clone(),
+            new: new.clone(),
+        });
+    };
+    if old_kind != new_kind {
+        return Some(TypeChange::Redefined {
+            old: old.clone(),
+            new: new.clone(),
+        });
+    }
+
+    let changes = diff_members(&old_members, &new_members);
+    (!changes.is_empty()).then_some(TypeChange::Members(changes))
+}
+
+fn diff_members(old: &[Member], new: &[Member]) -> MemberChanges {
+    let mut changes = MemberChanges::default();
+
+    for old_member in old {
+        match new.iter().find(|member| member.name == old_member.name) {
+            Some(new_member) if new_member.ty != old_member.ty => {
+                changes.retyped.push(Retyped {
+                    name: old_member.name.clone(),
+                    old: old_member.ty.clone(),
+                    new: new_member.ty.clone(),
+                });
+            }
+            Some(_) => {}
+            None => changes.removed.push(old_member.clone()),
+        }
+    }
+    for new_member in new {
+        if !old.iter().any(|member| member.name == new_member.name) {
+            changes.added.push(new_member.clone());
+        }
+    }
+
+    // A member which disappeared and one which appeared at the same position
+    // with the same type is most likely the same member under a new name
+    let position =
+        |members: &[Member], name: &str| members.iter().position(|member| member.name == name);
+    changes.removed.retain(|removed| {
+        let old_position = position(old, &removed.name);
+        let Some(index) = changes
+            .added
+            .iter()
+            .position(|added| added.ty == removed.ty && position(new, &added.name) == old_position)
+        else {
+            return true;
+        };
+        let added = changes.added.remove(index);
+        changes.renamed.push(Renamed {
+            old: removed.name.clone(),
+            new: added.name,
+            ty: added.ty,
+        });
+        false
+    });
+
+    changes
+}
+
+/// Extract fields of a struct or variants of an enum along with the kind of the type
+fn members(ty: &Value) -> Option<(&'static str, Vec<Member>)> {
+    let object = ty.as_object()?;
+    let (kind, name_key) = if object.contains_key("Struct") {
+        ("Struct", "name")
+    } else if object.contains_key("Enum") {
+        ("Enum", "tag")
+    } else {
+        return None;
+    };
+
+    let members = object[kind]
+        .as_array()?
+        .iter()
+        .map(|member| {
+            Some(Member {
+                name: member.get(name_key)?.as_str()?.to_owned(),
+                ty: member
+                    .get("type")
+                    .and_then(Value::as_str)
+                    .map(ToOwned::to_owned),
+            })
+        })
+        .collect::<Option<_>>()?;
+
+    Some((kind, members))
+}
+
+/// Names of `roots` and every type referenced by them, transitively, in either schema
+fn reachable_types(old: &Schema, new: &Schema, roots: &[String]) -> BTreeSet<String> {
+    let mut visited = BTreeSet::new();
+    let mut queue: Vec<String> = roots.to_vec();
+
+    while let Some(name) = queue.pop() {
+        if !visited.insert(name.clone()) {
+            continue;
+        }
+        for schema in [old, new] {
+            if let Some(ty) = schema.get(&name) {
+                collect_references(ty, &mut |reference| {
+                    if (old.contains_key(reference) || new.contains_key(reference))
+                        && !visited.contains(reference)
+                    {
+                        queue.push(reference.to_owned());
+                    }
+                });
+            }
+        }
+    }
+
+    visited
+}
+
+fn collect_references(value: &Value, f: &mut impl FnMut(&str)) {
+    match value {
+        Value::String(reference) => f(reference),
+        Value::Array(values) => values.iter().for_each(|value| collect_references(value, f)),
+        Value::Object(object) => object
+            .iter()
+            .filter(|(key, _)| !matches!(key.as_str(), "name" | "tag"))
+            .for_each(|(_, value)| collect_references(value, f)),
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn schema(value: Value) -> Schema {
+        match value {
+            Value::Object(schema) => schema,
+            _ => panic!("Schema is an object"),
+        }
+    }
+
+    #[test]
+    fn diff_reports_added_removed_and_renamed_fields() {
+        let old = schema(json!({
+            "Parameter": {"Struct": [
+                {"name": "id", "type": "ParameterId"},
+                {"name": "val", "type": "ParameterValueBox"},
+            ]},
+            "ParameterId": {"Struct": [{"name": "name", "type": "String"}]},
+            "ParameterValueBox": {"Enum": [
+                {"tag": "TransactionLimits", "discriminant": 0, "type": "TransactionLimits"},
+                {"tag": "Numeric", "discriminant": 1, "type": "u64"},
+            ]},
+            "TransactionLimits": {"Struct": [
+                {"name": "max_instruction_number", "type": "u64"},
+                {"name": "max_wasm_size_bytes", "type": "u64"},
+            ]},
+            "String": "String",
+            "u64": {"Int": "FixedWidth"},
+            "Unrelated": {"Struct": [{"name": "field", "type": "u64"}]},
+        }));
+        let new = schema(json!({
+            "Parameter": {"Struct": [
+                {"name": "id", "type": "ParameterId"},
+                {"name": "value", "type": "ParameterValueBox"},
+            ]},
+            "ParameterId": {"Struct": [{"name": "name", "type": "String"}]},
+            "ParameterValueBox": {"Enum": [
+                {"tag": "TransactionLimits", "discriminant": 0, "type": "TransactionLimits"},
+                {"tag": "Numeric", "discriminant": 1, "type": "u128"},
+                {"tag": "BlockTime", "discriminant": 2, "type": "u64"},
+            ]},
+            "TransactionLimits": {"Struct": [
+                {"name": "max_instructions", "type": "u64"},
+                {"name": "max_wasm_size_bytes", "type": "u64"},
+                {"name": "max_bytes", "type": "u64"},
+            ]},
+            "String": "String",
+            "u64": {"Int": "FixedWidth"},
+            "u128": {"Int": "FixedWidth"},
+            "Unrelated": {"Enum": []},
+        }));
+
+        let diff = diff(&old, &new, &["Parameter".to_owned()]);
+
+        assert_eq!(
+            diff,
+            SchemaDiff {
+                added: BTreeSet::from(["u128".to_owned()]),
+                removed: BTreeSet::new(),
+                changed: BTreeMap::from([
+                    (
+                        "Parameter".to_owned(),
+                        TypeChange::Members(MemberChanges {
+                            renamed: vec![Renamed {
+                                old: "val".to_owned(),
+                                new: "value".to_owned(),
+                                ty: Some("ParameterValueBox".to_owned()),
+                            }],
+                            ..MemberChanges::default()
+                        })
+                    ),
+                    (
+                        "ParameterValueBox".to_owned(),
+                        TypeChange::Members(MemberChanges {
+                            added: vec![Member {
+                                name: "BlockTime".to_owned(),
+                                ty: Some("u64".to_owned()),
+                            }],
+                            retyped: vec![Retyped {
+                                name: "Numeric".to_owned(),
+                                old: Some("u64".to_owned()),
+                                new: Some("u128".to_owned()),
+                            }],
+                            ..MemberChanges::default()
+                        })
+                    ),
+                    (
+                        "TransactionLimits".to_owned(),
+                        TypeChange::Members(MemberChanges {
+                            added: vec![Member {
+                                name: "max_bytes".to_owned(),
+                                ty: Some("u64".to_owned()),
+                            }],
+                            renamed: vec![Renamed {
+                                old: "max_instruction_number".to_owned(),
+                                new: "max_instructions".to_owned(),
+                                ty: Some("u64".to_owned()),
+                            }],
+                            ..MemberChanges::default()
+                        })
+                    ),
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn identical_schemas_have_empty_diff() {
+        let schema = schema(json!({
+            "LengthLimits": {"Struct": [
+                {"name": "min", "type": "u32"},
+                {"name": "max", "type": "u32"},
+            ]},
+            "u32": {"Int": "FixedWidth"},
+        }));
+
+        assert!(diff(&schema, &schema, &["LengthLimits".to_owned()]).is_empty());
+    }
+}
@@ -2,9 +2,7 @@
 use std::path::{Path, PathBuf};
 
 use clap::{Parser, Subcommand};
-use iroha_core::kura::{BlockIndex, BlockStore, LockStatus};
-use iroha_data_model::block::SignedBlock;
-use iroha_version::scale::DecodeVersioned;
+use iroha_core::kura::{decode_block, BlockIndex, BlockStore, LockStatus};
 
 /// Kura inspector
 #[derive(Parser)]
@@ -124,7 +122,7 @@ fn print_blockchain(block_store_path: &Path, from_height: u64, block_count: u64)
         block_store
             .read_block_data(idx.start, &mut block_buf)
             .unwrap_or_else(|_| panic!("Failed to read block № {} data.", meta_index + 1));
-        let block = SignedBlock::decode_all_versioned(&block_buf)
+        let block = decode_block(&block_buf)
             .unwrap_or_else(|_| panic!("Failed to decode block № {}", meta_index + 1));
         println!("Block#{} :", meta_index + 1);
         println!("{block:#?}");
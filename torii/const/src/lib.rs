@@ -10,6 +10,8 @@ pub mod uri {
     pub const QUERY: &str = "query";
     /// Transaction URI is used to handle incoming ISI requests.
     pub const TRANSACTION: &str = "transaction";
+    /// Validate URI is used to check ISI requests against the current state without submitting them.
+    pub const VALIDATE: &str = "validate";
     /// Block URI is used to handle incoming Block requests.
     pub const CONSENSUS: &str = "consensus";
     /// Health URI is used to handle incoming Healthcheck requests.
@@ -172,6 +172,15 @@ impl Torii {
                         ))
                         .and(body::versioned()),
                 )
+                .or(endpoint3(
+                    routing::handle_validate_transaction,
+                    warp::path(uri::VALIDATE)
+                        .and(add_state!(self.chain_id, self.state.clone()))
+                        .and(warp::body::content_length_limit(
+                            self.transaction_max_content_length,
+                        ))
+                        .and(body::versioned()),
+                ))
                 .or(endpoint3(
                     routing::handle_queries,
                     warp::path(uri::QUERY)
@@ -187,6 +196,13 @@ impl Torii {
             )
             .recover(|rejection| async move { body::recover_versioned(rejection) });
 
+        let delete_router = warp::delete().and(endpoint2(
+            routing::handle_release_query,
+            warp::path(uri::QUERY)
+                .and(add_state!(self.query_service))
+                .and(warp::query()),
+        ));
+
         let events_ws_router = warp::path(uri::SUBSCRIPTION)
             .and(add_state!(self.events))
             .and(warp::ws())
@@ -233,6 +249,7 @@ impl Torii {
             .or(ws_router
                 .or(get_router)
                 .or(post_router)
+                .or(delete_router)
                 .with(warp::trace::request()))
     }
 
@@ -301,6 +318,8 @@ pub enum Error {
     Config(#[source] eyre::Report),
     /// Failed to push into queue
     PushIntoQueue(#[from] Box<queue::Error>),
+    /// Only transactions made of instructions can be validated
+    ValidateWasm,
     #[cfg(feature = "telemetry")]
     /// Failed to get Prometheus metrics
     Prometheus(#[source] eyre::Report),
@@ -334,7 +353,7 @@ impl Error {
 
         match self {
             Query(e) => Self::query_status_code(e),
-            AcceptTransaction(_) => StatusCode::BAD_REQUEST,
+            AcceptTransaction(_) | ValidateWasm => StatusCode::BAD_REQUEST,
             Config(_) | StatusSegmentNotFound(_) => StatusCode::NOT_FOUND,
             PushIntoQueue(err) => match **err {
                 queue::Error::Full => StatusCode::INTERNAL_SERVER_ERROR,
@@ -351,7 +370,8 @@ impl Error {
 
     fn query_status_code(validation_error: &iroha_data_model::ValidationFail) -> StatusCode {
         use iroha_data_model::{
-            isi::error::InstructionExecutionError, query::error::QueryExecutionFail::*,
+            isi::error::{IndexedInstructionError, InstructionExecutionError},
+            query::error::QueryExecutionFail::*,
             ValidationFail::*,
         };
 
@@ -365,9 +385,13 @@ impl Error {
                 }
                 Signature(_) => StatusCode::UNAUTHORIZED,
                 Find(_) => StatusCode::NOT_FOUND,
+                Overflow => StatusCode::UNPROCESSABLE_ENTITY,
             },
             TooComplex => StatusCode::UNPROCESSABLE_ENTITY,
             InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            TransactionInstructionFailed(IndexedInstructionError { error, .. }) => {
+                query_status_code(error)
+            }
             InstructionFailed(error) => {
                 iroha_logger::error!(
                 ?error,
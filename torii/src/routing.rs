@@ -9,7 +9,10 @@
 use eyre::{eyre, WrapErr};
 use futures::TryStreamExt;
 use iroha_config::client_api::ConfigDTO;
-use iroha_core::{query::store::LiveQueryStoreHandle, smartcontracts::query::ValidQueryRequest};
+use iroha_core::{
+    query::{cursor::UnknownCursor, store::LiveQueryStoreHandle},
+    smartcontracts::query::ValidQueryRequest,
+};
 use iroha_data_model::{
     block::{
         stream::{BlockMessage, BlockSubscriptionRequest},
@@ -69,6 +72,30 @@ pub async fn handle_transaction(
         .map(|()| Empty)
 }
 
+/// Check instructions of the `transaction` against the current state as if they were executed
+/// on behalf of its authority, without submitting the transaction or changing the state.
+#[iroha_futures::telemetry_future]
+pub async fn handle_validate_transaction(
+    chain_id: Arc<ChainId>,
+    state: Arc<State>,
+    transaction: SignedTransaction,
+) -> Result<Scale<Vec<Result<(), ValidationFail>>>> {
+    let transaction_limits = state.view().config.transaction_limits;
+    let transaction = AcceptedTransaction::accept(transaction, &chain_id, &transaction_limits)
+        .map_err(Error::AcceptTransaction)?;
+    let (authority, executable) = transaction.into();
+    let Executable::Instructions(instructions) = executable else {
+        return Err(Error::ValidateWasm);
+    };
+
+    let handle =
+        task::spawn_blocking(move || state.validate_instructions(&authority, instructions));
+    let outcomes = handle
+        .await
+        .expect("Failed to join transaction validation task");
+    Ok(Scale(outcomes))
+}
+
 #[iroha_futures::telemetry_future]
 pub async fn handle_queries(
     live_query_store: LiveQueryStoreHandle,
@@ -97,6 +124,24 @@ pub async fn handle_queries(
         .map_err(Into::into)
 }
 
+/// Release the live query of the given `cursor`, e.g. when the client stopped iterating early.
+/// Releasing a query which is already gone is not an error.
+#[iroha_futures::telemetry_future]
+pub async fn handle_release_query(
+    live_query_store: LiveQueryStoreHandle,
+    cursor: ForwardCursor,
+) -> Result<Empty> {
+    let handle = task::spawn_blocking(move || {
+        let query_id = cursor.query_id.ok_or(UnknownCursor)?;
+        live_query_store.drop_query(query_id)
+    });
+    handle
+        .await
+        .expect("Failed to join query releasing task")
+        .map(|_| Empty)
+        .map_err(|error| ValidationFail::from(error).into())
+}
+
 #[derive(serde::Serialize)]
 #[non_exhaustive]
 enum Health {